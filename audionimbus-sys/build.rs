@@ -12,14 +12,28 @@ fn main() {
     println!("cargo::rerun-if-changed=steam-audio");
     println!("cargo::rerun-if-env-changed=AUDIONIMBUS_AUTO_INSTALL_PROGRESS");
     println!("cargo::rerun-if-env-changed=STEAMAUDIO_LIB_DIR");
+    println!("cargo::rerun-if-env-changed=STEAMAUDIO_DIR");
+    println!("cargo::rerun-if-env-changed=STEAMAUDIO_SOURCE_DIR");
 
     let out_dir_path = std::env::var("OUT_DIR").unwrap();
     let out_dir = Path::new(&out_dir_path);
 
     let version = version();
 
+    // Building from source takes precedence over downloading prebuilt libraries.
+    #[cfg(feature = "build-from-source")]
+    let link_statically =
+        build_from_source().unwrap_or_else(|e| panic!("build from source failed: {e}"));
+
+    #[cfg(not(feature = "build-from-source"))]
+    let link_statically = false;
+
+    // Some targets (iOS) only ship a static library.
+    let link_statically =
+        link_statically || get_target_info().is_ok_and(|target_info| target_info.is_static);
+
     #[cfg(feature = "auto-install")]
-    {
+    if !cfg!(feature = "build-from-source") {
         let did_work = handle_auto_install().unwrap_or_else(|e| panic!("auto-install failed: {e}"));
 
         if did_work {
@@ -29,6 +43,8 @@ fn main() {
 
     emit_manual_link_search_path();
 
+    emit_phonon_link(link_statically);
+
     generate_bindings_phonon(&out_dir.join("phonon.rs"), &version, out_dir);
 
     #[cfg(feature = "fmod")]
@@ -39,9 +55,266 @@ fn main() {
 }
 
 /// If set, adds `STEAMAUDIO_LIB_DIR` to the linker search path.
+///
+/// Otherwise, if `STEAMAUDIO_DIR` points to an extracted Steam Audio SDK, adds the library
+/// directory matching the target platform to the linker search path.
 fn emit_manual_link_search_path() {
     if let Ok(lib_dir) = std::env::var("STEAMAUDIO_LIB_DIR") {
         println!("cargo:rustc-link-search=native={lib_dir}");
+        return;
+    }
+
+    if let Ok(sdk_dir) = std::env::var("STEAMAUDIO_DIR") {
+        let target_info =
+            get_target_info().unwrap_or_else(|e| panic!("cannot use STEAMAUDIO_DIR: {e}"));
+
+        let lib_dir = Path::new(&sdk_dir).join("lib").join(&target_info.lib_dir);
+        if !lib_dir.exists() {
+            panic!(
+                "STEAMAUDIO_DIR is set, but {} does not exist. STEAMAUDIO_DIR must point to the extracted Steam Audio SDK (the directory containing `lib`).",
+                lib_dir.display()
+            );
+        }
+
+        println!("cargo:rustc-link-search=native={}", lib_dir.display());
+    }
+}
+
+/// Links against the Steam Audio library.
+fn emit_phonon_link(link_statically: bool) {
+    if !link_statically {
+        println!("cargo:rustc-link-lib=phonon");
+        return;
+    }
+
+    println!("cargo:rustc-link-lib=static=phonon");
+
+    // The static library does not carry its C++ runtime dependency.
+    let target = std::env::var("TARGET").unwrap();
+    if target.contains("apple") {
+        println!("cargo:rustc-link-lib=c++");
+    } else if target.contains("android") {
+        println!("cargo:rustc-link-lib=c++_shared");
+    } else if !target.contains("msvc") {
+        println!("cargo:rustc-link-lib=stdc++");
+    }
+}
+
+/// Builds the Steam Audio core library from source using CMake.
+///
+/// The sources are taken from `STEAMAUDIO_SOURCE_DIR` if set, or from the vendored `steam-audio`
+/// submodule otherwise. Steam Audio's own third-party dependencies must already be available, as
+/// described in its build instructions.
+///
+/// Steam Audio always declares `phonon` as a shared library on desktop platforms, so
+/// [`FORCE_STATIC_PHONON_CMAKE`] is injected into its CMake project to turn that target into a
+/// static library. Since a static library does not bundle its dependencies, the libraries
+/// `phonon` links against are linked as well.
+///
+/// Returns `true` if a static library was produced, and `false` if only a shared library was.
+#[cfg(feature = "build-from-source")]
+fn build_from_source() -> Result<bool, Box<dyn std::error::Error>> {
+    use std::process::Command;
+
+    let source_dir = std::env::var("STEAMAUDIO_SOURCE_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from("steam-audio"));
+    let core_dir = source_dir.join("core");
+    if !core_dir.join("CMakeLists.txt").exists() {
+        return Err(format!(
+            "Steam Audio sources not found in {}. Run `git submodule update --init` or set STEAMAUDIO_SOURCE_DIR.",
+            source_dir.display()
+        )
+        .into());
+    }
+
+    let out_dir = std::env::var("OUT_DIR")?;
+    let build_dir = Path::new(&out_dir).join("steam_audio_build");
+    std::fs::create_dir_all(&build_dir)?;
+
+    let project_include = build_dir.join("force_static_phonon.cmake");
+    std::fs::write(&project_include, FORCE_STATIC_PHONON_CMAKE)?;
+
+    let status = Command::new("cmake")
+        .arg("-S")
+        .arg(&core_dir)
+        .arg("-B")
+        .arg(&build_dir)
+        .arg(format!(
+            "-DCMAKE_PROJECT_INCLUDE={}",
+            project_include.display()
+        ))
+        .args([
+            "-DCMAKE_BUILD_TYPE=Release",
+            "-DCMAKE_POSITION_INDEPENDENT_CODE=ON",
+            "-DSTEAMAUDIO_BUILD_TESTS=OFF",
+            "-DSTEAMAUDIO_BUILD_ITESTS=OFF",
+            "-DSTEAMAUDIO_BUILD_BENCHMARKS=OFF",
+            "-DSTEAMAUDIO_BUILD_SAMPLES=OFF",
+            "-DSTEAMAUDIO_BUILD_DOCS=OFF",
+        ])
+        .status()
+        .map_err(|e| format!("failed to run cmake (is it installed?): {e}"))?;
+    if !status.success() {
+        return Err("cmake configuration failed".into());
+    }
+
+    let status = Command::new("cmake")
+        .arg("--build")
+        .arg(&build_dir)
+        .args(["--config", "Release", "--target", "phonon", "--parallel"])
+        .status()?;
+    if !status.success() {
+        return Err("cmake build failed".into());
+    }
+
+    // Written by `FORCE_STATIC_PHONON_CMAKE` when generating the build system.
+    let link_info_path = build_dir.join("phonon_link_Release.txt");
+    let link_info = std::fs::read_to_string(&link_info_path).map_err(|e| {
+        format!(
+            "failed to read {} (was the phonon target defined?): {e}",
+            link_info_path.display()
+        )
+    })?;
+
+    let mut lines = link_info.lines();
+    let target_type = lines.next().unwrap_or_default();
+    let lib_path = Path::new(lines.next().unwrap_or_default());
+
+    println!(
+        "cargo:rustc-link-search=native={}",
+        lib_path
+            .parent()
+            .ok_or("phonon library path has no parent directory")?
+            .display()
+    );
+
+    if target_type != "STATIC_LIBRARY" {
+        println!(
+            "cargo:warning=Steam Audio was built as a {target_type} library; linking dynamically instead of statically."
+        );
+        return Ok(false);
+    }
+
+    for dependency in lines.filter(|line| !line.is_empty()) {
+        emit_static_dependency_link(dependency);
+    }
+
+    Ok(true)
+}
+
+/// CMake script included after every `project()` call of Steam Audio's build.
+///
+/// It builds the `phonon` target as a static library, and writes the target type, the library
+/// path and the libraries `phonon` transitively links against to `phonon_link_<config>.txt`.
+#[cfg(feature = "build-from-source")]
+const FORCE_STATIC_PHONON_CMAKE: &str = r#"include_guard(GLOBAL)
+cmake_minimum_required(VERSION 3.19)
+
+function(add_library name)
+    if(name STREQUAL "phonon" AND ARGC GREATER 1 AND ARGV1 STREQUAL "SHARED")
+        list(SUBLIST ARGN 1 -1 args)
+        _add_library(${name} STATIC ${args})
+    else()
+        _add_library(${ARGV})
+    endif()
+endfunction()
+
+function(audionimbus_collect_link_libraries target)
+    get_property(visited GLOBAL PROPERTY AUDIONIMBUS_VISITED)
+    if(target IN_LIST visited)
+        return()
+    endif()
+    set_property(GLOBAL APPEND PROPERTY AUDIONIMBUS_VISITED ${target})
+
+    get_target_property(imported ${target} IMPORTED)
+    set(dependencies "")
+    if(NOT imported)
+        get_target_property(link_libraries ${target} LINK_LIBRARIES)
+        if(link_libraries)
+            list(APPEND dependencies ${link_libraries})
+        endif()
+    endif()
+    get_target_property(interface_link_libraries ${target} INTERFACE_LINK_LIBRARIES)
+    if(interface_link_libraries)
+        list(APPEND dependencies ${interface_link_libraries})
+    endif()
+
+    foreach(dependency IN LISTS dependencies)
+        string(REGEX REPLACE "^\$<LINK_ONLY:(.*)>$" "\1" dependency "${dependency}")
+        if(TARGET ${dependency})
+            get_target_property(type ${dependency} TYPE)
+            if(NOT type STREQUAL "INTERFACE_LIBRARY")
+                set_property(GLOBAL APPEND PROPERTY AUDIONIMBUS_LINK_LIBRARIES
+                    "$<TARGET_LINKER_FILE:${dependency}>")
+            endif()
+            audionimbus_collect_link_libraries(${dependency})
+        elseif(NOT dependency MATCHES "\$<")
+            set_property(GLOBAL APPEND PROPERTY AUDIONIMBUS_LINK_LIBRARIES "${dependency}")
+        endif()
+    endforeach()
+endfunction()
+
+function(audionimbus_write_phonon_link_info)
+    if(NOT TARGET phonon)
+        return()
+    endif()
+    audionimbus_collect_link_libraries(phonon)
+    get_property(link_libraries GLOBAL PROPERTY AUDIONIMBUS_LINK_LIBRARIES)
+    list(JOIN link_libraries "
+" link_libraries)
+    file(GENERATE
+        OUTPUT "${CMAKE_BINARY_DIR}/phonon_link_$<CONFIG>.txt"
+        CONTENT "$<TARGET_PROPERTY:phonon,TYPE>
+$<TARGET_LINKER_FILE:phonon>
+${link_libraries}
+")
+endfunction()
+
+cmake_language(DEFER DIRECTORY "${CMAKE_SOURCE_DIR}" CALL audionimbus_write_phonon_link_info)
+"#;
+
+/// Links a dependency of the static `phonon` library, as listed by
+/// [`FORCE_STATIC_PHONON_CMAKE`]: a library path, a linker flag, or a system library name.
+#[cfg(feature = "build-from-source")]
+fn emit_static_dependency_link(dependency: &str) {
+    if dependency.starts_with('-') {
+        // Linker flags such as `-pthread`.
+        println!("cargo:rustc-link-arg={dependency}");
+        return;
+    }
+
+    let path = Path::new(dependency);
+    if !path.is_absolute() {
+        // A system library, such as `dl` or `m`.
+        println!("cargo:rustc-link-lib={dependency}");
+        return;
+    }
+
+    let (Some(dir), Some(stem), Some(extension)) = (
+        path.parent(),
+        path.file_stem().and_then(|stem| stem.to_str()),
+        path.extension().and_then(|extension| extension.to_str()),
+    ) else {
+        println!("cargo:warning=ignoring Steam Audio dependency {dependency}");
+        return;
+    };
+
+    println!("cargo:rustc-link-search=native={}", dir.display());
+
+    match extension {
+        "a" => {
+            let name = stem.strip_prefix("lib").unwrap_or(stem);
+            println!("cargo:rustc-link-lib=static={name}");
+        }
+        // Either a static library or the import library of a DLL, which the linker tells apart.
+        "lib" => println!("cargo:rustc-link-lib={stem}"),
+        _ => {
+            // Shared libraries may be versioned, as in `libfoo.so.1`.
+            let name = stem.split('.').next().unwrap_or(stem);
+            let name = name.strip_prefix("lib").unwrap_or(name);
+            println!("cargo:rustc-link-lib=dylib={name}");
+        }
     }
 }
 
@@ -74,100 +347,107 @@ fn handle_auto_install() -> Result<bool, Box<dyn std::error::Error>> {
     Ok(did_work)
 }
 
-#[cfg(feature = "auto-install")]
 #[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "auto-install"), allow(dead_code))]
 struct TargetInfo {
     platform: String,
     arch: String,
     lib_dir: String,
     lib_names: Vec<String>,
-    _is_static: bool,
+    /// Whether the SDK ships a static library for this target.
+    is_static: bool,
 }
 
-#[cfg(feature = "auto-install")]
+/// Returns the Steam Audio SDK layout for the target being compiled for.
+///
+/// This relies on Cargo's `CARGO_CFG_TARGET_*` variables rather than the host configuration, so
+/// that cross-compilation picks the right libraries.
 fn get_target_info() -> Result<TargetInfo, Box<dyn std::error::Error>> {
     let target = std::env::var("TARGET")?;
-
-    let (platform, arch, lib_dir, lib_names, is_static) = match target.as_str() {
-        t if t.contains("windows") && t.contains("i686") => (
-            "windows".to_string(),
-            "x86".to_string(),
-            "windows-x86".to_string(),
-            vec!["phonon.dll".to_string()],
-            false,
-        ),
-        t if t.contains("windows") && t.contains("x86_64") => (
-            "windows".to_string(),
-            "x64".to_string(),
-            "windows-x64".to_string(),
-            vec!["phonon.dll".to_string(), "phonon.lib".to_string()],
-            false,
-        ),
-        t if t.contains("linux") && t.contains("i686") => (
-            "linux".to_string(),
-            "x86".to_string(),
-            "linux-x86".to_string(),
-            vec!["libphonon.so".to_string()],
-            false,
-        ),
-        t if t.contains("linux") && t.contains("x86_64") => (
-            "linux".to_string(),
-            "x64".to_string(),
-            "linux-x64".to_string(),
-            vec!["libphonon.so".to_string()],
-            false,
-        ),
-        t if t.contains("apple-darwin") => (
-            "macos".to_string(),
-            "universal".to_string(),
-            "osx".to_string(),
-            vec!["libphonon.dylib".to_string()],
-            false,
-        ),
-        t if t.contains("android") && t.contains("armv7") => (
-            "android".to_string(),
-            "armv7".to_string(),
-            "android-armv7".to_string(),
-            vec!["libphonon.so".to_string()],
-            false,
-        ),
-        t if t.contains("android") && (t.contains("aarch64") || t.contains("armv8")) => (
-            "android".to_string(),
-            "armv8".to_string(),
-            "android-armv8".to_string(),
-            vec!["libphonon.so".to_string()],
-            false,
-        ),
-        t if t.contains("android") && t.contains("i686") => (
-            "android".to_string(),
-            "x86".to_string(),
-            "android-x86".to_string(),
-            vec!["libphonon.so".to_string()],
-            false,
-        ),
-        t if t.contains("android") && t.contains("x86_64") => (
-            "android".to_string(),
-            "x64".to_string(),
-            "android-x64".to_string(),
-            vec!["libphonon.so".to_string()],
-            false,
-        ),
-        t if t.contains("ios") => (
-            "ios".to_string(),
-            "armv8".to_string(),
-            "ios".to_string(),
-            vec!["libphonon.a".to_string()],
-            true,
-        ),
-        _ => return Err(format!("Unsupported target: {target}").into()),
-    };
+    let target_os = std::env::var("CARGO_CFG_TARGET_OS")?;
+    let target_arch = std::env::var("CARGO_CFG_TARGET_ARCH")?;
+
+    let (platform, arch, lib_dir, lib_names, is_static) =
+        match (target_os.as_str(), target_arch.as_str()) {
+            ("windows", "x86") => (
+                "windows".to_string(),
+                "x86".to_string(),
+                "windows-x86".to_string(),
+                vec!["phonon.dll".to_string()],
+                false,
+            ),
+            ("windows", "x86_64") => (
+                "windows".to_string(),
+                "x64".to_string(),
+                "windows-x64".to_string(),
+                vec!["phonon.dll".to_string(), "phonon.lib".to_string()],
+                false,
+            ),
+            ("linux", "x86") => (
+                "linux".to_string(),
+                "x86".to_string(),
+                "linux-x86".to_string(),
+                vec!["libphonon.so".to_string()],
+                false,
+            ),
+            ("linux", "x86_64") => (
+                "linux".to_string(),
+                "x64".to_string(),
+                "linux-x64".to_string(),
+                vec!["libphonon.so".to_string()],
+                false,
+            ),
+            ("macos", _) => (
+                "macos".to_string(),
+                "universal".to_string(),
+                "osx".to_string(),
+                vec!["libphonon.dylib".to_string()],
+                false,
+            ),
+            ("android", "arm") => (
+                "android".to_string(),
+                "armv7".to_string(),
+                "android-armv7".to_string(),
+                vec!["libphonon.so".to_string()],
+                false,
+            ),
+            ("android", "aarch64") => (
+                "android".to_string(),
+                "armv8".to_string(),
+                "android-armv8".to_string(),
+                vec!["libphonon.so".to_string()],
+                false,
+            ),
+            ("android", "x86") => (
+                "android".to_string(),
+                "x86".to_string(),
+                "android-x86".to_string(),
+                vec!["libphonon.so".to_string()],
+                false,
+            ),
+            ("android", "x86_64") => (
+                "android".to_string(),
+                "x64".to_string(),
+                "android-x64".to_string(),
+                vec!["libphonon.so".to_string()],
+                false,
+            ),
+            ("ios", "aarch64") => (
+                "ios".to_string(),
+                "armv8".to_string(),
+                "ios".to_string(),
+                vec!["libphonon.a".to_string()],
+                true,
+            ),
+            _ => return Err(format!("Unsupported target: {target}").into()),
+        };
 
     Ok(TargetInfo {
         platform,
         arch,
         lib_dir,
         lib_names,
-        _is_static: is_static,
+        is_static,
     })
 }
 
@@ -581,8 +861,6 @@ fn install_progress_enabled() -> bool {
 }
 
 fn generate_bindings_phonon(output_path: &Path, version: &Version, tmp_dir: &Path) {
-    println!("cargo:rustc-link-lib=phonon");
-
     let _phonon_header_guard =
         temporary_version_header(&tmp_dir.join("phonon_version.h"), version, "STEAMAUDIO");
 
@@ -756,44 +1034,41 @@ impl Drop for TemporaryFileGuard {
     }
 }
 
+/// Returns the preprocessor flags describing the target platform to Steam Audio's headers.
+///
+/// `cfg!` would describe the host running the build script, so the target is read from Cargo's
+/// `CARGO_CFG_TARGET_*` variables instead.
 fn system_flags() -> Vec<String> {
+    let target_os = std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    let target_arch = std::env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    let target_family = std::env::var("CARGO_CFG_TARGET_FAMILY").unwrap_or_default();
+
     let mut flags = vec![];
 
-    if cfg!(target_os = "windows") {
-        flags.push("-DIPL_OS_WINDOWS");
-    } else if cfg!(target_os = "linux") {
-        flags.push("-DIPL_OS_LINUX");
-    } else if cfg!(target_os = "macos") {
-        flags.push("-DIPL_OS_MACOSX");
-    } else if cfg!(target_os = "android") {
-        flags.push("-DIPL_OS_ANDROID");
-    } else if cfg!(target_os = "ios") {
-        flags.push("-DIPL_OS_IOS");
-    } else if cfg!(target_family = "wasm") {
-        flags.push("-DIPL_OS_WASM");
+    match target_os.as_str() {
+        "windows" => flags.push("-DIPL_OS_WINDOWS"),
+        "linux" => flags.push("-DIPL_OS_LINUX"),
+        "macos" => flags.push("-DIPL_OS_MACOSX"),
+        "android" => flags.push("-DIPL_OS_ANDROID"),
+        "ios" => flags.push("-DIPL_OS_IOS"),
+        _ if target_family.split(',').any(|family| family == "wasm") => flags.push("-DIPL_OS_WASM"),
+        _ => {}
     }
 
-    if cfg!(target_os = "windows") || cfg!(target_os = "linux") {
-        if cfg!(target_pointer_width = "64") {
-            flags.push("-DIPL_CPU_X64");
-        } else if cfg!(target_pointer_width = "32") {
-            flags.push("-DIPL_CPU_X86");
-        }
-    } else if cfg!(target_os = "macos") {
-    } else if cfg!(target_os = "android") {
-        if std::env::var("TARGET").unwrap().contains("armv8") {
-            flags.push("-DIPL_CPU_ARMV8");
-        } else if cfg!(target_arch = "arm") {
-            flags.push("-DIPL_CPU_ARMV7");
-        } else if cfg!(target_arch = "x86") {
-            flags.push("-DIPL_CPU_X86");
-        } else if cfg!(target_arch = "x86_64") {
-            flags.push("-DIPL_CPU_X64");
+    match target_os.as_str() {
+        // macOS libraries are universal binaries.
+        "macos" => {}
+        "ios" => flags.push("-DIPL_CPU_ARMV8"),
+        _ if target_family.split(',').any(|family| family == "wasm") => {
+            flags.push("-DIPL_CPU_ARMV7")
         }
-    } else if cfg!(target_os = "ios") {
-        flags.push("-DIPL_CPU_ARMV8");
-    } else if cfg!(target_family = "wasm") {
-        flags.push("-DIPL_CPU_ARMV7");
+        _ => match target_arch.as_str() {
+            "x86_64" => flags.push("-DIPL_CPU_X64"),
+            "x86" => flags.push("-DIPL_CPU_X86"),
+            "aarch64" => flags.push("-DIPL_CPU_ARMV8"),
+            "arm" => flags.push("-DIPL_CPU_ARMV7"),
+            _ => {}
+        },
     }
 
     flags.into_iter().map(ToString::to_string).collect()
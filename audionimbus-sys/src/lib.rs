@@ -70,6 +70,21 @@ If you need to force a re-download, you can delete this directory.
 
 Set `AUDIONIMBUS_AUTO_INSTALL_PROGRESS=0` to silence Cargo progress warnings from the auto-install step.
 
+### Building From Source
+
+Steam Audio is open source, so it can also be built from source and linked statically with the `build-from-source` feature:
+
+```toml
+[dependencies]
+audionimbus-sys = { version = "4.8.2-rc.1", features = ["build-from-source"] }
+```
+
+The build script configures and builds the Steam Audio core library with CMake, using the sources in `STEAMAUDIO_SOURCE_DIR` if set, or those of the `steam-audio` submodule otherwise (only available when depending on the git repository, since the published crate only ships headers).
+It requires **CMake** and a C++ toolchain, and Steam Audio's own third-party dependencies must be available as described in its build instructions.
+If the build only produces a shared library, it is linked dynamically instead, and a warning is emitted.
+
+This feature takes precedence over `auto-install`.
+
 ### Manual Installation
 
 If you prefer manual installation or the automatic installation doesn't work for your setup, you can still install Steam Audio manually.
@@ -106,6 +121,7 @@ LIBRARY_PATH="/usr/local/lib" cargo build
 ```
 
 If you want to link directly against the extracted Steam Audio SDK instead of installing the library into a common linker directory, set `STEAMAUDIO_LIB_DIR` to the platform-specific library directory, for example `STEAMAUDIO_LIB_DIR="$SDKROOT/lib/linux-x64"`.
+Alternatively, set `STEAMAUDIO_DIR` to the directory in which you extracted the zip file (`SDKROOT`), and the library directory matching the target platform will be used.
 
 Finally, add `audionimbus-sys` to your `Cargo.toml`:
 
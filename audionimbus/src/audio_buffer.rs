@@ -189,6 +189,74 @@ impl<T, P: ChannelPointers> AudioBuffer<T, P> {
         Ok(())
     }
 
+    /// Converts the samples of the audio buffer to 16-bit integers, writing them to `dst`.
+    ///
+    /// `dst` is filled channel after channel (deinterleaved), like the data the buffer was
+    /// constructed from.
+    /// Samples are scaled by 32768 and saturated to the range of [`i16`], so that samples outside
+    /// of `[-1.0, 1.0]` are clipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AudioBufferOperationError::ConversionLengthMismatch`] if the destination slice
+    /// length does not match the audio buffer's total sample count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let samples = vec![0.5, -1.0, 2.0, 0.0];
+    /// let buffer = AudioBuffer::try_with_data(&samples)?;
+    ///
+    /// let mut pcm = [0; 4];
+    /// buffer.to_i16(&mut pcm)?;
+    /// assert_eq!(pcm, [16384, -32768, 32767, 0]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_i16(&self, dst: &mut [i16]) -> Result<(), AudioBufferOperationError> {
+        self.convert_into(dst, sample_to_i16)
+    }
+
+    /// Converts the samples of the audio buffer to 64-bit floats, writing them to `dst`.
+    ///
+    /// `dst` is filled channel after channel (deinterleaved), like the data the buffer was
+    /// constructed from.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AudioBufferOperationError::ConversionLengthMismatch`] if the destination slice
+    /// length does not match the audio buffer's total sample count.
+    pub fn to_f64(&self, dst: &mut [f64]) -> Result<(), AudioBufferOperationError> {
+        self.convert_into(dst, f64::from)
+    }
+
+    /// Writes the samples of the audio buffer to `dst`, channel after channel, converted with
+    /// `convert`.
+    fn convert_into<U>(
+        &self,
+        dst: &mut [U],
+        convert: impl Fn(Sample) -> U,
+    ) -> Result<(), AudioBufferOperationError> {
+        let expected_len = self.num_channels() * self.num_samples();
+        if dst.len() as u32 != expected_len {
+            return Err(AudioBufferOperationError::ConversionLengthMismatch {
+                dst_len: dst.len(),
+                expected_len,
+            });
+        }
+
+        for (channel, dst_channel) in self
+            .channels()
+            .zip(dst.chunks_exact_mut(self.num_samples as usize))
+        {
+            for (sample, dst_sample) in channel.iter().zip(dst_channel) {
+                *dst_sample = convert(*sample);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Mixes `source` into `self`.
     ///
     /// Both audio buffers must have the same number of channels and samples.
@@ -203,23 +271,7 @@ impl<T, P: ChannelPointers> AudioBuffer<T, P> {
         context: &Context,
         source: &AudioBuffer<T2, P2>,
     ) -> Result<(), AudioBufferOperationError> {
-        let self_num_channels = self.num_channels();
-        let other_num_channels = source.num_channels();
-        if self_num_channels != other_num_channels {
-            return Err(AudioBufferOperationError::ChannelCountMismatch {
-                self_num_channels,
-                other_num_channels,
-            });
-        }
-
-        let self_num_samples = self.num_samples();
-        let other_num_samples = source.num_samples();
-        if self_num_samples != other_num_samples {
-            return Err(AudioBufferOperationError::SampleCountMismatch {
-                self_num_samples,
-                other_num_samples,
-            });
-        }
+        self.validate_same_shape(source)?;
 
         unsafe {
             audionimbus_sys::iplAudioBufferMix(
@@ -267,6 +319,205 @@ impl<T, P: ChannelPointers> AudioBuffer<T, P> {
         Ok(())
     }
 
+    /// Returns a view of this audio buffer with its leading and trailing silence removed.
+    ///
+    /// A sample is considered silent if its magnitude does not exceed `threshold`.
+    /// Silence is trimmed across all channels at once, so only the samples that are silent in
+    /// every channel are removed and the channels stay aligned.
+    /// No samples are copied.
+    ///
+    /// Returns `None` if the whole audio buffer is silent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let samples = vec![0.0, 0.5, 0.0, 0.0, 0.0, 0.0, 0.25, 0.0];
+    /// let buffer = AudioBuffer::try_with_data_and_settings(
+    ///     &samples,
+    ///     AudioBufferSettings::with_num_channels(2),
+    /// )?;
+    ///
+    /// let trimmed = buffer.trim_silence(0.0).unwrap();
+    /// let channels: Vec<&[Sample]> = trimmed.channels().collect();
+    /// assert_eq!(channels, [[0.5, 0.0, 0.0], [0.0, 0.0, 0.25]]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn trim_silence(&self, threshold: f32) -> Option<AudioBuffer<&[Sample]>> {
+        let is_audible = |sample: &Sample| sample.abs() > threshold;
+
+        let (start, end) = self
+            .channels()
+            .fold((usize::MAX, 0), |(start, end), channel| {
+                let first = channel.iter().position(is_audible);
+                let last = channel.iter().rposition(is_audible);
+                match (first, last) {
+                    (Some(first), Some(last)) => (start.min(first), end.max(last + 1)),
+                    _ => (start, end),
+                }
+            });
+
+        if start >= end {
+            return None;
+        }
+
+        Some(self.window(start as u32, (end - start) as u32))
+    }
+
+    /// Returns the largest absolute difference between corresponding samples of `self` and
+    /// `other`.
+    ///
+    /// Returns `NaN` if any sample of either audio buffer is `NaN`, and `0.0` for empty audio
+    /// buffers.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// - [`AudioBufferOperationError::ChannelCountMismatch`] if the audio buffers have different numbers of channels.
+    /// - [`AudioBufferOperationError::SampleCountMismatch`] if the audio buffers have different numbers of samples per channel.
+    pub fn max_abs_diff<T2, P2: ChannelPointers>(
+        &self,
+        other: &AudioBuffer<T2, P2>,
+    ) -> Result<f32, AudioBufferOperationError> {
+        self.validate_same_shape(other)?;
+
+        let mut max_abs_diff: f32 = 0.0;
+        for (channel, other_channel) in self.channels().zip(other.channels()) {
+            for (sample, other_sample) in channel.iter().zip(other_channel) {
+                let abs_diff = (sample - other_sample).abs();
+                if abs_diff.is_nan() {
+                    return Ok(f32::NAN);
+                }
+                max_abs_diff = max_abs_diff.max(abs_diff);
+            }
+        }
+
+        Ok(max_abs_diff)
+    }
+
+    /// Returns `true` if every sample of `self` is within `epsilon` of the corresponding sample of
+    /// `other`.
+    ///
+    /// This is typically used to compare the output of an effect with a reference rendering in
+    /// tests, where exact equality is too strict for floating-point audio.
+    /// Audio buffers containing `NaN` samples are never approximately equal.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Self::max_abs_diff`] if the audio buffers have different
+    /// numbers of channels or samples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let rendered = vec![0.5, 0.25, -0.25, 0.0];
+    /// let golden = vec![0.5, 0.2501, -0.25, 0.0];
+    /// let rendered = AudioBuffer::try_with_data(&rendered)?;
+    /// let golden = AudioBuffer::try_with_data(&golden)?;
+    ///
+    /// assert!(rendered.approx_eq(&golden, 1e-3)?);
+    /// assert!(!rendered.approx_eq(&golden, 1e-5)?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn approx_eq<T2, P2: ChannelPointers>(
+        &self,
+        other: &AudioBuffer<T2, P2>,
+        epsilon: f32,
+    ) -> Result<bool, AudioBufferOperationError> {
+        Ok(self.max_abs_diff(other)? <= epsilon)
+    }
+
+    /// Returns an error if `other` does not have the same number of channels and samples as
+    /// `self`.
+    fn validate_same_shape<T2, P2: ChannelPointers>(
+        &self,
+        other: &AudioBuffer<T2, P2>,
+    ) -> Result<(), AudioBufferOperationError> {
+        let self_num_channels = self.num_channels();
+        let other_num_channels = other.num_channels();
+        if self_num_channels != other_num_channels {
+            return Err(AudioBufferOperationError::ChannelCountMismatch {
+                self_num_channels,
+                other_num_channels,
+            });
+        }
+
+        let self_num_samples = self.num_samples();
+        let other_num_samples = other.num_samples();
+        if self_num_samples != other_num_samples {
+            return Err(AudioBufferOperationError::SampleCountMismatch {
+                self_num_samples,
+                other_num_samples,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the pointer to the channel at `index`, or an error if it is out of bounds.
+    fn checked_channel_ptr(&self, index: u32) -> Result<*mut Sample, AudioBufferOperationError> {
+        let channel_ptrs = self.channel_ptrs.as_slice();
+        channel_ptrs.get(index as usize).copied().ok_or(
+            AudioBufferOperationError::ChannelOutOfBounds {
+                index,
+                num_channels: channel_ptrs.len() as u32,
+            },
+        )
+    }
+
+    /// Returns the samples of the audio buffer as raw bytes, for instance to share them with
+    /// another process.
+    ///
+    /// The layout is deinterleaved and channel-major: all samples of the first channel, followed
+    /// by all samples of the second channel, and so on.
+    /// Each sample is a 32-bit float in native byte order, so there are
+    /// `4 * num_channels * num_samples` bytes in total.
+    /// [`AudioBuffer::try_from_bytes`] reconstructs a buffer from such bytes.
+    ///
+    /// Returns `None` if the channels are not stored back to back in memory, which is the case
+    /// for frames of a larger buffer, or for buffers constructed from separate slices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let samples = vec![0.25, 0.5, -0.25, -0.5];
+    /// let buffer = AudioBuffer::try_with_data_and_settings(
+    ///     &samples,
+    ///     AudioBufferSettings::with_num_channels(2),
+    /// )?;
+    /// let bytes = buffer.as_bytes().unwrap().to_vec();
+    ///
+    /// // In the receiving process.
+    /// let received = AudioBuffer::try_from_bytes(&bytes, 2)?;
+    /// let channels: Vec<&[Sample]> = received.channels().collect();
+    /// assert_eq!(channels, [[0.25, 0.5], [-0.25, -0.5]]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "bytemuck")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        let channel_ptrs = self.channel_ptrs.as_slice();
+        let num_samples = self.num_samples as usize;
+        let first = channel_ptrs[0];
+
+        let contiguous = channel_ptrs
+            .iter()
+            .enumerate()
+            .all(|(channel, &ptr)| ptr == first.wrapping_add(channel * num_samples));
+        if !contiguous {
+            return None;
+        }
+
+        // SAFETY: each channel points to `num_samples` valid samples, and the channels follow each
+        // other in memory, so they form a single run of valid samples.
+        let samples =
+            unsafe { std::slice::from_raw_parts(first, channel_ptrs.len() * num_samples) };
+
+        Some(bytemuck::cast_slice(samples))
+    }
+
     /// Returns an iterator over channels.
     pub fn channels(&self) -> impl Iterator<Item = &[Sample]> + '_ {
         self.channel_ptrs.as_slice().iter().map(|&ptr|
@@ -282,6 +533,126 @@ impl<T, P: ChannelPointers> AudioBuffer<T, P> {
             unsafe { std::slice::from_raw_parts_mut(*ptr, num_samples) })
     }
 
+    /// Returns an iterator over consecutive frames of `frame_size` samples per channel.
+    ///
+    /// Each frame is a view into this buffer, spanning all of its channels; no samples are
+    /// copied.
+    /// This allows driving effects, which process one frame at a time, over a signal of
+    /// arbitrary length.
+    ///
+    /// If the number of samples is not a multiple of `frame_size`, the last frame is shorter.
+    /// Use [`Self::frames_padded`] to zero-pad it to `frame_size` instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frame_size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let samples = vec![0.0; 2 * 1000];
+    /// let buffer = AudioBuffer::try_with_data_and_settings(
+    ///     &samples,
+    ///     AudioBufferSettings::with_num_channels(2),
+    /// )?;
+    ///
+    /// let frame_sizes: Vec<u32> = buffer.frames(256).map(|frame| frame.num_samples()).collect();
+    /// assert_eq!(frame_sizes, [256, 256, 256, 232]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn frames(&self, frame_size: u32) -> impl Iterator<Item = AudioBuffer<&[Sample]>> + '_ {
+        assert!(frame_size > 0, "frame size must be greater than 0");
+
+        (0..self.num_samples.div_ceil(frame_size)).map(move |frame_index| {
+            let offset = frame_index * frame_size;
+            self.window(offset, frame_size.min(self.num_samples - offset))
+        })
+    }
+
+    /// Returns an iterator over consecutive frames of exactly `frame_size` samples per channel.
+    ///
+    /// Like [`Self::frames`], except that if the number of samples is not a multiple of
+    /// `frame_size`, the remaining samples are copied into `padding` and zero-padded to form the
+    /// last frame.
+    /// `padding` is resized as needed, and can be reused across calls to avoid allocations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frame_size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let samples = vec![1.0; 1000];
+    /// let buffer = AudioBuffer::try_with_data(&samples)?;
+    ///
+    /// let mut padding = Vec::new();
+    /// let frames: Vec<_> = buffer.frames_padded(256, &mut padding).collect();
+    /// assert_eq!(frames.len(), 4);
+    /// assert!(frames.iter().all(|frame| frame.num_samples() == 256));
+    ///
+    /// let last = frames[3].channels().next().unwrap();
+    /// assert_eq!(last[231], 1.0);
+    /// assert_eq!(last[232], 0.0);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn frames_padded<'a>(
+        &'a self,
+        frame_size: u32,
+        padding: &'a mut Vec<Sample>,
+    ) -> impl Iterator<Item = AudioBuffer<&'a [Sample]>> + 'a {
+        assert!(frame_size > 0, "frame size must be greater than 0");
+
+        let num_full_frames = self.num_samples / frame_size;
+        let remainder = (self.num_samples % frame_size) as usize;
+        let frame_size_usize = frame_size as usize;
+
+        let last_frame = (remainder > 0).then(move || {
+            let offset = (num_full_frames * frame_size) as usize;
+
+            padding.clear();
+            padding.resize(self.num_channels() as usize * frame_size_usize, 0.0);
+            for (channel, padded_channel) in self
+                .channels()
+                .zip(padding.chunks_exact_mut(frame_size_usize))
+            {
+                padded_channel[..remainder].copy_from_slice(&channel[offset..]);
+            }
+
+            let padding: &'a [Sample] = padding;
+            AudioBuffer::try_with_data_and_settings(
+                padding,
+                AudioBufferSettings::with_num_channels(self.num_channels()),
+            )
+            .expect("padding holds a whole number of non-empty channels")
+        });
+
+        (0..num_full_frames)
+            .map(move |frame_index| self.window(frame_index * frame_size, frame_size))
+            .chain(last_frame)
+    }
+
+    /// Returns a view over `num_samples` samples of each channel, starting at `offset`.
+    fn window(&self, offset: u32, num_samples: u32) -> AudioBuffer<&[Sample]> {
+        debug_assert!(offset + num_samples <= self.num_samples);
+
+        let channel_ptrs = self
+            .channel_ptrs
+            .as_slice()
+            .iter()
+            // SAFETY: the window lies within the `num_samples` valid samples of each channel.
+            .map(|&ptr| unsafe { ptr.add(offset as usize) })
+            .collect();
+
+        AudioBuffer {
+            num_samples,
+            channel_ptrs,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
     /// Converts an Ambisonic audio buffer from one Ambisonic format to another.
     ///
     /// Steam Audio’s "native" Ambisonic format is [`AmbisonicsType::N3D`], so for best performance, keep all Ambisonic data in N3D format except when exchanging data with your audio engine.
@@ -351,94 +722,528 @@ impl<T, P: ChannelPointers> AudioBuffer<T, P> {
     }
 }
 
-impl<T: AsRef<[Sample]>> AudioBuffer<T, Vec<*mut Sample>> {
-    /// Constructs an `AudioBuffer` over `data` with one channel spanning the entire data provided.
+impl<T, P> AudioBuffer<T, P>
+where
+    T: AsRef<[Sample]> + AsMut<[Sample]>,
+    P: ChannelPointers,
+{
+    /// Remixes `source` into `self`, converting between channel counts.
     ///
-    /// # Errors
+    /// Both audio buffers must have the same number of samples per channel.
+    /// Unlike [`Self::downmix`], this is implemented in Rust and supports any pair of channel
+    /// counts. Channels are assumed to follow Steam Audio's speaker ordering (see
+    /// [`SpeakerLayout`]).
     ///
-    /// - [`AudioBufferError::EmptyData`] if the `data` slice is empty.
-    /// - [`AudioBufferError::InvalidNumSamples`] if `num_samples` is 0 or the data length is not divisible by `num_samples`.
-    /// - [`AudioBufferError::InvalidNumChannels`] if `num_channels` is 0 or the data length is not divisible by `num_channels`.
-    /// - [`AudioBufferError::FrameOutOfBounds`] if the frame is out of channel bounds.
-    pub fn try_with_data(data: T) -> Result<Self, AudioBufferError> {
-        Self::try_with_data_and_settings(data, AudioBufferSettings::default())
-    }
-
-    /// Constructs an `AudioBuffer` over `data` given the provided [`AudioBufferSettings`].
+    /// The following rules apply, based on the number of source and destination channels:
+    /// - Mono to any: the source channel is duplicated into the first two destination channels
+    ///   (front left and right); remaining channels are silent.
+    /// - Stereo to mono: the two channels are averaged.
+    /// - 5.1 or 7.1 to stereo: ITU-R BS.775 downmix. The center and surround channels are added to
+    ///   the front channels with a -3 dB (1/√2) gain, and the LFE channel is discarded.
+    /// - 5.1 or 7.1 to mono: the stereo downmix above, averaged.
+    /// - Any other combination: channels are copied by index; extra source channels are dropped
+    ///   and extra destination channels are silent.
+    ///
+    /// Downmixing does not normalize its output, so the result may exceed the `[-1.0, 1.0]` range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let surround = vec![0.1; 6 * 1024];
+    /// let surround_buffer = AudioBuffer::try_with_data_and_settings(
+    ///     &surround,
+    ///     AudioBufferSettings::with_num_channels(6),
+    /// )?;
+    ///
+    /// let mut mono = vec![0.0; 1024];
+    /// let mut mono_buffer = AudioBuffer::try_with_data(&mut mono)?;
+    /// mono_buffer.remix(&surround_buffer)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
     ///
     /// # Errors
     ///
-    /// - [`AudioBufferError::EmptyData`] if the `data` slice is empty.
-    /// - [`AudioBufferError::InvalidNumSamples`] if `num_samples` is 0 or the data length is not divisible by `num_samples`.
-    /// - [`AudioBufferError::InvalidNumChannels`] if `num_channels` is 0 or the data length is not divisible by `num_channels`.
-    /// - [`AudioBufferError::FrameOutOfBounds`] if the frame is out of channel bounds.
-    pub fn try_with_data_and_settings(
-        data: T,
-        settings: AudioBufferSettings,
-    ) -> Result<Self, AudioBufferError> {
-        let data = data.as_ref();
+    /// Returns [`AudioBufferOperationError::SampleCountMismatch`] if the audio buffers have different numbers of samples per channel.
+    ///
+    /// [`SpeakerLayout`]: crate::SpeakerLayout
+    pub fn remix<T2, P2: ChannelPointers>(
+        &mut self,
+        source: &AudioBuffer<T2, P2>,
+    ) -> Result<(), AudioBufferOperationError> {
+        let self_num_samples = self.num_samples();
+        let other_num_samples = source.num_samples();
+        if self_num_samples != other_num_samples {
+            return Err(AudioBufferOperationError::SampleCountMismatch {
+                self_num_samples,
+                other_num_samples,
+            });
+        }
 
-        if data.is_empty() {
-            return Err(AudioBufferError::EmptyData);
+        let num_in = source.num_channels();
+        let num_out = self.num_channels();
+
+        for (out_channel, out_samples) in self.channels_mut().enumerate() {
+            out_samples.fill(0.0);
+
+            for (in_channel, in_samples) in source.channels().enumerate() {
+                let gain = remix_gain(num_in, num_out, in_channel as u32, out_channel as u32);
+                if gain == 0.0 {
+                    continue;
+                }
+
+                for (out_sample, in_sample) in out_samples.iter_mut().zip(in_samples) {
+                    *out_sample += gain * in_sample;
+                }
+            }
         }
 
-        let (num_channels, num_samples) = settings.num_channels_and_samples(data)?;
-        let frame_size = settings.frame_size.unwrap_or(num_samples);
-        let frame_index = settings.frame_index;
+        Ok(())
+    }
 
-        if (frame_index + 1) * frame_size > num_samples {
-            return Err(AudioBufferError::FrameOutOfBounds {
-                frame_size,
-                frame_index,
-            });
+    /// Fades in the first `num_samples` samples of every channel, from silence to full level.
+    ///
+    /// The first sample is silent, and the ramp reaches full level on the sample following the
+    /// fade.
+    /// If `num_samples` exceeds the length of the buffer, only the beginning of the ramp is
+    /// applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let mut samples = vec![1.0; 1024];
+    /// let mut buffer = AudioBuffer::try_with_data(&mut samples)?;
+    ///
+    /// // Start the source without a click.
+    /// buffer.fade_in(4, FadeCurve::Linear);
+    /// assert_eq!(samples[..5], [0.0, 0.25, 0.5, 0.75, 1.0]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn fade_in(&mut self, num_samples: u32, curve: FadeCurve) {
+        let len = num_samples.min(self.num_samples) as usize;
+
+        for channel in self.channels_mut() {
+            for (i, sample) in channel[..len].iter_mut().enumerate() {
+                *sample *= curve.gain(i as f32 / num_samples as f32);
+            }
         }
+    }
 
-        let channel_ptrs = (0..num_channels)
-            .map(|channel| {
-                let index = (channel * num_samples + frame_index * frame_size) as usize;
-                data[index..].as_ptr().cast_mut()
-            })
-            .collect();
+    /// Fades out the last `num_samples` samples of every channel, from full level to silence.
+    ///
+    /// The last sample is silent.
+    /// This mirrors [`Self::fade_in`]: if `num_samples` exceeds the length of the buffer, only
+    /// the end of the ramp is applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let mut samples = vec![1.0; 1024];
+    /// let mut buffer = AudioBuffer::try_with_data(&mut samples)?;
+    ///
+    /// // Stop the source without a click.
+    /// buffer.fade_out(4, FadeCurve::Linear);
+    /// assert_eq!(samples[1019..], [1.0, 0.75, 0.5, 0.25, 0.0]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn fade_out(&mut self, num_samples: u32, curve: FadeCurve) {
+        let len = num_samples.min(self.num_samples) as usize;
+
+        for channel in self.channels_mut() {
+            let start = channel.len() - len;
+            for (i, sample) in channel[start..].iter_mut().rev().enumerate() {
+                *sample *= curve.gain(i as f32 / num_samples as f32);
+            }
+        }
+    }
 
-        Ok(Self {
-            num_samples: frame_size,
-            channel_ptrs,
-            _marker: std::marker::PhantomData,
-        })
+    /// Inverts the phase of every channel, by negating all samples.
+    ///
+    /// Summing a stereo signal with a phase-inverted copy of one of its channels is a common way
+    /// of checking mono compatibility.
+    pub fn invert_phase(&mut self) {
+        for channel in self.channels_mut() {
+            for sample in channel {
+                *sample = -*sample;
+            }
+        }
     }
-}
 
-impl<'a, T: AsRef<[Sample]>> AudioBuffer<T, &'a mut [*mut Sample]> {
-    /// Constructs an `AudioBuffer` over `data` with one channel spanning the entire data provided.
-    /// The `null_channel_ptrs` argument will be filled with actual channel pointers.
+    /// Inverts the phase of the channel at `index`, by negating its samples.
     ///
     /// # Errors
     ///
-    /// - [`AudioBufferError::EmptyData`] if the `data` slice is empty.
-    /// - [`AudioBufferError::InvalidNumSamples`] if the number of samples is 0 or the data length is not divisible by the number of samples.
-    /// - [`AudioBufferError::InvalidNumChannels`] if the number of channels is 0 or the data length is not divisible by the number of channels.
-    /// - [`AudioBufferError::FrameOutOfBounds`] if the frame is out of channel bounds.
-    /// - [`AudioBufferError::InvalidChannelPtrs`] if the length of `null_channel_ptrs` is not equal to the number of channels.
-    pub fn try_borrowed_with_data(
-        data: T,
-        null_channel_ptrs: &'a mut [*mut Sample],
-    ) -> Result<Self, AudioBufferError> {
-        Self::try_borrowed_with_data_and_settings(
-            data,
-            null_channel_ptrs,
-            AudioBufferSettings::default(),
-        )
+    /// Returns [`AudioBufferOperationError::ChannelOutOfBounds`] if `index` is not less than the
+    /// number of channels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let mut samples = vec![0.5, 0.25, 0.5, 0.25];
+    /// let mut buffer = AudioBuffer::try_with_data_and_settings(
+    ///     &mut samples,
+    ///     AudioBufferSettings::with_num_channels(2),
+    /// )?;
+    ///
+    /// buffer.invert_channel(1)?;
+    /// assert_eq!(samples, [0.5, 0.25, -0.5, -0.25]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn invert_channel(&mut self, index: u32) -> Result<(), AudioBufferOperationError> {
+        let channel = self.checked_channel_ptr(index)?;
+
+        // SAFETY: pointers are guaranteed to be valid by the lifetime.
+        let channel = unsafe { std::slice::from_raw_parts_mut(channel, self.num_samples as usize) };
+        for sample in channel {
+            *sample = -*sample;
+        }
+
+        Ok(())
     }
 
-    /// Constructs an `AudioBuffer` over `data` given the provided [`AudioBufferSettings`].
-    /// The `null_channel_ptrs` argument will be filled with actual channel pointers.
+    /// Swaps the samples of the channels at indices `a` and `b`.
+    ///
+    /// This is typically used to swap the left and right channels of a stereo buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AudioBufferOperationError::ChannelOutOfBounds`] if `a` or `b` is not less than
+    /// the number of channels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let mut samples = vec![1.0, 1.0, 2.0, 2.0];
+    /// let mut buffer = AudioBuffer::try_with_data_and_settings(
+    ///     &mut samples,
+    ///     AudioBufferSettings::with_num_channels(2),
+    /// )?;
+    ///
+    /// buffer.swap_channels(0, 1)?;
+    /// assert_eq!(samples, [2.0, 2.0, 1.0, 1.0]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn swap_channels(&mut self, a: u32, b: u32) -> Result<(), AudioBufferOperationError> {
+        let a = self.checked_channel_ptr(a)?;
+        let b = self.checked_channel_ptr(b)?;
+
+        if a != b {
+            // SAFETY: pointers are guaranteed to be valid by the lifetime, and distinct channels
+            // do not overlap.
+            unsafe { std::ptr::swap_nonoverlapping(a, b, self.num_samples as usize) };
+        }
+
+        Ok(())
+    }
+
+    /// Calls `f` with the channel index, sample index and a mutable reference to every sample.
+    ///
+    /// Samples are visited channel by channel, in the order they are stored: all samples of the
+    /// first channel, then all samples of the second channel, and so on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let mut samples = vec![0.0; 4];
+    /// let mut buffer = AudioBuffer::try_with_data_and_settings(
+    ///     &mut samples,
+    ///     AudioBufferSettings::with_num_channels(2),
+    /// )?;
+    ///
+    /// // Inject a click at the start of the second channel.
+    /// buffer.for_each_sample(|channel, sample, value| {
+    ///     if channel == 1 && sample == 0 {
+    ///         *value = 1.0;
+    ///     }
+    /// });
+    /// assert_eq!(samples, [0.0, 0.0, 1.0, 0.0]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn for_each_sample(&mut self, mut f: impl FnMut(usize, usize, &mut Sample)) {
+        for (channel_index, channel) in self.channels_mut().enumerate() {
+            for (sample_index, sample) in channel.iter_mut().enumerate() {
+                f(channel_index, sample_index, sample);
+            }
+        }
+    }
+
+    /// Replaces every sample with the result of calling `f` on it.
+    ///
+    /// Samples are visited in the same order as [`Self::for_each_sample`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let mut samples = vec![-2.0, -0.5, 0.5, 2.0];
+    /// let mut buffer = AudioBuffer::try_with_data(&mut samples)?;
+    ///
+    /// // Hard clipping.
+    /// buffer.map(|sample| sample.clamp(-1.0, 1.0));
+    /// assert_eq!(samples, [-1.0, -0.5, 0.5, 1.0]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn map(&mut self, mut f: impl FnMut(Sample) -> Sample) {
+        self.for_each_sample(|_, _, sample| *sample = f(*sample));
+    }
+
+    /// Scales all channels so that the largest absolute sample equals `target_peak`, and returns
+    /// the gain that was applied.
+    ///
+    /// Dividing by the returned gain undoes the normalization.
+    /// A silent audio buffer, or one whose peak is not finite, is left untouched and a gain of
+    /// `1.0` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let mut samples = vec![0.25, -0.5, 0.125, 0.0];
+    /// let mut buffer = AudioBuffer::try_with_data_and_settings(
+    ///     &mut samples,
+    ///     AudioBufferSettings::with_num_channels(2),
+    /// )?;
+    ///
+    /// let gain = buffer.normalize(1.0);
+    /// assert_eq!(gain, 2.0);
+    /// assert_eq!(samples, [0.5, -1.0, 0.25, 0.0]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn normalize(&mut self, target_peak: f32) -> f32 {
+        let peak = self
+            .channels()
+            .flatten()
+            .fold(0.0, |peak: f32, sample| peak.max(sample.abs()));
+
+        if peak == 0.0 || !peak.is_finite() {
+            return 1.0;
+        }
+
+        let gain = target_peak / peak;
+        self.map(|sample| sample * gain);
+
+        gain
+    }
+}
+
+impl<T: AsRef<[Sample]>> AudioBuffer<T, Vec<*mut Sample>> {
+    /// Constructs an `AudioBuffer` over `data` with one channel spanning the entire data provided.
+    ///
+    /// # Errors
+    ///
+    /// - [`AudioBufferError::EmptyData`] if the `data` slice is empty.
+    /// - [`AudioBufferError::InvalidNumSamples`] if `num_samples` is 0 or the data length is not divisible by `num_samples`.
+    /// - [`AudioBufferError::InvalidNumChannels`] if `num_channels` is 0 or the data length is not divisible by `num_channels`.
+    /// - [`AudioBufferError::FrameOutOfBounds`] if the frame is empty or out of channel bounds.
+    pub fn try_with_data(data: T) -> Result<Self, AudioBufferError> {
+        Self::try_with_data_and_settings(data, AudioBufferSettings::default())
+    }
+
+    /// Constructs an `AudioBuffer` over `data` given the provided [`AudioBufferSettings`].
+    ///
+    /// # Errors
+    ///
+    /// - [`AudioBufferError::EmptyData`] if the `data` slice is empty.
+    /// - [`AudioBufferError::InvalidNumSamples`] if `num_samples` is 0 or the data length is not divisible by `num_samples`.
+    /// - [`AudioBufferError::InvalidNumChannels`] if `num_channels` is 0 or the data length is not divisible by `num_channels`.
+    /// - [`AudioBufferError::FrameOutOfBounds`] if the frame is empty or out of channel bounds.
+    pub fn try_with_data_and_settings(
+        data: T,
+        settings: AudioBufferSettings,
+    ) -> Result<Self, AudioBufferError> {
+        let data = data.as_ref();
+
+        if data.is_empty() {
+            return Err(AudioBufferError::EmptyData);
+        }
+
+        let (num_channels, num_samples) = settings.num_channels_and_samples(data)?;
+        let (frame_size, frame_offset) = settings.frame_bounds(num_samples)?;
+        let channel_stride = settings.channel_stride.unwrap_or(num_samples) as usize;
+
+        let channel_ptrs = (0..num_channels as usize)
+            .map(|channel| {
+                let index = channel * channel_stride + frame_offset as usize;
+                data[index..].as_ptr().cast_mut()
+            })
+            .collect();
+
+        Ok(Self {
+            num_samples: frame_size,
+            channel_ptrs,
+            _marker: std::marker::PhantomData,
+        })
+    }
+}
+
+impl<'a> AudioBuffer<&'a mut [Sample]> {
+    /// Constructs an output `AudioBuffer` over `data`, split into `num_channels` channels.
+    ///
+    /// This is a shorthand for [`Self::try_with_data_and_settings`] with
+    /// [`AudioBufferSettings::with_num_channels`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let mut output = vec![0.0; 2 * 1024];
+    /// let output_buffer = AudioBuffer::try_output(&mut output, 2)?;
+    /// assert_eq!(output_buffer.num_channels(), 2);
+    /// assert_eq!(output_buffer.num_samples(), 1024);
+    /// # Ok::<(), audionimbus::AudioBufferError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AudioBufferError::EmptyData`] if `data` is empty.
+    /// - [`AudioBufferError::InvalidNumChannels`] if `num_channels` is 0 or the data length is not divisible by `num_channels`.
+    pub fn try_output(data: &'a mut [Sample], num_channels: u32) -> Result<Self, AudioBufferError> {
+        Self::try_with_data_and_settings(data, AudioBufferSettings::with_num_channels(num_channels))
+    }
+
+    /// Constructs an `AudioBuffer` from 16-bit integer samples, split into `num_channels`
+    /// channels.
+    ///
+    /// `samples` must be deinterleaved: all samples of the first channel, followed by all samples
+    /// of the second channel, and so on.
+    /// Samples are converted by dividing them by 32768, and written to `data`, which is resized as
+    /// needed and can be reused across calls to avoid allocations.
+    ///
+    /// See [`Self::to_i16`] for the opposite conversion.
+    ///
+    /// # Errors
+    ///
+    /// - [`AudioBufferError::EmptyData`] if `samples` is empty.
+    /// - [`AudioBufferError::InvalidNumChannels`] if `num_channels` is 0 or the number of samples is not divisible by `num_channels`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let pcm: [i16; 4] = [16384, -32768, 0, 32767];
+    /// let mut data = Vec::new();
+    /// let buffer = AudioBuffer::try_from_i16(&pcm, &mut data, 2)?;
+    ///
+    /// let channels: Vec<&[Sample]> = buffer.channels().collect();
+    /// assert_eq!(channels[0], [0.5, -1.0]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_from_i16(
+        samples: &[i16],
+        data: &'a mut Vec<Sample>,
+        num_channels: u32,
+    ) -> Result<Self, AudioBufferError> {
+        data.clear();
+        data.extend(samples.iter().copied().map(i16_to_sample));
+
+        Self::try_output(data, num_channels)
+    }
+
+    /// Constructs an `AudioBuffer` from 64-bit float samples, split into `num_channels` channels.
+    ///
+    /// `samples` must be deinterleaved: all samples of the first channel, followed by all samples
+    /// of the second channel, and so on.
+    /// Samples are converted to [`Sample`] precision and written to `data`, which is resized as
+    /// needed and can be reused across calls to avoid allocations.
+    ///
+    /// See [`Self::to_f64`] for the opposite conversion.
+    ///
+    /// # Errors
+    ///
+    /// - [`AudioBufferError::EmptyData`] if `samples` is empty.
+    /// - [`AudioBufferError::InvalidNumChannels`] if `num_channels` is 0 or the number of samples is not divisible by `num_channels`.
+    pub fn try_from_f64(
+        samples: &[f64],
+        data: &'a mut Vec<Sample>,
+        num_channels: u32,
+    ) -> Result<Self, AudioBufferError> {
+        data.clear();
+        data.extend(samples.iter().map(|&sample| sample as Sample));
+
+        Self::try_output(data, num_channels)
+    }
+}
+
+impl<'a> AudioBuffer<&'a [Sample]> {
+    /// Constructs an `AudioBuffer` over raw bytes, split into `num_channels` channels.
+    ///
+    /// `bytes` must follow the layout described in [`AudioBuffer::as_bytes`]: deinterleaved,
+    /// channel-major 32-bit float samples in native byte order.
+    /// The bytes are reinterpreted in place, without copying.
+    ///
+    /// # Errors
+    ///
+    /// - [`AudioBufferError::MisalignedBytes`] if `bytes` is not aligned for [`Sample`].
+    /// - [`AudioBufferError::InvalidBytesLength`] if the length of `bytes` is not a multiple of the size of a [`Sample`].
+    /// - [`AudioBufferError::EmptyData`] if `bytes` is empty.
+    /// - [`AudioBufferError::InvalidNumChannels`] if `num_channels` is 0 or the number of samples is not divisible by `num_channels`.
+    #[cfg(feature = "bytemuck")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+    pub fn try_from_bytes(bytes: &'a [u8], num_channels: u32) -> Result<Self, AudioBufferError> {
+        let samples = bytemuck::try_cast_slice(bytes).map_err(|error| match error {
+            bytemuck::PodCastError::TargetAlignmentGreaterAndInputNotAligned
+            | bytemuck::PodCastError::AlignmentMismatch => AudioBufferError::MisalignedBytes,
+            bytemuck::PodCastError::OutputSliceWouldHaveSlop
+            | bytemuck::PodCastError::SizeMismatch => {
+                AudioBufferError::InvalidBytesLength { len: bytes.len() }
+            }
+        })?;
+
+        Self::try_with_data_and_settings(
+            samples,
+            AudioBufferSettings::with_num_channels(num_channels),
+        )
+    }
+}
+
+/// Converts a 16-bit integer sample to a [`Sample`] in `[-1.0, 1.0)`.
+fn i16_to_sample(sample: i16) -> Sample {
+    Sample::from(sample) / 32768.0
+}
+
+/// Converts a [`Sample`] to a 16-bit integer sample, saturating samples outside of `[-1.0, 1.0)`.
+fn sample_to_i16(sample: Sample) -> i16 {
+    (sample * 32768.0)
+        .round()
+        .clamp(Sample::from(i16::MIN), Sample::from(i16::MAX)) as i16
+}
+
+impl<'a, T: AsRef<[Sample]>> AudioBuffer<T, &'a mut [*mut Sample]> {
+    /// Constructs an `AudioBuffer` over `data` with one channel spanning the entire data provided.
+    /// The `null_channel_ptrs` argument will be filled with actual channel pointers.
+    ///
+    /// # Errors
+    ///
+    /// - [`AudioBufferError::EmptyData`] if the `data` slice is empty.
+    /// - [`AudioBufferError::InvalidNumSamples`] if the number of samples is 0 or the data length is not divisible by the number of samples.
+    /// - [`AudioBufferError::InvalidNumChannels`] if the number of channels is 0 or the data length is not divisible by the number of channels.
+    /// - [`AudioBufferError::FrameOutOfBounds`] if the frame is empty or out of channel bounds.
+    /// - [`AudioBufferError::InvalidChannelPtrs`] if the length of `null_channel_ptrs` is not equal to the number of channels.
+    pub fn try_borrowed_with_data(
+        data: T,
+        null_channel_ptrs: &'a mut [*mut Sample],
+    ) -> Result<Self, AudioBufferError> {
+        Self::try_borrowed_with_data_and_settings(
+            data,
+            null_channel_ptrs,
+            AudioBufferSettings::default(),
+        )
+    }
+
+    /// Constructs an `AudioBuffer` over `data` given the provided [`AudioBufferSettings`].
+    /// The `null_channel_ptrs` argument will be filled with actual channel pointers.
     ///
     /// # Errors
     ///
     /// - [`AudioBufferError::EmptyData`] if `data` is empty.
     /// - [`AudioBufferError::InvalidNumSamples`] if the number of samples is 0 or the data length is not divisible by the number of samples.
     /// - [`AudioBufferError::InvalidNumChannels`] if the number of channels is 0 or the data length is not divisible by the number of channels.
-    /// - [`AudioBufferError::FrameOutOfBounds`] if the frame is out of channel bounds.
+    /// - [`AudioBufferError::FrameOutOfBounds`] if the frame is empty or out of channel bounds.
     /// - [`AudioBufferError::InvalidChannelPtrs`] if the length of `null_channel_ptrs` is not equal to the number of channels.
     pub fn try_borrowed_with_data_and_settings(
         data: T,
@@ -452,15 +1257,8 @@ impl<'a, T: AsRef<[Sample]>> AudioBuffer<T, &'a mut [*mut Sample]> {
         }
 
         let (num_channels, num_samples) = settings.num_channels_and_samples(data)?;
-        let frame_size = settings.frame_size.unwrap_or(num_samples);
-        let frame_index = settings.frame_index;
-
-        if (frame_index + 1) * frame_size > num_samples {
-            return Err(AudioBufferError::FrameOutOfBounds {
-                frame_size,
-                frame_index,
-            });
-        }
+        let (frame_size, frame_offset) = settings.frame_bounds(num_samples)?;
+        let channel_stride = settings.channel_stride.unwrap_or(num_samples) as usize;
 
         if null_channel_ptrs.len() as u32 != num_channels {
             return Err(AudioBufferError::InvalidChannelPtrs {
@@ -473,8 +1271,8 @@ impl<'a, T: AsRef<[Sample]>> AudioBuffer<T, &'a mut [*mut Sample]> {
             .iter_mut()
             .enumerate()
             .for_each(|(i, channel)| {
-                let index = i as u32 * num_samples + frame_index * frame_size;
-                *channel = data[index as usize..].as_ptr().cast_mut();
+                let index = i * channel_stride + frame_offset as usize;
+                *channel = data[index..].as_ptr().cast_mut();
             });
 
         let channel_ptrs = null_channel_ptrs;
@@ -529,9 +1327,66 @@ impl<'a> AudioBuffer<(), &'a mut [*mut Sample]> {
     }
 }
 
+/// Returns the gain applied to `in_channel` when mixing it into `out_channel` in
+/// [`AudioBuffer::remix`].
+fn remix_gain(num_in: u32, num_out: u32, in_channel: u32, out_channel: u32) -> Sample {
+    const MINUS_3_DB: Sample = std::f32::consts::FRAC_1_SQRT_2;
+
+    match (num_in, num_out) {
+        (1, _) => {
+            if out_channel < 2 {
+                1.0
+            } else {
+                0.0
+            }
+        }
+        (2, 1) => 0.5,
+        // 5.1: FL, FR, FC, LFE, SL, SR.
+        // 7.1: FL, FR, FC, LFE, SL, SR, BL, BR.
+        (6 | 8, 2) => match (in_channel, out_channel) {
+            (0, 0) | (1, 1) => 1.0,
+            (2, _) => MINUS_3_DB,
+            (4 | 6, 0) | (5 | 7, 1) => MINUS_3_DB,
+            _ => 0.0,
+        },
+        (6 | 8, 1) => {
+            0.5 * (remix_gain(num_in, 2, in_channel, 0) + remix_gain(num_in, 2, in_channel, 1))
+        }
+        _ => {
+            if in_channel == out_channel {
+                1.0
+            } else {
+                0.0
+            }
+        }
+    }
+}
+
 /// An audio sample.
 pub type Sample = f32;
 
+/// The shape of the gain ramp applied by [`AudioBuffer::fade_in`] and [`AudioBuffer::fade_out`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum FadeCurve {
+    /// Gain increases at a constant rate.
+    #[default]
+    Linear,
+
+    /// Gain follows half a cosine period, starting and ending with a zero slope.
+    /// Sounds smoother than [`Self::Linear`] for the same fade duration.
+    RaisedCosine,
+}
+
+impl FadeCurve {
+    /// Returns the gain at `position` along a fade-in, between 0.0 (start) and 1.0 (end).
+    fn gain(self, position: f32) -> f32 {
+        match self {
+            Self::Linear => position,
+            Self::RaisedCosine => 0.5 - 0.5 * (std::f32::consts::PI * position).cos(),
+        }
+    }
+}
+
 /// Settings used to construct an [`AudioBuffer`].
 #[derive(Default, Copy, Clone, Debug)]
 pub struct AudioBufferSettings {
@@ -556,11 +1411,24 @@ pub struct AudioBufferSettings {
 
     /// Zero-based index of the frame.
     pub frame_index: u32,
+
+    /// The distance, in samples, between the first samples of two consecutive channels.
+    ///
+    /// If `None`, channels are tightly packed and the stride is the number of samples per
+    /// channel.
+    /// If `Some`, the data may contain padding between channels, for instance when it is a region
+    /// of a larger buffer. In that case, the number of channels defaults to 1 and the number of
+    /// samples per channel defaults to the stride, rather than being inferred from the data length.
+    pub channel_stride: Option<u32>,
 }
 
 impl AudioBufferSettings {
     /// Creates a new [`AudioBufferSettings`] with the specified number of channels.
     /// The number of samples per channel will be inferred.
+    ///
+    /// The data is assumed to be deinterleaved and tightly packed: all samples of the first
+    /// channel, immediately followed by all samples of the second channel, and so on.
+    /// Use [`Self::with_strided`] if there is padding between channels.
     pub fn with_num_channels(num_channels: u32) -> Self {
         Self {
             num_channels: Some(num_channels),
@@ -587,6 +1455,37 @@ impl AudioBufferSettings {
         }
     }
 
+    /// Creates a new [`AudioBufferSettings`] for deinterleaved data with padding between channels.
+    ///
+    /// Each of the `num_channels` channels holds `num_samples` samples, and consecutive channels
+    /// start `channel_stride` samples apart.
+    /// The data must therefore contain at least `(num_channels - 1) * channel_stride + num_samples`
+    /// samples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// // Two channels of 3 samples, each followed by 2 samples of padding.
+    /// let data = [1.0, 2.0, 3.0, 0.0, 0.0, 4.0, 5.0, 6.0, 0.0, 0.0];
+    /// let buffer = AudioBuffer::try_with_data_and_settings(
+    ///     &data,
+    ///     AudioBufferSettings::with_strided(2, 3, 5),
+    /// )?;
+    ///
+    /// let channels: Vec<&[Sample]> = buffer.channels().collect();
+    /// assert_eq!(channels, [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_strided(num_channels: u32, num_samples: u32, channel_stride: u32) -> Self {
+        Self {
+            num_channels: Some(num_channels),
+            num_samples: Some(num_samples),
+            channel_stride: Some(channel_stride),
+            ..Default::default()
+        }
+    }
+
     /// Returns the number of channels and the number of samples derived from these
     /// [`AudioBufferSettings`].
     ///
@@ -594,12 +1493,17 @@ impl AudioBufferSettings {
     ///
     /// - [`AudioBufferError::InvalidNumSamples`] if [`Self::num_samples`] is 0 or the data length is not divisible by [`Self::num_samples`].
     /// - [`AudioBufferError::InvalidNumChannels`] if [`Self::num_channels`] is 0 or the data length is not divisible by [`Self::num_channels`].
+    /// - [`AudioBufferError::InvalidChannelStride`] if [`Self::channel_stride`] is smaller than the number of samples per channel, or the data is too short for the strided channels.
     pub fn num_channels_and_samples<T: AsRef<[Sample]>>(
         &self,
         data: T,
     ) -> Result<(u32, u32), AudioBufferError> {
         let data = data.as_ref();
 
+        if let Some(channel_stride) = self.channel_stride {
+            return self.strided_num_channels_and_samples(data, channel_stride);
+        }
+
         let (num_channels, num_samples) = match (self.num_channels, self.num_samples) {
             (None, None) => (1, data.len() as u32),
             (Some(num_channels), Some(num_samples)) => {
@@ -607,7 +1511,9 @@ impl AudioBufferSettings {
                     return Err(AudioBufferError::InvalidNumChannels { num_channels });
                 }
 
-                if num_samples == 0 || num_channels * num_samples != data.len() as u32 {
+                // Computed in `usize` so that an overflowing product cannot match the length.
+                let total_num_samples = (num_channels as usize).checked_mul(num_samples as usize);
+                if num_samples == 0 || total_num_samples != Some(data.len()) {
                     return Err(AudioBufferError::InvalidNumSamples { num_samples });
                 }
 
@@ -635,6 +1541,57 @@ impl AudioBufferSettings {
 
         Ok((num_channels, num_samples))
     }
+
+    /// Returns the number of channels and the number of samples of channels `channel_stride`
+    /// samples apart.
+    fn strided_num_channels_and_samples(
+        &self,
+        data: &[Sample],
+        channel_stride: u32,
+    ) -> Result<(u32, u32), AudioBufferError> {
+        let num_channels = self.num_channels.unwrap_or(1);
+        if num_channels == 0 {
+            return Err(AudioBufferError::InvalidNumChannels { num_channels });
+        }
+
+        let num_samples = self.num_samples.unwrap_or(channel_stride);
+        if num_samples == 0 {
+            return Err(AudioBufferError::InvalidNumSamples { num_samples });
+        }
+
+        // Computed in `usize` so that an overflowing length cannot fit in the data.
+        let required_len = (num_channels as usize - 1)
+            .checked_mul(channel_stride as usize)
+            .and_then(|len| len.checked_add(num_samples as usize));
+        if num_samples > channel_stride || required_len.is_none_or(|len| len > data.len()) {
+            return Err(AudioBufferError::InvalidChannelStride { channel_stride });
+        }
+
+        Ok((num_channels, num_samples))
+    }
+
+    /// Returns the frame size and the offset of the frame within each channel of `num_samples`
+    /// samples.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AudioBufferError::FrameOutOfBounds`] if the frame is empty or extends past the
+    /// end of the channels.
+    fn frame_bounds(&self, num_samples: u32) -> Result<(u32, u32), AudioBufferError> {
+        let frame_size = self.frame_size.unwrap_or(num_samples);
+        let frame_offset = self.frame_index.checked_mul(frame_size);
+        let frame_end = frame_offset.and_then(|offset| offset.checked_add(frame_size));
+
+        match (frame_offset, frame_end) {
+            (Some(frame_offset), Some(frame_end)) if frame_size > 0 && frame_end <= num_samples => {
+                Ok((frame_size, frame_offset))
+            }
+            _ => Err(AudioBufferError::FrameOutOfBounds {
+                frame_size,
+                frame_index: self.frame_index,
+            }),
+        }
+    }
 }
 
 /// Allocates a vector of mutable pointers to later store channel pointers of an audio buffer.
@@ -652,6 +1609,95 @@ pub fn allocate_channel_ptrs<T: AsRef<[Sample]>>(
     Ok(channel_ptrs)
 }
 
+/// Crossfades from `from` to `to` with equal power, writing the result into `out`.
+///
+/// `t_start` and `t_end` are the crossfade progress at the start of the frame and after its last
+/// sample, between 0.0 (only `from`) and 1.0 (only `to`); progress is interpolated linearly in
+/// between.
+/// To spread a transition over several frames, pass the `t_end` of a frame as the `t_start` of
+/// the next one.
+///
+/// The gains follow a quarter cosine and sine period, so that the summed power stays constant
+/// for uncorrelated signals.
+/// This avoids the dip in loudness of a linear crossfade, e.g. when switching HRTFs or toggling
+/// reflections by processing both configurations during the transition.
+///
+/// # Errors
+///
+/// Returns:
+/// - [`AudioBufferOperationError::ChannelCountMismatch`] if the audio buffers have different numbers of channels.
+/// - [`AudioBufferOperationError::SampleCountMismatch`] if the audio buffers have different numbers of samples per channel.
+///
+/// # Examples
+///
+/// ```
+/// # use audionimbus::*;
+/// let old = vec![1.0; 1024];
+/// let new = vec![1.0; 1024];
+/// let mut output = vec![0.0; 1024];
+///
+/// // Second half of a transition spanning two frames.
+/// crossfade(
+///     &AudioBuffer::try_with_data(&old)?,
+///     &AudioBuffer::try_with_data(&new)?,
+///     &mut AudioBuffer::try_with_data(&mut output)?,
+///     0.5,
+///     1.0,
+/// )?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn crossfade<T1, P1, T2, P2, T3, P3>(
+    from: &AudioBuffer<T1, P1>,
+    to: &AudioBuffer<T2, P2>,
+    out: &mut AudioBuffer<T3, P3>,
+    t_start: f32,
+    t_end: f32,
+) -> Result<(), AudioBufferOperationError>
+where
+    P1: ChannelPointers,
+    P2: ChannelPointers,
+    T3: AsRef<[Sample]> + AsMut<[Sample]>,
+    P3: ChannelPointers,
+{
+    for (other_num_channels, other_num_samples) in [
+        (from.num_channels(), from.num_samples()),
+        (to.num_channels(), to.num_samples()),
+    ] {
+        if out.num_channels() != other_num_channels {
+            return Err(AudioBufferOperationError::ChannelCountMismatch {
+                self_num_channels: out.num_channels(),
+                other_num_channels,
+            });
+        }
+
+        if out.num_samples() != other_num_samples {
+            return Err(AudioBufferOperationError::SampleCountMismatch {
+                self_num_samples: out.num_samples(),
+                other_num_samples,
+            });
+        }
+    }
+
+    let num_samples = out.num_samples() as f32;
+
+    for ((out_channel, from_channel), to_channel) in
+        out.channels_mut().zip(from.channels()).zip(to.channels())
+    {
+        for (i, ((out_sample, from_sample), to_sample)) in out_channel
+            .iter_mut()
+            .zip(from_channel)
+            .zip(to_channel)
+            .enumerate()
+        {
+            let t = (t_start + (t_end - t_start) * i as f32 / num_samples).clamp(0.0, 1.0);
+            let (to_gain, from_gain) = (t * std::f32::consts::FRAC_PI_2).sin_cos();
+            *out_sample = from_gain * from_sample + to_gain * to_sample;
+        }
+    }
+
+    Ok(())
+}
+
 /// [`AudioBuffer`] construction errors.
 #[derive(Debug, PartialEq, Eq)]
 pub enum AudioBufferError {
@@ -670,6 +1716,18 @@ pub enum AudioBufferError {
 
     /// Error when trying to construct an [`AudioBuffer`] with a frame out of channel bounds.
     FrameOutOfBounds { frame_size: u32, frame_index: u32 },
+
+    /// Error when trying to construct an [`AudioBuffer`] with a channel stride smaller than the
+    /// number of samples per channel, or too large for the data.
+    InvalidChannelStride { channel_stride: u32 },
+
+    /// Error when trying to construct an [`AudioBuffer`] from bytes that are not aligned for
+    /// [`Sample`].
+    MisalignedBytes,
+
+    /// Error when trying to construct an [`AudioBuffer`] from a number of bytes that is not a
+    /// multiple of the size of a [`Sample`].
+    InvalidBytesLength { len: usize },
 }
 
 impl std::error::Error for AudioBufferError {}
@@ -699,6 +1757,19 @@ impl std::fmt::Display for AudioBufferError {
                     "frame with index {frame_index} of size {frame_size} out of channel bounds"
                 )
             }
+            Self::InvalidChannelStride { channel_stride } => {
+                write!(
+                    f,
+                    "invalid channel stride {channel_stride} for the number of samples and data length"
+                )
+            }
+            Self::MisalignedBytes => write!(f, "audio buffer bytes are not aligned for samples"),
+            Self::InvalidBytesLength { len } => {
+                write!(
+                    f,
+                    "invalid length of audio buffer bytes: {len} is not a multiple of the sample size"
+                )
+            }
         }
     }
 }
@@ -726,6 +1797,12 @@ pub enum AudioBufferOperationError {
 
     /// Audio buffers have mismatched total sample count for conversion.
     TotalSampleMismatch { self_count: u32, other_count: u32 },
+
+    /// Destination slice length of a sample format conversion does not match audio buffer length.
+    ConversionLengthMismatch { dst_len: usize, expected_len: u32 },
+
+    /// Channel index is not less than the number of channels of the audio buffer.
+    ChannelOutOfBounds { index: u32, num_channels: u32 },
 }
 
 impl std::error::Error for AudioBufferOperationError {}
@@ -768,6 +1845,20 @@ impl std::fmt::Display for AudioBufferOperationError {
                 f,
                 "total sample count mismatch: buffer has {self_count} samples, other has {other_count}"
             ),
+            Self::ConversionLengthMismatch {
+                dst_len,
+                expected_len,
+            } => write!(
+                f,
+                "conversion destination length {dst_len} does not match expected length {expected_len}"
+            ),
+            Self::ChannelOutOfBounds {
+                index,
+                num_channels,
+            } => write!(
+                f,
+                "channel index {index} out of bounds for buffer with {num_channels} channels"
+            ),
         }
     }
 }
@@ -794,6 +1885,31 @@ pub const fn num_ambisonics_channels(order: u32) -> u32 {
     (order + 1) * (order + 1)
 }
 
+/// Returns the ambisonics order corresponding to a given number of channels, or `None` if the
+/// number of channels is not of the form `(order + 1)²`.
+///
+/// This is the inverse of [`num_ambisonics_channels`].
+///
+/// # Examples
+///
+/// ```
+/// # use audionimbus::*;
+/// const FOA_ORDER: Option<u32> = ambisonics_order_from_channels(4);
+/// assert_eq!(FOA_ORDER, Some(1));
+///
+/// assert_eq!(ambisonics_order_from_channels(16), Some(3));
+/// assert_eq!(ambisonics_order_from_channels(6), None);
+/// assert_eq!(ambisonics_order_from_channels(0), None);
+/// ```
+pub const fn ambisonics_order_from_channels(num_channels: u32) -> Option<u32> {
+    let root = num_channels.isqrt();
+    if root == 0 || root * root != num_channels {
+        return None;
+    }
+
+    Some(root - 1)
+}
+
 /// Describes the channel count requirement for an audio buffer.
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum ChannelRequirement {
@@ -891,6 +2007,84 @@ mod tests {
         }
     }
 
+    mod try_output {
+        use super::*;
+
+        #[test]
+        fn test_valid() {
+            let mut data: Vec<Sample> = vec![0.0; 8];
+            let audio_buffer = AudioBuffer::try_output(&mut data, 4).unwrap();
+            assert_eq!(audio_buffer.num_channels(), 4);
+            assert_eq!(audio_buffer.num_samples(), 2);
+        }
+
+        #[test]
+        fn test_invalid_num_channels() {
+            let mut data: Vec<Sample> = vec![0.0; 10];
+            assert!(matches!(
+                AudioBuffer::try_output(&mut data, 3),
+                Err(AudioBufferError::InvalidNumChannels { num_channels: 3 }),
+            ));
+        }
+    }
+
+    #[cfg(feature = "bytemuck")]
+    mod as_bytes {
+        use super::*;
+
+        #[test]
+        fn test_round_trip() {
+            let mut data: Vec<Sample> = vec![0.1, 0.2, 0.3, 0.4, 0.5, 0.6];
+            let audio_buffer = AudioBuffer::try_output(&mut data, 3).unwrap();
+            let bytes = audio_buffer.as_bytes().unwrap();
+            assert_eq!(bytes.len(), 6 * std::mem::size_of::<Sample>());
+
+            let received = AudioBuffer::try_from_bytes(bytes, 3).unwrap();
+            assert_eq!(received.num_channels(), 3);
+            assert_eq!(received.num_samples(), 2);
+            let channels: Vec<&[Sample]> = received.channels().collect();
+            assert_eq!(channels, [[0.1, 0.2], [0.3, 0.4], [0.5, 0.6]]);
+        }
+
+        #[test]
+        fn test_non_contiguous_frame() {
+            let data: Vec<Sample> = vec![0.0; 8];
+            let audio_buffer = AudioBuffer::try_with_data_and_settings(
+                &data,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+
+            let frame = audio_buffer.frames(2).next().unwrap();
+            assert_eq!(frame.as_bytes(), None);
+        }
+    }
+
+    #[cfg(feature = "bytemuck")]
+    mod try_from_bytes {
+        use super::*;
+
+        #[test]
+        fn test_misaligned() {
+            let data: Vec<Sample> = vec![0.0; 4];
+            let bytes: &[u8] = bytemuck::cast_slice(&data);
+            assert_eq!(
+                AudioBuffer::try_from_bytes(&bytes[1..5], 1).unwrap_err(),
+                AudioBufferError::MisalignedBytes,
+            );
+        }
+
+        #[test]
+        fn test_invalid_length() {
+            let data: Vec<Sample> = vec![0.0; 4];
+            let bytes: &[u8] = bytemuck::cast_slice(&data);
+            assert_eq!(
+                AudioBuffer::try_from_bytes(&bytes[..6], 1).unwrap_err(),
+                AudioBufferError::InvalidBytesLength { len: 6 },
+            );
+        }
+    }
+
     mod try_with_data_and_settings {
         use super::*;
 
@@ -924,6 +2118,7 @@ mod tests {
                 num_samples: Some(5),
                 frame_size: Some(3),
                 frame_index: 0,
+                channel_stride: None,
             };
 
             let result = AudioBuffer::try_with_data_and_settings(&data, settings);
@@ -960,6 +2155,7 @@ mod tests {
                 num_samples: Some(5),
                 frame_size: None,
                 frame_index: 0,
+                channel_stride: None,
             };
 
             let result = AudioBuffer::try_with_data_and_settings(&data, settings);
@@ -977,6 +2173,7 @@ mod tests {
                 num_samples: Some(0),
                 frame_size: None,
                 frame_index: 0,
+                channel_stride: None,
             };
 
             let result = AudioBuffer::try_with_data_and_settings(&data, settings);
@@ -994,6 +2191,7 @@ mod tests {
                 num_samples: Some(3),
                 frame_size: None,
                 frame_index: 0,
+                channel_stride: None,
             };
 
             let result = AudioBuffer::try_with_data_and_settings(&data, settings);
@@ -1011,6 +2209,7 @@ mod tests {
                 num_samples: Some(5),
                 frame_size: Some(3),
                 frame_index: 1,
+                channel_stride: None,
             };
 
             let result = AudioBuffer::try_with_data_and_settings(&data, settings);
@@ -1022,6 +2221,58 @@ mod tests {
                 })
             ));
         }
+
+        #[test]
+        fn test_frame_out_of_bounds_overflow() {
+            let data: Vec<Sample> = vec![0.0; 10];
+            let settings = AudioBufferSettings {
+                num_channels: Some(2),
+                num_samples: Some(5),
+                frame_size: Some(1 << 31),
+                frame_index: 1,
+                channel_stride: None,
+            };
+
+            let result = AudioBuffer::try_with_data_and_settings(&data, settings);
+            assert_eq!(
+                result.err(),
+                Some(AudioBufferError::FrameOutOfBounds {
+                    frame_size: 1 << 31,
+                    frame_index: 1
+                })
+            );
+        }
+
+        #[test]
+        fn test_empty_frame() {
+            let data: Vec<Sample> = vec![0.0; 10];
+            let settings = AudioBufferSettings {
+                frame_size: Some(0),
+                ..Default::default()
+            };
+
+            let result = AudioBuffer::try_with_data_and_settings(&data, settings);
+            assert_eq!(
+                result.err(),
+                Some(AudioBufferError::FrameOutOfBounds {
+                    frame_size: 0,
+                    frame_index: 0
+                })
+            );
+        }
+
+        #[test]
+        fn test_num_channels_and_samples_overflow() {
+            // 1073741825 * 4 wraps around to 4 in `u32`.
+            let data: Vec<Sample> = vec![0.0; 4];
+            let settings = AudioBufferSettings::with_num_channels_and_num_samples(1073741825, 4);
+
+            let result = AudioBuffer::try_with_data_and_settings(&data, settings);
+            assert_eq!(
+                result.err(),
+                Some(AudioBufferError::InvalidNumSamples { num_samples: 4 })
+            );
+        }
     }
 
     mod try_new_borrowed {
@@ -1059,246 +2310,930 @@ mod tests {
             let mut data = vec![1.0, 2.0, 3.0];
             let mut ptrs = vec![data.as_mut_ptr()];
 
-            let result = unsafe { AudioBuffer::<&[Sample], _>::try_new(&mut ptrs, 0) };
+            let result = unsafe { AudioBuffer::<&[Sample], _>::try_new(&mut ptrs, 0) };
+
+            assert!(matches!(
+                result,
+                Err(AudioBufferError::InvalidNumSamples { num_samples: 0 })
+            ));
+        }
+    }
+
+    mod try_borrowed_with_data_and_settings {
+        use super::*;
+
+        #[test]
+        fn test_valid_construction() {
+            let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+            let settings = AudioBufferSettings::with_num_channels(2);
+            let mut channel_ptrs = allocate_channel_ptrs(&data, settings).unwrap();
+
+            let buffer = AudioBuffer::try_borrowed_with_data_and_settings(
+                &data,
+                &mut channel_ptrs,
+                settings,
+            )
+            .unwrap();
+            assert_eq!(buffer.num_channels(), 2);
+            assert_eq!(buffer.num_samples(), 3);
+
+            let channels: Vec<&[Sample]> = buffer.channels().collect();
+            assert_eq!(channels[0], &[1.0, 2.0, 3.0]);
+            assert_eq!(channels[1], &[4.0, 5.0, 6.0]);
+        }
+
+        #[test]
+        fn test_invalid_channel_ptrs() {
+            let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+            let settings = AudioBufferSettings::with_num_channels(2);
+            let mut channel_ptrs = [std::ptr::null_mut(); 3];
+
+            let result = AudioBuffer::try_borrowed_with_data_and_settings(
+                &data,
+                &mut channel_ptrs,
+                settings,
+            );
+
+            assert!(matches!(
+                result,
+                Err(AudioBufferError::InvalidChannelPtrs {
+                    actual: 3,
+                    expected: 2
+                })
+            ));
+        }
+    }
+
+    mod try_from_slices {
+        use super::*;
+
+        #[test]
+        fn test_valid_construction() {
+            let channel_0 = vec![1.0, 2.0, 3.0, 4.0];
+            let channel_1 = vec![5.0, 6.0, 7.0, 8.0];
+
+            let channels: &[&[Sample]] = &[&channel_0, &channel_1];
+            let mut channel_ptrs = vec![std::ptr::null_mut(); 2];
+
+            let audio_buffer = AudioBuffer::try_from_slices(channels, &mut channel_ptrs).unwrap();
+
+            assert_eq!(audio_buffer.num_channels(), 2);
+            assert_eq!(audio_buffer.num_samples(), 4);
+
+            let mut iter = audio_buffer.channels();
+            assert_eq!(iter.next().unwrap(), &[1.0, 2.0, 3.0, 4.0]);
+            assert_eq!(iter.next().unwrap(), &[5.0, 6.0, 7.0, 8.0]);
+            assert!(iter.next().is_none());
+        }
+
+        #[test]
+        fn test_empty_channels() {
+            let empty_channels: &[&[Sample]] = &[];
+            let mut channel_ptrs = vec![];
+            let result = AudioBuffer::try_from_slices(empty_channels, &mut channel_ptrs);
+            assert!(matches!(
+                result,
+                Err(AudioBufferError::InvalidNumChannels { num_channels: 0 })
+            ));
+        }
+
+        #[test]
+        fn test_mismatched_channel_ptrs_length() {
+            let channel_0 = vec![1.0, 2.0, 3.0];
+            let channel_1 = vec![4.0, 5.0, 6.0];
+            let channels: &[&[Sample]] = &[&channel_0, &channel_1];
+
+            let mut channel_ptrs_wrong_size = vec![std::ptr::null_mut(); 1];
+            let result = AudioBuffer::try_from_slices(channels, &mut channel_ptrs_wrong_size);
+            assert!(matches!(
+                result,
+                Err(AudioBufferError::InvalidChannelPtrs {
+                    actual: 1,
+                    expected: 2
+                })
+            ));
+        }
+
+        #[test]
+        fn test_empty_channel_data() {
+            let empty_channel = vec![];
+            let channels_with_empty: &[&[Sample]] = &[&empty_channel];
+            let mut channel_ptrs = vec![std::ptr::null_mut(); 1];
+            let result = AudioBuffer::try_from_slices(channels_with_empty, &mut channel_ptrs);
+            assert!(matches!(
+                result,
+                Err(AudioBufferError::InvalidNumSamples { num_samples: 0 })
+            ));
+        }
+    }
+
+    mod channels_iteration {
+        use super::*;
+
+        #[test]
+        fn test_channels_iter() {
+            let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+            let buffer = AudioBuffer::try_with_data_and_settings(
+                &data,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+
+            let channels: Vec<&[Sample]> = buffer.channels().collect();
+            assert_eq!(channels.len(), 2);
+            assert_eq!(channels[0], &[1.0, 2.0, 3.0]);
+            assert_eq!(channels[1], &[4.0, 5.0, 6.0]);
+        }
+
+        #[test]
+        fn test_channels_mut_iter() {
+            let mut data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+            let mut buffer = AudioBuffer::try_with_data_and_settings(
+                &mut data,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+
+            for channel in buffer.channels_mut() {
+                for sample in channel.iter_mut() {
+                    *sample *= 2.0;
+                }
+            }
+
+            assert_eq!(data, vec![2.0, 4.0, 6.0, 8.0, 10.0, 12.0]);
+        }
+    }
+
+    mod audio_buffer_settings {
+        use super::*;
+
+        #[test]
+        fn test_with_num_channels() {
+            let settings = AudioBufferSettings::with_num_channels(4);
+            assert_eq!(settings.num_channels, Some(4));
+            assert_eq!(settings.num_samples, None);
+        }
+
+        #[test]
+        fn test_with_num_samples() {
+            let settings = AudioBufferSettings::with_num_samples(1024);
+            assert_eq!(settings.num_channels, None);
+            assert_eq!(settings.num_samples, Some(1024));
+        }
+
+        #[test]
+        fn test_with_num_channels_and_num_samples() {
+            let settings = AudioBufferSettings::with_num_channels_and_num_samples(2, 512);
+            assert_eq!(settings.num_channels, Some(2));
+            assert_eq!(settings.num_samples, Some(512));
+        }
+
+        #[test]
+        fn test_num_channels_and_samples_inference() {
+            let data = vec![0.0; 12];
+
+            // Infer both from data length
+            let settings = AudioBufferSettings::default();
+            let (channels, samples) = settings.num_channels_and_samples(&data).unwrap();
+            assert_eq!(channels, 1);
+            assert_eq!(samples, 12);
+
+            // Infer samples from channels
+            let settings = AudioBufferSettings::with_num_channels(3);
+            let (channels, samples) = settings.num_channels_and_samples(&data).unwrap();
+            assert_eq!(channels, 3);
+            assert_eq!(samples, 4);
+
+            // Infer channels from samples
+            let settings = AudioBufferSettings::with_num_samples(4);
+            let (channels, samples) = settings.num_channels_and_samples(&data).unwrap();
+            assert_eq!(channels, 3);
+            assert_eq!(samples, 4);
+        }
+    }
+
+    mod invert_phase {
+        use super::*;
+
+        #[test]
+        fn test_all_channels() {
+            let mut data: Vec<Sample> = vec![1.0, -0.5, 0.25, 0.0];
+            let mut audio_buffer = AudioBuffer::try_with_data_and_settings(
+                &mut data,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+
+            audio_buffer.invert_phase();
+            assert_eq!(data, [-1.0, 0.5, -0.25, -0.0]);
+        }
+
+        #[test]
+        fn test_channel_out_of_bounds() {
+            let mut data: Vec<Sample> = vec![1.0; 4];
+            let mut audio_buffer = AudioBuffer::try_with_data_and_settings(
+                &mut data,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+
+            assert_eq!(
+                audio_buffer.invert_channel(2),
+                Err(AudioBufferOperationError::ChannelOutOfBounds {
+                    index: 2,
+                    num_channels: 2
+                })
+            );
+        }
+    }
+
+    mod for_each_sample {
+        use super::*;
+
+        #[test]
+        fn test_order() {
+            let mut data: Vec<Sample> = vec![0.0; 6];
+            let mut audio_buffer = AudioBuffer::try_with_data_and_settings(
+                &mut data,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+
+            let mut visited = Vec::new();
+            audio_buffer.for_each_sample(|channel, sample, value| {
+                visited.push((channel, sample));
+                *value = (10 * channel + sample) as Sample;
+            });
+
+            assert_eq!(visited, [(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]);
+            assert_eq!(data, [0.0, 1.0, 2.0, 10.0, 11.0, 12.0]);
+        }
+
+        #[test]
+        fn test_strided() {
+            let mut data: Vec<Sample> = vec![1.0, 2.0, 0.0, 3.0, 4.0];
+            let mut audio_buffer = AudioBuffer::try_with_data_and_settings(
+                &mut data,
+                AudioBufferSettings::with_strided(2, 2, 3),
+            )
+            .unwrap();
+
+            audio_buffer.map(|sample| sample * 2.0);
+
+            // Padding between channels is left untouched.
+            assert_eq!(data, [2.0, 4.0, 0.0, 6.0, 8.0]);
+        }
+    }
+
+    mod normalize {
+        use super::*;
+
+        #[test]
+        fn test_scales_to_target_peak() {
+            let mut data: Vec<Sample> = vec![0.1, -0.4, 0.2, 0.3];
+            let mut audio_buffer = AudioBuffer::try_with_data_and_settings(
+                &mut data,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+
+            let gain = audio_buffer.normalize(0.8);
+
+            assert_eq!(gain, 2.0);
+            assert_eq!(data, [0.2, -0.8, 0.4, 0.6]);
+        }
+
+        #[test]
+        fn test_undo() {
+            let original: Vec<Sample> = vec![0.3, -0.7, 0.05];
+            let mut data = original.clone();
+            let mut audio_buffer = AudioBuffer::try_with_data(&mut data).unwrap();
+
+            let gain = audio_buffer.normalize(1.0);
+            audio_buffer.map(|sample| sample / gain);
+
+            for (sample, expected) in data.iter().zip(&original) {
+                assert!((sample - expected).abs() < 1e-6);
+            }
+        }
+
+        #[test]
+        fn test_silent() {
+            let mut data: Vec<Sample> = vec![0.0; 4];
+            let mut audio_buffer = AudioBuffer::try_with_data(&mut data).unwrap();
+
+            assert_eq!(audio_buffer.normalize(1.0), 1.0);
+            assert_eq!(data, [0.0; 4]);
+        }
+    }
+
+    mod trim_silence {
+        use super::*;
+
+        #[test]
+        fn test_preserves_channel_alignment() {
+            let data: Vec<Sample> = vec![
+                0.0, 0.0, 0.1, 0.2, 0.0, 0.0, // Channel 0
+                0.0, 0.3, 0.0, 0.0, 0.0, 0.0, // Channel 1
+            ];
+            let audio_buffer = AudioBuffer::try_with_data_and_settings(
+                &data,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+
+            let trimmed = audio_buffer.trim_silence(0.0).unwrap();
+
+            assert_eq!(trimmed.num_channels(), 2);
+            assert_eq!(trimmed.num_samples(), 3);
+            let channels: Vec<&[Sample]> = trimmed.channels().collect();
+            assert_eq!(channels, [[0.0, 0.1, 0.2], [0.3, 0.0, 0.0]]);
+        }
+
+        #[test]
+        fn test_threshold() {
+            let data: Vec<Sample> = vec![0.01, -0.02, 0.5, -0.6, 0.01];
+            let audio_buffer = AudioBuffer::try_with_data(&data).unwrap();
+
+            let trimmed = audio_buffer.trim_silence(0.05).unwrap();
+
+            assert_eq!(trimmed.channels().next().unwrap(), [0.5, -0.6]);
+        }
+
+        #[test]
+        fn test_silent() {
+            let data: Vec<Sample> = vec![0.01, -0.01, 0.0, 0.0];
+            let audio_buffer = AudioBuffer::try_with_data_and_settings(
+                &data,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+
+            assert!(audio_buffer.trim_silence(0.01).is_none());
+        }
+    }
+
+    mod max_abs_diff {
+        use super::*;
+
+        #[test]
+        fn test_max_abs_diff() {
+            let a: Vec<Sample> = vec![0.0, 0.5, 1.0, -1.0];
+            let b: Vec<Sample> = vec![0.0, 0.25, 1.0, -0.5];
+            let a = AudioBuffer::try_with_data_and_settings(
+                &a,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+            let b = AudioBuffer::try_with_data_and_settings(
+                &b,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+
+            assert_eq!(a.max_abs_diff(&b), Ok(0.5));
+            assert_eq!(a.max_abs_diff(&a), Ok(0.0));
+            assert_eq!(a.approx_eq(&b, 0.5), Ok(true));
+            assert_eq!(a.approx_eq(&b, 0.25), Ok(false));
+        }
+
+        #[test]
+        fn test_nan() {
+            let a: Vec<Sample> = vec![0.0, Sample::NAN];
+            let a = AudioBuffer::try_with_data(&a).unwrap();
+
+            assert!(a.max_abs_diff(&a).unwrap().is_nan());
+            assert_eq!(a.approx_eq(&a, 1.0), Ok(false));
+        }
+
+        #[test]
+        fn test_mismatch() {
+            let a: Vec<Sample> = vec![0.0; 4];
+            let b: Vec<Sample> = vec![0.0; 2];
+            let mono = AudioBuffer::try_with_data(&a).unwrap();
+            let stereo = AudioBuffer::try_with_data_and_settings(
+                &a,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+            let short = AudioBuffer::try_with_data(&b).unwrap();
+
+            assert_eq!(
+                mono.approx_eq(&stereo, 0.0),
+                Err(AudioBufferOperationError::ChannelCountMismatch {
+                    self_num_channels: 1,
+                    other_num_channels: 2,
+                })
+            );
+            assert_eq!(
+                mono.max_abs_diff(&short),
+                Err(AudioBufferOperationError::SampleCountMismatch {
+                    self_num_samples: 4,
+                    other_num_samples: 2,
+                })
+            );
+        }
+    }
+
+    mod swap_channels {
+        use super::*;
+
+        #[test]
+        fn test_same_channel() {
+            let mut data: Vec<Sample> = vec![1.0, 2.0, 3.0, 4.0];
+            let mut audio_buffer = AudioBuffer::try_with_data_and_settings(
+                &mut data,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+
+            assert!(audio_buffer.swap_channels(1, 1).is_ok());
+            assert_eq!(data, [1.0, 2.0, 3.0, 4.0]);
+        }
+
+        #[test]
+        fn test_frame() {
+            let mut data: Vec<Sample> = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+            let settings = AudioBufferSettings {
+                num_channels: Some(2),
+                frame_size: Some(2),
+                frame_index: 0,
+                ..Default::default()
+            };
+            let mut audio_buffer =
+                AudioBuffer::try_with_data_and_settings(&mut data, settings).unwrap();
+
+            audio_buffer.swap_channels(0, 1).unwrap();
+            assert_eq!(data, [4.0, 5.0, 3.0, 1.0, 2.0, 6.0]);
+        }
+
+        #[test]
+        fn test_out_of_bounds() {
+            let mut data: Vec<Sample> = vec![1.0; 4];
+            let mut audio_buffer = AudioBuffer::try_with_data_and_settings(
+                &mut data,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+
+            assert_eq!(
+                audio_buffer.swap_channels(0, 3),
+                Err(AudioBufferOperationError::ChannelOutOfBounds {
+                    index: 3,
+                    num_channels: 2
+                })
+            );
+        }
+    }
+
+    mod with_strided {
+        use super::*;
+
+        #[test]
+        fn test_frame() {
+            let data: Vec<Sample> = (0..12).map(|i| i as Sample).collect();
+            let settings = AudioBufferSettings {
+                frame_size: Some(2),
+                frame_index: 1,
+                ..AudioBufferSettings::with_strided(2, 4, 6)
+            };
+
+            let audio_buffer = AudioBuffer::try_with_data_and_settings(&data, settings).unwrap();
+            let channels: Vec<&[Sample]> = audio_buffer.channels().collect();
+            assert_eq!(channels, [[2.0, 3.0], [8.0, 9.0]]);
+        }
+
+        #[test]
+        fn test_borrowed() {
+            let data: Vec<Sample> = vec![1.0, 2.0, 0.0, 3.0, 4.0];
+            let mut channel_ptrs = vec![std::ptr::null_mut(); 2];
+
+            let audio_buffer = AudioBuffer::try_borrowed_with_data_and_settings(
+                &data,
+                &mut channel_ptrs,
+                AudioBufferSettings::with_strided(2, 2, 3),
+            )
+            .unwrap();
+            let channels: Vec<&[Sample]> = audio_buffer.channels().collect();
+            assert_eq!(channels, [[1.0, 2.0], [3.0, 4.0]]);
+        }
+
+        #[test]
+        fn test_stride_smaller_than_num_samples() {
+            let data: Vec<Sample> = vec![0.0; 8];
+            assert_eq!(
+                AudioBufferSettings::with_strided(2, 4, 3).num_channels_and_samples(&data),
+                Err(AudioBufferError::InvalidChannelStride { channel_stride: 3 }),
+            );
+        }
+
+        #[test]
+        fn test_data_too_short() {
+            let data: Vec<Sample> = vec![0.0; 8];
+            assert_eq!(
+                AudioBufferSettings::with_strided(2, 3, 6).num_channels_and_samples(&data),
+                Err(AudioBufferError::InvalidChannelStride { channel_stride: 6 }),
+            );
+        }
+    }
+
+    mod allocate_channel_ptrs {
+        use super::*;
+
+        #[test]
+        fn test_valid() {
+            let data = vec![0.0; 12];
+            let settings = AudioBufferSettings::with_num_channels(3);
+            let ptrs = allocate_channel_ptrs(&data, settings).unwrap();
+
+            assert_eq!(ptrs.len(), 3);
+            assert!(ptrs.iter().all(|&ptr| ptr.is_null()));
+        }
+
+        #[test]
+        fn test_invalid() {
+            let data = vec![0.0; 10];
+            let settings = AudioBufferSettings {
+                num_channels: Some(3),
+                num_samples: Some(3),
+                ..Default::default()
+            };
+
+            let result = allocate_channel_ptrs(&data, settings);
+            assert!(result.is_err());
+        }
+    }
+
+    mod channel_requirement {
+        use super::*;
+
+        #[test]
+        fn test_is_satisfied_by() {
+            assert!(ChannelRequirement::Exactly(2).is_satisfied_by(2));
+            assert!(!ChannelRequirement::Exactly(2).is_satisfied_by(1));
+            assert!(ChannelRequirement::AtLeast(2).is_satisfied_by(3));
+            assert!(!ChannelRequirement::AtLeast(2).is_satisfied_by(1));
+            assert!(ChannelRequirement::Range { min: 1, max: 4 }.is_satisfied_by(3));
+            assert!(!ChannelRequirement::Range { min: 1, max: 4 }.is_satisfied_by(5));
+        }
+    }
+
+    mod frames {
+        use super::*;
+
+        #[test]
+        fn test_exact_multiple() {
+            let samples: Vec<Sample> = (0..8).map(|i| i as Sample).collect();
+            let buffer = AudioBuffer::try_with_data_and_settings(
+                &samples,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+
+            let frames: Vec<Vec<Vec<Sample>>> = buffer
+                .frames(2)
+                .map(|frame| frame.channels().map(<[Sample]>::to_vec).collect())
+                .collect();
+            assert_eq!(
+                frames,
+                vec![
+                    vec![vec![0.0, 1.0], vec![4.0, 5.0]],
+                    vec![vec![2.0, 3.0], vec![6.0, 7.0]],
+                ]
+            );
+        }
+
+        #[test]
+        fn test_shortened_last_frame() {
+            let samples: Vec<Sample> = (0..5).map(|i| i as Sample).collect();
+            let buffer = AudioBuffer::try_with_data(&samples).unwrap();
+
+            let frames: Vec<Vec<Sample>> = buffer
+                .frames(2)
+                .map(|frame| frame.channels().next().unwrap().to_vec())
+                .collect();
+            assert_eq!(frames, vec![vec![0.0, 1.0], vec![2.0, 3.0], vec![4.0]]);
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_zero_frame_size() {
+            let samples = vec![0.0; 4];
+            let buffer = AudioBuffer::try_with_data(&samples).unwrap();
+            let _ = buffer.frames(0);
+        }
+    }
+
+    mod fade_in {
+        use super::*;
+
+        #[test]
+        fn test_linear() {
+            let mut samples = vec![1.0; 2 * 8];
+            let mut buffer = AudioBuffer::try_with_data_and_settings(
+                &mut samples,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+
+            buffer.fade_in(4, FadeCurve::Linear);
+
+            let expected = [0.0, 0.25, 0.5, 0.75, 1.0, 1.0, 1.0, 1.0];
+            assert!(buffer.channels().all(|channel| channel == expected));
+        }
+
+        #[test]
+        fn test_raised_cosine() {
+            let mut samples = vec![1.0; 8];
+            let mut buffer = AudioBuffer::try_with_data(&mut samples).unwrap();
+
+            buffer.fade_in(4, FadeCurve::RaisedCosine);
+
+            assert_eq!(samples[0], 0.0);
+            assert!((samples[2] - 0.5).abs() < 1e-6);
+            assert!(samples[1] < 0.25 && samples[3] > 0.75);
+            assert_eq!(samples[4], 1.0);
+        }
+
+        #[test]
+        fn test_longer_than_buffer() {
+            let mut samples = vec![1.0; 2];
+            let mut buffer = AudioBuffer::try_with_data(&mut samples).unwrap();
+
+            buffer.fade_in(4, FadeCurve::Linear);
 
-            assert!(matches!(
-                result,
-                Err(AudioBufferError::InvalidNumSamples { num_samples: 0 })
-            ));
+            assert_eq!(samples, [0.0, 0.25]);
         }
     }
 
-    mod try_borrowed_with_data_and_settings {
+    mod fade_out {
         use super::*;
 
         #[test]
-        fn test_valid_construction() {
-            let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
-            let settings = AudioBufferSettings::with_num_channels(2);
-            let mut channel_ptrs = allocate_channel_ptrs(&data, settings).unwrap();
-
-            let buffer = AudioBuffer::try_borrowed_with_data_and_settings(
-                &data,
-                &mut channel_ptrs,
-                settings,
+        fn test_linear() {
+            let mut samples = vec![1.0; 2 * 8];
+            let mut buffer = AudioBuffer::try_with_data_and_settings(
+                &mut samples,
+                AudioBufferSettings::with_num_channels(2),
             )
             .unwrap();
-            assert_eq!(buffer.num_channels(), 2);
-            assert_eq!(buffer.num_samples(), 3);
 
-            let channels: Vec<&[Sample]> = buffer.channels().collect();
-            assert_eq!(channels[0], &[1.0, 2.0, 3.0]);
-            assert_eq!(channels[1], &[4.0, 5.0, 6.0]);
+            buffer.fade_out(4, FadeCurve::Linear);
+
+            let expected = [1.0, 1.0, 1.0, 1.0, 0.75, 0.5, 0.25, 0.0];
+            assert!(buffer.channels().all(|channel| channel == expected));
         }
 
         #[test]
-        fn test_invalid_channel_ptrs() {
-            let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
-            let settings = AudioBufferSettings::with_num_channels(2);
-            let mut channel_ptrs = [std::ptr::null_mut(); 3];
+        fn test_longer_than_buffer() {
+            let mut samples = vec![1.0; 2];
+            let mut buffer = AudioBuffer::try_with_data(&mut samples).unwrap();
 
-            let result = AudioBuffer::try_borrowed_with_data_and_settings(
-                &data,
-                &mut channel_ptrs,
-                settings,
-            );
+            buffer.fade_out(4, FadeCurve::Linear);
 
-            assert!(matches!(
-                result,
-                Err(AudioBufferError::InvalidChannelPtrs {
-                    actual: 3,
-                    expected: 2
-                })
-            ));
+            assert_eq!(samples, [0.25, 0.0]);
         }
     }
 
-    mod try_from_slices {
+    mod crossfade {
         use super::*;
 
         #[test]
-        fn test_valid_construction() {
-            let channel_0 = vec![1.0, 2.0, 3.0, 4.0];
-            let channel_1 = vec![5.0, 6.0, 7.0, 8.0];
-
-            let channels: &[&[Sample]] = &[&channel_0, &channel_1];
-            let mut channel_ptrs = vec![std::ptr::null_mut(); 2];
-
-            let audio_buffer = AudioBuffer::try_from_slices(channels, &mut channel_ptrs).unwrap();
-
-            assert_eq!(audio_buffer.num_channels(), 2);
-            assert_eq!(audio_buffer.num_samples(), 4);
+        fn test_equal_power() {
+            let from = vec![1.0; 4];
+            let to = vec![-1.0; 4];
+            let mut out = vec![0.0; 4];
+
+            crossfade(
+                &AudioBuffer::try_with_data(&from).unwrap(),
+                &AudioBuffer::try_with_data(&to).unwrap(),
+                &mut AudioBuffer::try_with_data(&mut out).unwrap(),
+                0.0,
+                1.0,
+            )
+            .unwrap();
 
-            let mut iter = audio_buffer.channels();
-            assert_eq!(iter.next().unwrap(), &[1.0, 2.0, 3.0, 4.0]);
-            assert_eq!(iter.next().unwrap(), &[5.0, 6.0, 7.0, 8.0]);
-            assert!(iter.next().is_none());
+            assert_eq!(out[0], 1.0);
+            // Halfway through, both signals are attenuated by 3 dB and cancel out.
+            assert!(out[2].abs() < 1e-6);
+            assert!(out[3] < 0.0);
         }
 
         #[test]
-        fn test_empty_channels() {
-            let empty_channels: &[&[Sample]] = &[];
-            let mut channel_ptrs = vec![];
-            let result = AudioBuffer::try_from_slices(empty_channels, &mut channel_ptrs);
-            assert!(matches!(
-                result,
-                Err(AudioBufferError::InvalidNumChannels { num_channels: 0 })
-            ));
+        fn test_constant_progress() {
+            let from = vec![1.0; 4];
+            let to = vec![2.0; 4];
+            let mut out = vec![0.0; 4];
+
+            crossfade(
+                &AudioBuffer::try_with_data(&from).unwrap(),
+                &AudioBuffer::try_with_data(&to).unwrap(),
+                &mut AudioBuffer::try_with_data(&mut out).unwrap(),
+                1.0,
+                1.0,
+            )
+            .unwrap();
+
+            assert!(out.iter().all(|&sample| (sample - 2.0).abs() < 1e-6));
         }
 
         #[test]
-        fn test_mismatched_channel_ptrs_length() {
-            let channel_0 = vec![1.0, 2.0, 3.0];
-            let channel_1 = vec![4.0, 5.0, 6.0];
-            let channels: &[&[Sample]] = &[&channel_0, &channel_1];
+        fn test_sample_count_mismatch() {
+            let from = vec![1.0; 4];
+            let to = vec![1.0; 2];
+            let mut out = vec![0.0; 4];
+
+            let result = crossfade(
+                &AudioBuffer::try_with_data(&from).unwrap(),
+                &AudioBuffer::try_with_data(&to).unwrap(),
+                &mut AudioBuffer::try_with_data(&mut out).unwrap(),
+                0.0,
+                1.0,
+            );
 
-            let mut channel_ptrs_wrong_size = vec![std::ptr::null_mut(); 1];
-            let result = AudioBuffer::try_from_slices(channels, &mut channel_ptrs_wrong_size);
-            assert!(matches!(
+            assert_eq!(
                 result,
-                Err(AudioBufferError::InvalidChannelPtrs {
-                    actual: 1,
-                    expected: 2
+                Err(AudioBufferOperationError::SampleCountMismatch {
+                    self_num_samples: 4,
+                    other_num_samples: 2,
                 })
-            ));
-        }
-
-        #[test]
-        fn test_empty_channel_data() {
-            let empty_channel = vec![];
-            let channels_with_empty: &[&[Sample]] = &[&empty_channel];
-            let mut channel_ptrs = vec![std::ptr::null_mut(); 1];
-            let result = AudioBuffer::try_from_slices(channels_with_empty, &mut channel_ptrs);
-            assert!(matches!(
-                result,
-                Err(AudioBufferError::InvalidNumSamples { num_samples: 0 })
-            ));
+            );
         }
     }
 
-    mod channels_iteration {
+    mod frames_padded {
         use super::*;
 
         #[test]
-        fn test_channels_iter() {
-            let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        fn test_padded_last_frame() {
+            let samples: Vec<Sample> = (0..6).map(|i| i as Sample).collect();
             let buffer = AudioBuffer::try_with_data_and_settings(
-                &data,
+                &samples,
                 AudioBufferSettings::with_num_channels(2),
             )
             .unwrap();
 
-            let channels: Vec<&[Sample]> = buffer.channels().collect();
-            assert_eq!(channels.len(), 2);
-            assert_eq!(channels[0], &[1.0, 2.0, 3.0]);
-            assert_eq!(channels[1], &[4.0, 5.0, 6.0]);
+            let mut padding = Vec::new();
+            let frames: Vec<Vec<Vec<Sample>>> = buffer
+                .frames_padded(2, &mut padding)
+                .map(|frame| frame.channels().map(<[Sample]>::to_vec).collect())
+                .collect();
+            assert_eq!(
+                frames,
+                vec![
+                    vec![vec![0.0, 1.0], vec![3.0, 4.0]],
+                    vec![vec![2.0, 0.0], vec![5.0, 0.0]],
+                ]
+            );
         }
 
         #[test]
-        fn test_channels_mut_iter() {
-            let mut data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
-            let mut buffer = AudioBuffer::try_with_data_and_settings(
-                &mut data,
-                AudioBufferSettings::with_num_channels(2),
-            )
-            .unwrap();
-
-            for channel in buffer.channels_mut() {
-                for sample in channel.iter_mut() {
-                    *sample *= 2.0;
-                }
-            }
+        fn test_exact_multiple() {
+            let samples = vec![1.0; 4];
+            let buffer = AudioBuffer::try_with_data(&samples).unwrap();
 
-            assert_eq!(data, vec![2.0, 4.0, 6.0, 8.0, 10.0, 12.0]);
+            let mut padding = Vec::new();
+            assert_eq!(buffer.frames_padded(2, &mut padding).count(), 2);
+            assert!(padding.is_empty());
         }
     }
 
-    mod audio_buffer_settings {
+    mod remix {
         use super::*;
 
         #[test]
-        fn test_with_num_channels() {
-            let settings = AudioBufferSettings::with_num_channels(4);
-            assert_eq!(settings.num_channels, Some(4));
-            assert_eq!(settings.num_samples, None);
-        }
+        fn test_mono_to_stereo() {
+            let mono = vec![0.5; 4];
+            let mono_buffer = AudioBuffer::try_with_data(&mono).unwrap();
 
-        #[test]
-        fn test_with_num_samples() {
-            let settings = AudioBufferSettings::with_num_samples(1024);
-            assert_eq!(settings.num_channels, None);
-            assert_eq!(settings.num_samples, Some(1024));
+            let mut stereo = vec![0.0; 8];
+            let mut stereo_buffer = AudioBuffer::try_with_data_and_settings(
+                &mut stereo,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+
+            assert!(stereo_buffer.remix(&mono_buffer).is_ok());
+            assert_eq!(stereo, vec![0.5; 8]);
         }
 
         #[test]
-        fn test_with_num_channels_and_num_samples() {
-            let settings = AudioBufferSettings::with_num_channels_and_num_samples(2, 512);
-            assert_eq!(settings.num_channels, Some(2));
-            assert_eq!(settings.num_samples, Some(512));
+        fn test_stereo_to_mono() {
+            let stereo = vec![1.0, 1.0, 0.0, 0.0];
+            let stereo_buffer = AudioBuffer::try_with_data_and_settings(
+                &stereo,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+
+            let mut mono = vec![0.0; 2];
+            let mut mono_buffer = AudioBuffer::try_with_data(&mut mono).unwrap();
+
+            assert!(mono_buffer.remix(&stereo_buffer).is_ok());
+            assert_eq!(mono, vec![0.5, 0.5]);
         }
 
         #[test]
-        fn test_num_channels_and_samples_inference() {
-            let data = vec![0.0; 12];
+        fn test_surround_5_1_to_stereo() {
+            // One sample per channel: FL, FR, FC, LFE, SL, SR.
+            let surround = vec![1.0, 0.0, 1.0, 1.0, 1.0, 0.0];
+            let surround_buffer = AudioBuffer::try_with_data_and_settings(
+                &surround,
+                AudioBufferSettings::with_num_channels(6),
+            )
+            .unwrap();
 
-            // Infer both from data length
-            let settings = AudioBufferSettings::default();
-            let (channels, samples) = settings.num_channels_and_samples(&data).unwrap();
-            assert_eq!(channels, 1);
-            assert_eq!(samples, 12);
+            let mut stereo = vec![0.0; 2];
+            let mut stereo_buffer = AudioBuffer::try_with_data_and_settings(
+                &mut stereo,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
 
-            // Infer samples from channels
-            let settings = AudioBufferSettings::with_num_channels(3);
-            let (channels, samples) = settings.num_channels_and_samples(&data).unwrap();
-            assert_eq!(channels, 3);
-            assert_eq!(samples, 4);
+            assert!(stereo_buffer.remix(&surround_buffer).is_ok());
 
-            // Infer channels from samples
-            let settings = AudioBufferSettings::with_num_samples(4);
-            let (channels, samples) = settings.num_channels_and_samples(&data).unwrap();
-            assert_eq!(channels, 3);
-            assert_eq!(samples, 4);
+            let minus_3_db = std::f32::consts::FRAC_1_SQRT_2;
+            assert!((stereo[0] - (1.0 + 2.0 * minus_3_db)).abs() < 1e-6);
+            assert!((stereo[1] - minus_3_db).abs() < 1e-6);
         }
-    }
-
-    mod allocate_channel_ptrs {
-        use super::*;
 
         #[test]
-        fn test_valid() {
-            let data = vec![0.0; 12];
-            let settings = AudioBufferSettings::with_num_channels(3);
-            let ptrs = allocate_channel_ptrs(&data, settings).unwrap();
+        fn test_fallback_zero_fills_and_truncates() {
+            let stereo = vec![1.0, 2.0];
+            let stereo_buffer = AudioBuffer::try_with_data_and_settings(
+                &stereo,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
 
-            assert_eq!(ptrs.len(), 3);
-            assert!(ptrs.iter().all(|&ptr| ptr.is_null()));
+            let mut quad = vec![9.0; 4];
+            let mut quad_buffer = AudioBuffer::try_with_data_and_settings(
+                &mut quad,
+                AudioBufferSettings::with_num_channels(4),
+            )
+            .unwrap();
+            assert!(quad_buffer.remix(&stereo_buffer).is_ok());
+            assert_eq!(quad, vec![1.0, 2.0, 0.0, 0.0]);
+
+            let quad = vec![1.0, 2.0, 3.0, 4.0];
+            let quad_buffer = AudioBuffer::try_with_data_and_settings(
+                &quad,
+                AudioBufferSettings::with_num_channels(4),
+            )
+            .unwrap();
+
+            let mut stereo = vec![0.0; 2];
+            let mut stereo_buffer = AudioBuffer::try_with_data_and_settings(
+                &mut stereo,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+            assert!(stereo_buffer.remix(&quad_buffer).is_ok());
+            assert_eq!(stereo, vec![1.0, 2.0]);
         }
 
         #[test]
-        fn test_invalid() {
-            let data = vec![0.0; 10];
-            let settings = AudioBufferSettings {
-                num_channels: Some(3),
-                num_samples: Some(3),
-                ..Default::default()
-            };
+        fn test_sample_count_mismatch() {
+            let mono = vec![0.5; 4];
+            let mono_buffer = AudioBuffer::try_with_data(&mono).unwrap();
 
-            let result = allocate_channel_ptrs(&data, settings);
-            assert!(result.is_err());
+            let mut stereo = vec![0.0; 4];
+            let mut stereo_buffer = AudioBuffer::try_with_data_and_settings(
+                &mut stereo,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+
+            assert_eq!(
+                stereo_buffer.remix(&mono_buffer),
+                Err(AudioBufferOperationError::SampleCountMismatch {
+                    self_num_samples: 2,
+                    other_num_samples: 4,
+                })
+            );
         }
     }
 
-    mod channel_requirement {
+    mod ambisonics_order_from_channels {
         use super::*;
 
         #[test]
-        fn test_is_satisfied_by() {
-            assert!(ChannelRequirement::Exactly(2).is_satisfied_by(2));
-            assert!(!ChannelRequirement::Exactly(2).is_satisfied_by(1));
-            assert!(ChannelRequirement::AtLeast(2).is_satisfied_by(3));
-            assert!(!ChannelRequirement::AtLeast(2).is_satisfied_by(1));
-            assert!(ChannelRequirement::Range { min: 1, max: 4 }.is_satisfied_by(3));
-            assert!(!ChannelRequirement::Range { min: 1, max: 4 }.is_satisfied_by(5));
+        fn test_round_trip() {
+            for order in 0..=10 {
+                assert_eq!(
+                    ambisonics_order_from_channels(num_ambisonics_channels(order)),
+                    Some(order)
+                );
+            }
+        }
+
+        #[test]
+        fn test_invalid() {
+            assert_eq!(ambisonics_order_from_channels(0), None);
+            assert_eq!(ambisonics_order_from_channels(2), None);
+            assert_eq!(ambisonics_order_from_channels(15), None);
+            assert_eq!(ambisonics_order_from_channels(17), None);
+            assert_eq!(ambisonics_order_from_channels(u32::MAX), None);
         }
     }
 
@@ -1431,6 +3366,74 @@ mod tests {
         }
     }
 
+    mod to_i16 {
+        use super::*;
+
+        #[test]
+        fn test_round_trip() {
+            let pcm: Vec<i16> = vec![i16::MIN, -1, 0, 1, 12345, i16::MAX];
+            let mut data = Vec::new();
+            let audio_buffer = AudioBuffer::try_from_i16(&pcm, &mut data, 2).unwrap();
+            assert_eq!(audio_buffer.num_channels(), 2);
+            assert_eq!(audio_buffer.num_samples(), 3);
+
+            let mut converted = vec![0; 6];
+            audio_buffer.to_i16(&mut converted).unwrap();
+            assert_eq!(converted, pcm);
+        }
+
+        #[test]
+        fn test_saturation() {
+            let data: Vec<Sample> = vec![-2.0, -1.0, 1.0, 2.0, f32::NAN];
+            let audio_buffer = AudioBuffer::try_with_data(&data).unwrap();
+
+            let mut converted = vec![0; 5];
+            audio_buffer.to_i16(&mut converted).unwrap();
+            assert_eq!(converted, [i16::MIN, i16::MIN, i16::MAX, i16::MAX, 0]);
+        }
+
+        #[test]
+        fn test_length_mismatch() {
+            let data: Vec<Sample> = vec![0.0; 4];
+            let audio_buffer = AudioBuffer::try_with_data(&data).unwrap();
+
+            let mut converted = vec![0; 3];
+            assert_eq!(
+                audio_buffer.to_i16(&mut converted),
+                Err(AudioBufferOperationError::ConversionLengthMismatch {
+                    dst_len: 3,
+                    expected_len: 4,
+                }),
+            );
+        }
+    }
+
+    mod try_from_f64 {
+        use super::*;
+
+        #[test]
+        fn test_round_trip() {
+            let samples: Vec<f64> = vec![0.5, -0.25, 0.125, 1.0];
+            let mut data = Vec::new();
+            let audio_buffer = AudioBuffer::try_from_f64(&samples, &mut data, 2).unwrap();
+            assert_eq!(audio_buffer.num_channels(), 2);
+            assert_eq!(audio_buffer.num_samples(), 2);
+
+            let mut converted = vec![0.0; 4];
+            audio_buffer.to_f64(&mut converted).unwrap();
+            assert_eq!(converted, samples);
+        }
+
+        #[test]
+        fn test_empty() {
+            let mut data = Vec::new();
+            assert_eq!(
+                AudioBuffer::try_from_f64(&[], &mut data, 1).unwrap_err(),
+                AudioBufferError::EmptyData,
+            );
+        }
+    }
+
     mod deinterleave {
         use super::*;
 
@@ -6,7 +6,7 @@ use crate::geometry::Sphere;
 ///
 /// Each probe batch may store multiple layers of data, such as reverb, static source reflections, or pathing.
 /// Each layer can be accessed using an identifier.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum BakedDataIdentifier {
     /// Reflections.
     ///
@@ -66,7 +66,7 @@ impl From<BakedDataIdentifier> for audionimbus_sys::IPLBakedDataIdentifier {
 }
 
 /// The different ways in which the source and listener positions used to generate baked data can vary as a function of probe position.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum BakedDataVariation {
     /// At each probe, baked data is calculated with both the source and the listener at the probe position.
     /// This is useful for modeling traditional reverbs, which depend only on the listener’s position (or only on the source’s position).
@@ -3,6 +3,10 @@
 pub enum BakeError {
     /// Another bake operation is already in progress.
     BakeInProgress,
+
+    /// [`ReflectionsBakeParams::irradiance_min_distance`](crate::ReflectionsBakeParams::irradiance_min_distance)
+    /// is below [`MIN_IRRADIANCE_MIN_DISTANCE`](crate::MIN_IRRADIANCE_MIN_DISTANCE), or is `NaN`.
+    IrradianceMinDistanceTooSmall,
 }
 
 impl std::error::Error for BakeError {}
@@ -13,6 +17,56 @@ impl std::fmt::Display for BakeError {
             Self::BakeInProgress => {
                 write!(f, "another bake operation is already in progress")
             }
+            Self::IrradianceMinDistanceTooSmall => {
+                write!(
+                    f,
+                    "irradiance minimum distance must be at least {}m",
+                    crate::MIN_IRRADIANCE_MIN_DISTANCE
+                )
+            }
+        }
+    }
+}
+
+/// Errors that can occur when building bake parameters.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BakeParamsError {
+    /// The saved duration is longer than the simulated duration.
+    SavedDurationExceedsSimulatedDuration {
+        /// The saved duration in seconds.
+        saved: f32,
+        /// The simulated duration in seconds.
+        simulated: f32,
+    },
+
+    /// The irradiance minimum distance is below [`MIN_IRRADIANCE_MIN_DISTANCE`](crate::MIN_IRRADIANCE_MIN_DISTANCE), or is `NaN`.
+    IrradianceMinDistanceTooSmall {
+        /// The requested distance in meters.
+        requested: f32,
+        /// The minimum allowed distance in meters.
+        min: f32,
+    },
+}
+
+impl std::error::Error for BakeParamsError {}
+
+impl std::fmt::Display for BakeParamsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::SavedDurationExceedsSimulatedDuration { saved, simulated } => {
+                write!(
+                    f,
+                    "saved duration of {}s exceeds simulated duration of {}s",
+                    saved, simulated
+                )
+            }
+            Self::IrradianceMinDistanceTooSmall { requested, min } => {
+                write!(
+                    f,
+                    "requested irradiance minimum distance of {}m, but minimum is {}m",
+                    requested, min
+                )
+            }
         }
     }
 }
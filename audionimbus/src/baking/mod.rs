@@ -14,6 +14,87 @@
 //! - [`PathBaker`]: Precomputes pathing data, an alternative simulation method that finds
 //!   the shortest unoccluded paths from sources to listeners by traveling between probes.
 //!   Pathing requires probe generation (see [`PathEffect`]) and is typically baked offline.
+//!
+//! Like real-time reflections, baked reflections are not deterministic: see the
+//! [simulation documentation](crate::simulation#determinism).
+//!
+//! ## Workflow
+//!
+//! 1. Generate probes throughout a [`Scene`] into a [`ProbeArray`] with
+//!    [`ProbeArray::generate_probes`].
+//! 2. Add them to a [`ProbeBatch`] with [`ProbeBatch::add_probe_array`], then call
+//!    [`ProbeBatch::commit`].
+//! 3. Bake data into the probe batch with one of the bakers.
+//! 4. Save the probe batch with [`ProbeBatch::save`], to load it later with [`ProbeBatch::load`].
+//!
+//! ```
+//! # use audionimbus::*;
+//! let context = Context::default();
+//!
+//! let mut scene = Scene::try_new(&context)?;
+//! let vertices = [
+//!     Point::new(-5.0, 0.0, -5.0),
+//!     Point::new(5.0, 0.0, -5.0),
+//!     Point::new(5.0, 0.0, 5.0),
+//!     Point::new(-5.0, 0.0, 5.0),
+//! ];
+//! let static_mesh = StaticMesh::try_new(
+//!     &scene,
+//!     &StaticMeshSettings {
+//!         vertices: &vertices,
+//!         triangles: &[Triangle::new(0, 1, 2), Triangle::new(0, 2, 3)],
+//!         material_indices: &[0, 0],
+//!         materials: &[Material::default()],
+//!     },
+//! )?;
+//! scene.add_static_mesh(static_mesh);
+//! scene.commit();
+//!
+//! // 1. Generate probes.
+//! let mut probe_array = ProbeArray::try_new(&context)?;
+//! probe_array.generate_probes(
+//!     &scene,
+//!     &ProbeGenerationParams::Centroid {
+//!         transform: Matrix4::IDENTITY,
+//!     },
+//! );
+//!
+//! // 2. Add them to a probe batch.
+//! let mut probe_batch = ProbeBatch::try_new(&context)?;
+//! probe_batch.add_probe_array(&probe_array);
+//! probe_batch.commit();
+//!
+//! // 3. Bake.
+//! let identifier = BakedDataIdentifier::Reflections {
+//!     variation: BakedDataVariation::Reverb,
+//! };
+//! ReflectionsBaker::<DefaultRayTracer>::new().bake(
+//!     &context,
+//!     &mut probe_batch,
+//!     &scene,
+//!     ReflectionsBakeParams {
+//!         identifier,
+//!         bake_flags: ReflectionsBakeFlags::BAKE_PARAMETRIC,
+//!         num_rays: 256,
+//!         num_diffuse_samples: 16,
+//!         num_bounces: 4,
+//!         simulated_duration: 1.0,
+//!         saved_duration: 1.0,
+//!         order: 1,
+//!         num_threads: 1,
+//!         irradiance_min_distance: 1.0,
+//!         bake_batch_size: 1,
+//!     },
+//! )?;
+//! assert!(probe_batch.data_size(identifier) > 0);
+//!
+//! // 4. Save.
+//! let mut serialized_object = SerializedObject::try_new(&context)?;
+//! probe_batch.save(&mut serialized_object);
+//! let bytes = serialized_object.to_vec();
+//! # let _ = bytes;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
 
 use std::sync::Mutex;
 
@@ -22,7 +103,9 @@ use crate::effect::pathing::PathEffect;
 #[cfg(doc)]
 use crate::effect::reflections::ReflectionEffect;
 #[cfg(doc)]
-use crate::probe::ProbeBatch;
+use crate::geometry::Scene;
+#[cfg(doc)]
+use crate::probe::{ProbeArray, ProbeBatch};
 #[cfg(doc)]
 use crate::simulation::Simulator;
 
@@ -38,7 +121,9 @@ pub mod pathing;
 pub use pathing::{PathBakeParams, PathBaker};
 
 pub mod reflections;
-pub use reflections::{ReflectionsBakeFlags, ReflectionsBakeParams, ReflectionsBaker};
+pub use reflections::{
+    ReflectionsBakeFlags, ReflectionsBakeParams, ReflectionsBakeParamsBuilder, ReflectionsBaker,
+};
 
 #[cfg(test)]
 mod tests {
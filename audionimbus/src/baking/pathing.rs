@@ -2,7 +2,7 @@
 
 use super::BakedDataIdentifier;
 use super::{BAKE_LOCK, BakeError};
-use crate::callback::ProgressCallback;
+use crate::callback::{ProgressCallback, ProgressFn};
 use crate::context::Context;
 use crate::geometry::Scene;
 use crate::probe::ProbeBatch;
@@ -77,7 +77,40 @@ impl<T: RayTracer> PathBaker<T> {
             probe_batch,
             scene,
             params,
-            Some(progress_callback),
+            Some(progress_callback.as_raw_parts()),
+        )
+    }
+
+    /// Bakes a single layer of pathing data in a probe batch, reporting progress to a closure.
+    ///
+    /// `progress` is called with the fraction of the bake that has been completed, between 0.0
+    /// and 1.0.
+    /// Unlike [`Self::bake_with_progress_callback`], the closure may borrow and mutate local state.
+    ///
+    /// Only one bake can be in progress at any point in time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BakeError`] if another bake operation is already in progress.
+    pub fn bake_with_progress_fn<F>(
+        &self,
+        context: &Context,
+        probe_batch: &mut ProbeBatch,
+        scene: &Scene<T>,
+        params: PathBakeParams,
+        mut progress: F,
+    ) -> Result<(), BakeError>
+    where
+        F: FnMut(f32) + Send,
+    {
+        let progress = ProgressFn::new(&mut progress);
+
+        self.bake_with_optional_progress_callback(
+            context,
+            probe_batch,
+            scene,
+            params,
+            Some(progress.as_raw_parts()),
         )
     }
 
@@ -94,7 +127,10 @@ impl<T: RayTracer> PathBaker<T> {
         probe_batch: &mut ProbeBatch,
         scene: &Scene<T>,
         params: PathBakeParams,
-        progress_callback: Option<ProgressCallback>,
+        progress_callback: Option<(
+            unsafe extern "C" fn(f32, *mut std::ffi::c_void),
+            *mut std::ffi::c_void,
+        )>,
     ) -> Result<(), BakeError> {
         // WORKAROUND: Steam Audio segfaults when passing `NULL` callback to `iplPathBakerBake`.
         // We pass a no-op callback instead until the fix is released.
@@ -106,10 +142,7 @@ impl<T: RayTracer> PathBaker<T> {
             .try_lock()
             .map_err(|_| BakeError::BakeInProgress)?;
 
-        let (callback, user_data) = progress_callback.as_ref().map_or(
-            (noop as _, std::ptr::null_mut()),
-            ProgressCallback::as_raw_parts,
-        );
+        let (callback, user_data) = progress_callback.unwrap_or((noop as _, std::ptr::null_mut()));
 
         let mut ffi_params = audionimbus_sys::IPLPathBakeParams {
             scene: scene.raw_ptr(),
@@ -315,5 +348,41 @@ pub mod tests {
                     .is_ok()
             );
         }
+
+        // With progress closure
+        {
+            let context = Context::default();
+            let scene = test_scene(&context);
+            let mut probe_batch = test_probe_batch(&context, &scene);
+
+            let baker = PathBaker::<DefaultRayTracer>::new();
+
+            let params = PathBakeParams {
+                identifier: BakedDataIdentifier::Pathing {
+                    variation: BakedDataVariation::Dynamic,
+                },
+                num_samples: 4,
+                radius: 0.5,
+                threshold: 0.3,
+                visibility_range: 5.0,
+                path_range: 10.0,
+                num_threads: 1,
+            };
+
+            let mut reported = Vec::new();
+            assert!(
+                baker
+                    .bake_with_progress_fn(&context, &mut probe_batch, &scene, params, |progress| {
+                        reported.push(progress);
+                    })
+                    .is_ok()
+            );
+            assert!(!reported.is_empty());
+            assert!(
+                reported
+                    .iter()
+                    .all(|progress| (0.0..=1.0).contains(progress))
+            );
+        }
     }
 }
@@ -1,14 +1,15 @@
 //! Reflections backing.
 
 use super::BakedDataIdentifier;
-use super::{BAKE_LOCK, BakeError};
-use crate::callback::ProgressCallback;
+use super::{BAKE_LOCK, BakeError, BakeParamsError};
+use crate::callback::{ProgressCallback, ProgressFn};
 use crate::context::Context;
 use crate::device::open_cl::OpenClDevice;
 use crate::device::radeon_rays::RadeonRaysDevice;
 use crate::geometry::Scene;
 use crate::probe::ProbeBatch;
 use crate::ray_tracing::{CustomRayTracer, DefaultRayTracer, Embree, RadeonRays, RayTracer};
+use crate::simulation::{MIN_IRRADIANCE_MIN_DISTANCE, is_valid_irradiance_min_distance};
 use std::marker::PhantomData;
 
 #[cfg(doc)]
@@ -89,7 +90,9 @@ impl<T: RayTracer> ReflectionsBaker<'_, T> {
     ///
     /// # Errors
     ///
-    /// Returns [`BakeError`] if another bake operation is already in progress.
+    /// Returns:
+    /// - [`BakeError::IrradianceMinDistanceTooSmall`] if `params.irradiance_min_distance` is below [`MIN_IRRADIANCE_MIN_DISTANCE`].
+    /// - [`BakeError::BakeInProgress`] if another bake operation is already in progress.
     pub fn bake(
         &self,
         context: &Context,
@@ -106,7 +109,9 @@ impl<T: RayTracer> ReflectionsBaker<'_, T> {
     ///
     /// # Errors
     ///
-    /// Returns [`BakeError`] if another bake operation is already in progress.
+    /// Returns:
+    /// - [`BakeError::IrradianceMinDistanceTooSmall`] if `params.irradiance_min_distance` is below [`MIN_IRRADIANCE_MIN_DISTANCE`].
+    /// - [`BakeError::BakeInProgress`] if another bake operation is already in progress.
     pub fn bake_with_progress_callback(
         &self,
         context: &Context,
@@ -120,7 +125,42 @@ impl<T: RayTracer> ReflectionsBaker<'_, T> {
             probe_batch,
             scene,
             params,
-            Some(progress_callback),
+            Some(progress_callback.as_raw_parts()),
+        )
+    }
+
+    /// Bakes a single layer of reflections data in a probe batch, reporting progress to a closure.
+    ///
+    /// `progress` is called with the fraction of the bake that has been completed, between 0.0
+    /// and 1.0.
+    /// Unlike [`Self::bake_with_progress_callback`], the closure may borrow and mutate local state.
+    ///
+    /// Only one bake can be in progress at any point in time.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// - [`BakeError::IrradianceMinDistanceTooSmall`] if `params.irradiance_min_distance` is below [`MIN_IRRADIANCE_MIN_DISTANCE`].
+    /// - [`BakeError::BakeInProgress`] if another bake operation is already in progress.
+    pub fn bake_with_progress_fn<F>(
+        &self,
+        context: &Context,
+        probe_batch: &mut ProbeBatch,
+        scene: &Scene<T>,
+        params: ReflectionsBakeParams,
+        mut progress: F,
+    ) -> Result<(), BakeError>
+    where
+        F: FnMut(f32) + Send,
+    {
+        let progress = ProgressFn::new(&mut progress);
+
+        self.bake_with_optional_progress_callback(
+            context,
+            probe_batch,
+            scene,
+            params,
+            Some(progress.as_raw_parts()),
         )
     }
 
@@ -130,26 +170,32 @@ impl<T: RayTracer> ReflectionsBaker<'_, T> {
     ///
     /// # Errors
     ///
-    /// Returns [`BakeError`] if another bake operation is already in progress.
+    /// Returns:
+    /// - [`BakeError::IrradianceMinDistanceTooSmall`] if `params.irradiance_min_distance` is below [`MIN_IRRADIANCE_MIN_DISTANCE`].
+    /// - [`BakeError::BakeInProgress`] if another bake operation is already in progress.
     fn bake_with_optional_progress_callback(
         &self,
         context: &Context,
         probe_batch: &mut ProbeBatch,
         scene: &Scene<T>,
         params: ReflectionsBakeParams,
-        progress_callback: Option<ProgressCallback>,
+        progress_callback: Option<(
+            unsafe extern "C" fn(f32, *mut std::ffi::c_void),
+            *mut std::ffi::c_void,
+        )>,
     ) -> Result<(), BakeError> {
+        if !is_valid_irradiance_min_distance(params.irradiance_min_distance) {
+            return Err(BakeError::IrradianceMinDistanceTooSmall);
+        }
+
         let _guard = BAKE_LOCK
             .try_lock()
             .map_err(|_| BakeError::BakeInProgress)?;
 
-        let (callback, user_data) = progress_callback.as_ref().map_or(
-            (None, std::ptr::null_mut()),
-            |callback_information| {
-                let (callback, user_data) = callback_information.as_raw_parts();
+        let (callback, user_data) = progress_callback
+            .map_or((None, std::ptr::null_mut()), |(callback, user_data)| {
                 (Some(callback), user_data)
-            },
-        );
+            });
 
         let mut ffi_params = audionimbus_sys::IPLReflectionsBakeParams {
             scene: scene.raw_ptr(),
@@ -234,12 +280,162 @@ pub struct ReflectionsBakeParams {
     pub num_threads: u32,
 
     /// When calculating how much sound energy reaches a surface directly from a source, any source that is closer than [`Self::irradiance_min_distance`] to the surface is assumed to be at a distance of [`Self::irradiance_min_distance`], for the purposes of energy calculations.
+    ///
+    /// Must be at least [`MIN_IRRADIANCE_MIN_DISTANCE`].
     pub irradiance_min_distance: f32,
 
     /// If using Radeon Rays or if [`Self::identifier`] uses [`BakedDataVariation::StaticListener`], this is the number of probes for which data is baked simultaneously.
     pub bake_batch_size: u32,
 }
 
+impl ReflectionsBakeParams {
+    /// Returns a builder for baking the data layer identified by `identifier`, with all other
+    /// parameters set to reasonable defaults.
+    ///
+    /// See [`ReflectionsBakeParamsBuilder`] for the defaults.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let params = ReflectionsBakeParams::builder(BakedDataIdentifier::Reflections {
+    ///     variation: BakedDataVariation::Reverb,
+    /// })
+    /// .with_bake_flags(ReflectionsBakeFlags::BAKE_CONVOLUTION | ReflectionsBakeFlags::BAKE_PARAMETRIC)
+    /// .with_simulated_duration(2.0)
+    /// .with_saved_duration(1.0)
+    /// .build()?;
+    ///
+    /// assert_eq!(params.num_rays, 16384);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub const fn builder(identifier: BakedDataIdentifier) -> ReflectionsBakeParamsBuilder {
+        ReflectionsBakeParamsBuilder {
+            params: Self {
+                identifier,
+                bake_flags: ReflectionsBakeFlags::BAKE_CONVOLUTION,
+                num_rays: 16384,
+                num_diffuse_samples: 1024,
+                num_bounces: 16,
+                simulated_duration: 1.0,
+                saved_duration: 1.0,
+                order: 1,
+                num_threads: 1,
+                irradiance_min_distance: 1.0,
+                bake_batch_size: 1,
+            },
+        }
+    }
+}
+
+/// Builder for [`ReflectionsBakeParams`], created with [`ReflectionsBakeParams::builder`].
+///
+/// Unless overridden, parameters default to:
+/// - [`ReflectionsBakeFlags::BAKE_CONVOLUTION`]
+/// - 16384 rays, 1024 diffuse samples and 16 bounces
+/// - 1 second simulated and saved durations
+/// - Ambisonic order 1
+/// - 1 thread
+/// - 1 meter irradiance minimum distance
+/// - a bake batch size of 1
+#[derive(Debug, Copy, Clone)]
+pub struct ReflectionsBakeParamsBuilder {
+    params: ReflectionsBakeParams,
+}
+
+impl ReflectionsBakeParamsBuilder {
+    /// Sets the types of data to save for each probe.
+    pub const fn with_bake_flags(mut self, bake_flags: ReflectionsBakeFlags) -> Self {
+        self.params.bake_flags = bake_flags;
+        self
+    }
+
+    /// Sets the number of rays to trace from each listener position.
+    pub const fn with_num_rays(mut self, num_rays: u32) -> Self {
+        self.params.num_rays = num_rays;
+        self
+    }
+
+    /// Sets the number of directions to consider when generating diffusely-reflected rays.
+    pub const fn with_num_diffuse_samples(mut self, num_diffuse_samples: u32) -> Self {
+        self.params.num_diffuse_samples = num_diffuse_samples;
+        self
+    }
+
+    /// Sets the number of times each ray is reflected off of solid geometry.
+    pub const fn with_num_bounces(mut self, num_bounces: u32) -> Self {
+        self.params.num_bounces = num_bounces;
+        self
+    }
+
+    /// Sets the length (in seconds) of the impulse responses to simulate.
+    pub const fn with_simulated_duration(mut self, simulated_duration: f32) -> Self {
+        self.params.simulated_duration = simulated_duration;
+        self
+    }
+
+    /// Sets the length (in seconds) of the impulse responses to save at each probe.
+    ///
+    /// Must not exceed the simulated duration.
+    pub const fn with_saved_duration(mut self, saved_duration: f32) -> Self {
+        self.params.saved_duration = saved_duration;
+        self
+    }
+
+    /// Sets the Ambisonic order of the baked IRs.
+    pub const fn with_order(mut self, order: u32) -> Self {
+        self.params.order = order;
+        self
+    }
+
+    /// Sets the number of threads to use for baking.
+    pub const fn with_num_threads(mut self, num_threads: u32) -> Self {
+        self.params.num_threads = num_threads;
+        self
+    }
+
+    /// Sets the irradiance minimum distance (in meters).
+    ///
+    /// Must be at least [`MIN_IRRADIANCE_MIN_DISTANCE`].
+    pub const fn with_irradiance_min_distance(mut self, irradiance_min_distance: f32) -> Self {
+        self.params.irradiance_min_distance = irradiance_min_distance;
+        self
+    }
+
+    /// Sets the number of probes for which data is baked simultaneously.
+    pub const fn with_bake_batch_size(mut self, bake_batch_size: u32) -> Self {
+        self.params.bake_batch_size = bake_batch_size;
+        self
+    }
+
+    /// Validates the parameters and returns them.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// - [`BakeParamsError::SavedDurationExceedsSimulatedDuration`] if the saved duration is longer than the simulated duration.
+    /// - [`BakeParamsError::IrradianceMinDistanceTooSmall`] if the irradiance minimum distance is below [`MIN_IRRADIANCE_MIN_DISTANCE`], or is `NaN`.
+    pub fn build(self) -> Result<ReflectionsBakeParams, BakeParamsError> {
+        let params = self.params;
+
+        if params.saved_duration > params.simulated_duration {
+            return Err(BakeParamsError::SavedDurationExceedsSimulatedDuration {
+                saved: params.saved_duration,
+                simulated: params.simulated_duration,
+            });
+        }
+
+        if !is_valid_irradiance_min_distance(params.irradiance_min_distance) {
+            return Err(BakeParamsError::IrradianceMinDistanceTooSmall {
+                requested: params.irradiance_min_distance,
+                min: MIN_IRRADIANCE_MIN_DISTANCE,
+            });
+        }
+
+        Ok(params)
+    }
+}
+
 bitflags::bitflags! {
     /// Flags for specifying what types of reflections data to bake.
     #[derive(Copy, Clone, Debug)]
@@ -262,6 +458,57 @@ impl From<ReflectionsBakeFlags> for audionimbus_sys::IPLReflectionsBakeFlags {
 pub mod tests {
     use crate::*;
 
+    mod reflections_bake_params_builder {
+        use super::*;
+
+        fn builder() -> ReflectionsBakeParamsBuilder {
+            ReflectionsBakeParams::builder(BakedDataIdentifier::Reflections {
+                variation: BakedDataVariation::Reverb,
+            })
+        }
+
+        #[test]
+        fn test_valid() {
+            let params = builder()
+                .with_num_rays(512)
+                .with_simulated_duration(2.0)
+                .with_saved_duration(1.5)
+                .with_order(2)
+                .build()
+                .unwrap();
+
+            assert_eq!(params.num_rays, 512);
+            assert_eq!(params.simulated_duration, 2.0);
+            assert_eq!(params.saved_duration, 1.5);
+            assert_eq!(params.order, 2);
+        }
+
+        #[test]
+        fn test_saved_duration_exceeds_simulated_duration() {
+            assert_eq!(
+                builder().with_saved_duration(2.0).build().unwrap_err(),
+                BakeParamsError::SavedDurationExceedsSimulatedDuration {
+                    saved: 2.0,
+                    simulated: 1.0,
+                }
+            );
+        }
+
+        #[test]
+        fn test_irradiance_min_distance_too_small() {
+            assert_eq!(
+                builder()
+                    .with_irradiance_min_distance(0.0)
+                    .build()
+                    .unwrap_err(),
+                BakeParamsError::IrradianceMinDistanceTooSmall {
+                    requested: 0.0,
+                    min: MIN_IRRADIANCE_MIN_DISTANCE,
+                }
+            );
+        }
+    }
+
     fn test_scene(context: &Context) -> Scene<DefaultRayTracer> {
         let mut scene = Scene::try_new(context).unwrap();
 
@@ -509,5 +756,75 @@ pub mod tests {
                     .is_ok()
             );
         }
+
+        // With progress closure
+        {
+            let context = Context::default();
+            let scene = test_scene(&context);
+            let mut probe_batch = test_probe_batch(&context, &scene);
+
+            let baker = ReflectionsBaker::<DefaultRayTracer>::new();
+
+            let params = ReflectionsBakeParams {
+                identifier: BakedDataIdentifier::Reflections {
+                    variation: BakedDataVariation::Reverb,
+                },
+                bake_flags: ReflectionsBakeFlags::BAKE_CONVOLUTION,
+                num_rays: 512,
+                num_diffuse_samples: 16,
+                num_bounces: 4,
+                simulated_duration: 1.0,
+                saved_duration: 1.0,
+                order: 1,
+                num_threads: 1,
+                irradiance_min_distance: 0.5,
+                bake_batch_size: 4,
+            };
+
+            let mut reported = Vec::new();
+            assert!(
+                baker
+                    .bake_with_progress_fn(&context, &mut probe_batch, &scene, params, |progress| {
+                        reported.push(progress);
+                    })
+                    .is_ok()
+            );
+            assert!(!reported.is_empty());
+            assert!(
+                reported
+                    .iter()
+                    .all(|progress| (0.0..=1.0).contains(progress))
+            );
+        }
+
+        // Irradiance minimum distance too small
+        {
+            let context = Context::default();
+            let scene = test_scene(&context);
+            let mut probe_batch = test_probe_batch(&context, &scene);
+
+            let baker = ReflectionsBaker::<DefaultRayTracer>::new();
+
+            let params = ReflectionsBakeParams {
+                identifier: BakedDataIdentifier::Reflections {
+                    variation: BakedDataVariation::Reverb,
+                },
+                bake_flags: ReflectionsBakeFlags::BAKE_CONVOLUTION,
+                num_rays: 512,
+                num_diffuse_samples: 16,
+                num_bounces: 4,
+                simulated_duration: 1.0,
+                saved_duration: 1.0,
+                order: 1,
+                num_threads: 1,
+                irradiance_min_distance: 0.0,
+                bake_batch_size: 4,
+            };
+
+            assert_eq!(
+                baker.bake(&context, &mut probe_batch, &scene, params),
+                Err(BakeError::IrradianceMinDistanceTooSmall)
+            );
+        }
     }
 }
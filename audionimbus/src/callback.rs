@@ -1,9 +1,22 @@
 //! Callback definitions.
+//!
+//! # Panics
+//!
+//! Callbacks are invoked from within Steam Audio, and a panic unwinding across the FFI boundary
+//! is undefined behavior.
+//! Every callback installed by this crate therefore catches panics: the panic is reported, then
+//! swallowed, and the callback is treated as having returned a default result (for example, `0.0`
+//! for attenuation callbacks, or no hit for ray tracing callbacks).
+//!
+//! Caught panics are reported to the handler set with [`set_panic_handler`].
+//! Without one, only the first caught panic is reported, on stderr.
 
 use crate::geometry::{Hit, Ray, Vector3};
+use crate::ray_tracing::CustomRayTracing;
 use std::cell::Cell;
 use std::ffi::c_void;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
 
 #[cfg(doc)]
 use crate::simulation::Simulator;
@@ -42,7 +55,11 @@ macro_rules! callback {
                 $(let $arg = <$arg_ty as $crate::callback::FfiConvert>::from_ffi($arg);)*
 
                 #[allow(unused_variables)]
-                let result = callback($($arg),*);
+                let result = $crate::callback::catch_unwind_or(
+                    stringify!($name),
+                    Default::default(),
+                    move || callback($($arg),*),
+                );
 
                 $(return <$ret as $crate::callback::FfiConvert>::to_ffi(result);)?
             }
@@ -59,6 +76,14 @@ macro_rules! callback {
             }
         }
 
+        impl PartialEq for $name {
+            /// Two callbacks are equal if they share the same closure, i.e. one is a clone of the
+            /// other.
+            fn eq(&self, other: &Self) -> bool {
+                Arc::ptr_eq(&self.callback, &other.callback)
+            }
+        }
+
         impl std::fmt::Debug for $name {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 f.debug_struct(stringify!($name))
@@ -68,6 +93,115 @@ macro_rules! callback {
         }
     };
 }
+
+/// Handler set with [`set_panic_handler`].
+static PANIC_HANDLER: Mutex<Option<fn(&str)>> = Mutex::new(None);
+
+/// Whether a panic was reported on stderr, in the absence of a handler.
+static PANIC_REPORTED: AtomicBool = AtomicBool::new(false);
+
+/// Sets the handler notified of panics caught in callbacks, replacing any previous one.
+///
+/// The handler is called with the name of the callback that panicked, on the thread that invoked
+/// the callback, which may be a real-time audio or simulation thread.
+/// Passing `None` restores the default behavior, which reports only the first caught panic on
+/// stderr.
+///
+/// # Examples
+///
+/// ```
+/// # use audionimbus::callback::set_panic_handler;
+/// set_panic_handler(Some(|callback_name| {
+///     // Forward to the application's logger instead.
+///     let _ = callback_name;
+/// }));
+/// ```
+pub fn set_panic_handler(handler: Option<fn(&str)>) {
+    *PANIC_HANDLER.lock().unwrap_or_else(PoisonError::into_inner) = handler;
+}
+
+/// Reports a panic caught in the callback named `callback_name`.
+///
+/// The panic is forwarded to the handler set with [`set_panic_handler`] if any; otherwise, only
+/// the first caught panic is reported, on stderr.
+/// This is used by the callbacks generated by [`log_callback!`](crate::log_callback),
+/// [`allocate_callback!`](crate::allocate_callback) and [`free_callback!`](crate::free_callback).
+pub fn report_panic(callback_name: &str) {
+    let handler = *PANIC_HANDLER.lock().unwrap_or_else(PoisonError::into_inner);
+    match handler {
+        Some(handler) => handler(callback_name),
+        None => {
+            if !PANIC_REPORTED.swap(true, Ordering::Relaxed) {
+                eprintln!(
+                    "audionimbus: caught a panic in {callback_name}; it was not propagated to Steam Audio (further panics are not reported)"
+                );
+            }
+        }
+    }
+}
+
+/// Runs `f`, catching any panic so that it does not unwind into Steam Audio.
+///
+/// If `f` panics, the panic is reported with [`report_panic`] and `fallback` is returned.
+pub(crate) fn catch_unwind_or<R>(callback_name: &str, fallback: R, f: impl FnOnce() -> R) -> R {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).unwrap_or_else(|_| {
+        report_panic(callback_name);
+        fallback
+    })
+}
+
+/// An `FnMut` closure that can be called through a shared reference.
+///
+/// Calls are serialized through a mutex, in case Steam Audio invokes the closure from several
+/// threads.
+pub(crate) struct SerializedFnMut<F>(Mutex<F>);
+
+impl<F> SerializedFnMut<F> {
+    pub(crate) const fn new(f: F) -> Self {
+        Self(Mutex::new(f))
+    }
+
+    /// Calls `call` with exclusive access to the closure.
+    ///
+    /// Does nothing if a previous call panicked while holding the mutex.
+    pub(crate) fn with(&self, call: impl FnOnce(&mut F)) {
+        if let Ok(mut f) = self.0.lock() {
+            call(&mut f);
+        }
+    }
+}
+
+/// Adapts a borrowed `FnMut(f32)` closure into a Steam Audio progress callback.
+///
+/// Unlike [`ProgressCallback`], the closure does not need to be `'static` or `Sync`, which allows
+/// it to borrow and mutate local state for the duration of a single call into Steam Audio.
+pub(crate) struct ProgressFn<'a, F> {
+    progress: SerializedFnMut<&'a mut F>,
+}
+
+impl<'a, F: FnMut(f32) + Send> ProgressFn<'a, F> {
+    pub(crate) const fn new(progress: &'a mut F) -> Self {
+        Self {
+            progress: SerializedFnMut::new(progress),
+        }
+    }
+
+    unsafe extern "C" fn trampoline(progress: f32, user_data: *mut c_void) {
+        // SAFETY: `user_data` was set in `as_raw_parts()`.
+        // The pointer is non-null and correctly aligned.
+        // The pointee outlives the call into Steam Audio that invokes this callback.
+        let closure = unsafe { &*(user_data as *const SerializedFnMut<&mut F>) };
+
+        catch_unwind_or("progress closure", (), || {
+            closure.with(|closure| closure(progress));
+        });
+    }
+
+    pub(crate) fn as_raw_parts(&self) -> (unsafe extern "C" fn(f32, *mut c_void), *mut c_void) {
+        (Self::trampoline, &self.progress as *const _ as *mut c_void)
+    }
+}
+
 /// Trait for types that can be converted to/from FFI representations.
 pub(crate) trait FfiConvert {
     type FfiType;
@@ -168,6 +302,30 @@ callback! {
     )
 }
 
+impl PathingVisualizationCallback {
+    /// Creates a callback from a closure that needs mutable access to its state, such as a debug
+    /// renderer accumulating the segments to draw.
+    ///
+    /// Segments reported from several threads are passed to the closure one at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let mut segments = Vec::new();
+    /// let callback = PathingVisualizationCallback::from_fn_mut(move |from, to, occluded| {
+    ///     segments.push((from, to, occluded));
+    /// });
+    /// ```
+    pub fn from_fn_mut<F>(f: F) -> Self
+    where
+        F: FnMut(Vector3, Vector3, bool) + Send + 'static,
+    {
+        let f = SerializedFnMut::new(f);
+        Self::new(move |from, to, occluded| f.with(|f| f(from, to, occluded)))
+    }
+}
+
 callback! {
     /// Callback for calculating how much to attenuate sound in a given frequency band based on the angle of deviation when the sound path bends around a corner as it propagated from the source to the listener.
     ///
@@ -256,7 +414,7 @@ impl DirectivityCallback {
             unsafe { &*(callback_ptr as *const Arc<dyn Fn(Vector3) -> f32 + Send + Sync>) };
 
         let direction = Vector3::from_ffi(direction);
-        callback(direction)
+        catch_unwind_or("DirectivityCallback", 0.0, || callback(direction))
     }
 
     // WORKAROUND: This stores the callback pointer in thread-local storage rather than
@@ -273,6 +431,13 @@ impl DirectivityCallback {
     }
 }
 
+impl PartialEq for DirectivityCallback {
+    /// Two callbacks are equal if they share the same closure, i.e. one is a clone of the other.
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.callback, &other.callback)
+    }
+}
+
 impl std::fmt::Debug for DirectivityCallback {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("DirectivityCallback")
@@ -335,6 +500,36 @@ impl CustomRayTracingCallbacks {
         }
     }
 
+    /// Creates custom callbacks forwarding to the methods of `ray_tracing`.
+    pub fn from_ray_tracing<T: CustomRayTracing>(ray_tracing: T) -> Self {
+        let ray_tracing = Arc::new(ray_tracing);
+
+        let closest_hit = {
+            let ray_tracing = Arc::clone(&ray_tracing);
+            ClosestHitCallback::new(move |ray, min_distance, max_distance| {
+                ray_tracing.closest_hit(ray, min_distance, max_distance)
+            })
+        };
+        let any_hit = {
+            let ray_tracing = Arc::clone(&ray_tracing);
+            AnyHitCallback::new(move |ray, min_distance, max_distance| {
+                ray_tracing.any_hit(ray, min_distance, max_distance)
+            })
+        };
+        let batched_closest_hit = {
+            let ray_tracing = Arc::clone(&ray_tracing);
+            BatchedClosestHitCallback::new(move |rays, min_distances, max_distances| {
+                ray_tracing.batched_closest_hit(rays, min_distances, max_distances)
+            })
+        };
+        let batched_any_hit =
+            BatchedAnyHitCallback::new(move |rays, min_distances, max_distances| {
+                ray_tracing.batched_any_hit(rays, min_distances, max_distances)
+            });
+
+        Self::new(closest_hit, any_hit, batched_closest_hit, batched_any_hit)
+    }
+
     /// Returns FFI scene settings with custom callbacks and the user data box.
     /// The returned `Arc<CustomRayTracingUserData>` must be kept alive for as long as the scene is
     /// in use.
@@ -376,6 +571,29 @@ impl CustomRayTracingCallbacks {
 
 type ClosestHitFn = dyn Fn(Ray, f32, f32) -> Option<Hit> + Send + Sync;
 type AnyHitFn = dyn Fn(Ray, f32, f32) -> bool + Send + Sync;
+/// Converts the result of a closest hit callback, writing an explicit miss for `None` so that the
+/// output slot does not keep a stale hit.
+fn to_ffi_hit(hit: Option<Hit>) -> audionimbus_sys::IPLHit {
+    match hit {
+        Some(hit) => audionimbus_sys::IPLHit {
+            distance: hit.distance,
+            triangleIndex: hit.triangle_index.map(|i| i as i32).unwrap_or(-1),
+            objectIndex: hit.object_index.map(|i| i as i32).unwrap_or(-1),
+            materialIndex: hit.material_index.map(|i| i as i32).unwrap_or(-1),
+            normal: hit.normal.into(),
+            material: std::ptr::null_mut(),
+        },
+        None => audionimbus_sys::IPLHit {
+            distance: f32::INFINITY,
+            triangleIndex: -1,
+            objectIndex: -1,
+            materialIndex: -1,
+            normal: Vector3::default().into(),
+            material: std::ptr::null_mut(),
+        },
+    }
+}
+
 type BatchedClosestHitFn = dyn Fn(&[Ray], &[f32], &[f32]) -> Vec<Option<Hit>> + Send + Sync;
 type BatchedAnyHitFn = dyn Fn(&[Ray], &[f32], &[f32]) -> Vec<bool> + Send + Sync;
 
@@ -450,21 +668,14 @@ impl ClosestHitCallback {
             // SAFETY: `ray` is non-null and is a valid pointer.
             Ray::from(unsafe { *ray })
         };
-        let result = callback(ray, min_distance, max_distance);
+        let result = catch_unwind_or("ClosestHitCallback", None, || {
+            callback(ray, min_distance, max_distance)
+        });
 
-        if let Some(hit_result) = result
-            && !hit.is_null()
-        {
+        if !hit.is_null() {
             // SAFETY: `hit` is non-null and points to a valid `IPLHit` output slot.
             unsafe {
-                *hit = audionimbus_sys::IPLHit {
-                    distance: hit_result.distance,
-                    triangleIndex: hit_result.triangle_index.map(|i| i as i32).unwrap_or(-1),
-                    objectIndex: hit_result.object_index.map(|i| i as i32).unwrap_or(-1),
-                    materialIndex: hit_result.material_index.map(|i| i as i32).unwrap_or(-1),
-                    normal: hit_result.normal.into(),
-                    material: std::ptr::null_mut(),
-                };
+                *hit = to_ffi_hit(result);
             }
         }
     }
@@ -531,7 +742,9 @@ impl AnyHitCallback {
             // SAFETY: `ray` is non-null and is a valid pointer.
             Ray::from(unsafe { *ray })
         };
-        let result = callback(ray, min_distance, max_distance);
+        let result = catch_unwind_or("AnyHitCallback", false, || {
+            callback(ray, min_distance, max_distance)
+        });
 
         if !occluded.is_null() {
             // SAFETY: `occluded` is non-null and points to a valid `u8` output slot.
@@ -617,30 +830,19 @@ impl BatchedClosestHitCallback {
         let min_distances_slice = unsafe { std::slice::from_raw_parts(min_distances, num_rays) };
         let max_distances_slice = unsafe { std::slice::from_raw_parts(max_distances, num_rays) };
 
-        let results = callback(&rays_slice, min_distances_slice, max_distances_slice);
-
-        for (i, result) in results.iter().enumerate().take(num_rays) {
-            if let Some(hit_result) = result {
-                // SAFETY: `hits` is non-null and points to a contiguous array of `num_rays`
-                // `IPLHit` elements.
-                // `i < num_rays` (enforced by `.take(num_rays)`), so `hits.add(i)` is within
-                // bounds.
-                unsafe {
-                    *hits.add(i) = audionimbus_sys::IPLHit {
-                        distance: hit_result.distance,
-                        triangleIndex: hit_result
-                            .triangle_index
-                            .map(|idx| idx as i32)
-                            .unwrap_or(-1),
-                        objectIndex: hit_result.object_index.map(|idx| idx as i32).unwrap_or(-1),
-                        materialIndex: hit_result
-                            .material_index
-                            .map(|idx| idx as i32)
-                            .unwrap_or(-1),
-                        normal: hit_result.normal.into(),
-                        material: std::ptr::null_mut(),
-                    };
-                }
+        let results = catch_unwind_or("BatchedClosestHitCallback", vec![None; num_rays], || {
+            callback(&rays_slice, min_distances_slice, max_distances_slice)
+        });
+
+        // Every ray is written, so that rays without a result are not left holding stale hits.
+        for i in 0..num_rays {
+            let ffi_hit = to_ffi_hit(results.get(i).copied().flatten());
+
+            // SAFETY: `hits` is non-null and points to a contiguous array of `num_rays` `IPLHit`
+            // elements.
+            // `i < num_rays`, so `hits.add(i)` is within bounds.
+            unsafe {
+                *hits.add(i) = ffi_hit;
             }
         }
     }
@@ -721,13 +923,17 @@ impl BatchedAnyHitCallback {
         let min_distances_slice = unsafe { std::slice::from_raw_parts(min_distances, num_rays) };
         let max_distances_slice = unsafe { std::slice::from_raw_parts(max_distances, num_rays) };
 
-        let results = callback(&rays_slice, min_distances_slice, max_distances_slice);
+        let results = catch_unwind_or("BatchedAnyHitCallback", vec![false; num_rays], || {
+            callback(&rays_slice, min_distances_slice, max_distances_slice)
+        });
+
+        // Every ray is written, so that rays without a result are not left holding stale values.
+        for i in 0..num_rays {
+            let result = results.get(i).copied().unwrap_or(false);
 
-        for (i, &result) in results.iter().enumerate().take(num_rays) {
             // SAFETY: `occluded` is non-null and points to a contiguous array of `num_rays` `u8`
             // elements.
-            // `i < num_rays` (enforced by `.take(num_rays)`), so `occluded.add(i)` is within
-            // bounds.
+            // `i < num_rays`, so `occluded.add(i)` is within bounds.
             unsafe {
                 *occluded.add(i) = if result { 1 } else { 0 };
             }
@@ -830,7 +1036,9 @@ macro_rules! log_callback {
             };
 
             let closure: fn($crate::callback::LogLevel, &str) = $closure;
-            closure(rust_level, rust_message);
+            if ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| closure(rust_level, rust_message))).is_err() {
+                $crate::callback::report_panic("log callback");
+            }
         }
     };
 }
@@ -920,7 +1128,10 @@ macro_rules! allocate_callback {
             alignment: usize,
         ) -> *mut ::std::ffi::c_void {
             let closure: fn(usize, usize) -> *mut ::std::ffi::c_void = $closure;
-            closure(size, alignment)
+            ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| closure(size, alignment))).unwrap_or_else(|_| {
+                $crate::callback::report_panic("allocate callback");
+                ::std::ptr::null_mut()
+            })
         }
     };
 }
@@ -994,7 +1205,94 @@ macro_rules! free_callback {
     (@impl $name:ident, $closure:expr) => {
         unsafe extern "C" fn $name(ptr: *mut ::std::ffi::c_void) {
             let closure: fn(*mut ::std::ffi::c_void) = $closure;
-            closure(ptr);
+            if ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| closure(ptr))).is_err() {
+                $crate::callback::report_panic("free callback");
+            }
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn panicking_user_data() -> CustomRayTracingUserData {
+        CustomRayTracingUserData {
+            closest_hit: Arc::new(|_: Ray, _: f32, _: f32| -> Option<Hit> {
+                panic!("closest hit panicked")
+            }),
+            any_hit: Arc::new(|_: Ray, _: f32, _: f32| -> bool { panic!("any hit panicked") }),
+            batched_closest_hit: Arc::new(|_: &[Ray], _: &[f32], _: &[f32]| -> Vec<Option<Hit>> {
+                panic!("batched closest hit panicked")
+            }),
+            batched_any_hit: Arc::new(|_: &[Ray], _: &[f32], _: &[f32]| -> Vec<bool> {
+                panic!("batched any hit panicked")
+            }),
+        }
+    }
+
+    mod batched_closest_hit {
+        use super::*;
+
+        #[test]
+        fn test_panic_writes_no_hit() {
+            let user_data = panicking_user_data();
+            let rays = [audionimbus_sys::IPLRay::from(Ray::default()); 2];
+            let min_distances = [0.0; 2];
+            let max_distances = [1.0; 2];
+            let stale_hit = to_ffi_hit(Some(Hit {
+                distance: 0.5,
+                triangle_index: Some(1),
+                object_index: Some(2),
+                material_index: Some(3),
+                normal: Vector3::new(0.0, 1.0, 0.0),
+                material: None,
+            }));
+            let mut hits = [stale_hit; 2];
+
+            unsafe {
+                BatchedClosestHitCallback::trampoline(
+                    2,
+                    rays.as_ptr(),
+                    min_distances.as_ptr(),
+                    max_distances.as_ptr(),
+                    hits.as_mut_ptr(),
+                    &user_data as *const _ as *mut c_void,
+                );
+            }
+
+            for hit in hits {
+                assert_eq!(hit.distance, f32::INFINITY);
+                assert_eq!(hit.triangleIndex, -1);
+                assert_eq!(hit.objectIndex, -1);
+                assert_eq!(hit.materialIndex, -1);
+            }
+        }
+    }
+
+    mod batched_any_hit {
+        use super::*;
+
+        #[test]
+        fn test_panic_writes_not_occluded() {
+            let user_data = panicking_user_data();
+            let rays = [audionimbus_sys::IPLRay::from(Ray::default()); 2];
+            let min_distances = [0.0; 2];
+            let max_distances = [1.0; 2];
+            let mut occluded = [1u8; 2];
+
+            unsafe {
+                BatchedAnyHitCallback::trampoline(
+                    2,
+                    rays.as_ptr(),
+                    min_distances.as_ptr(),
+                    max_distances.as_ptr(),
+                    occluded.as_mut_ptr(),
+                    &user_data as *const _ as *mut c_void,
+                );
+            }
+
+            assert_eq!(occluded, [0, 0]);
+        }
+    }
+}
@@ -42,6 +42,12 @@ impl Context {
     /// - Incompatible API version
     /// - Memory allocation failure
     /// - External dependency initialization failure
+    ///
+    /// The version check is performed by Steam Audio itself: the library rejects a
+    /// [`ContextSettings`] version it is not compatible with by returning
+    /// [`SteamAudioError::Initialization`].
+    /// The core API does not expose the version of the loaded library, so the mismatching version
+    /// cannot be reported in the error.
     pub fn try_new(settings: &ContextSettings) -> Result<Self, SteamAudioError> {
         let mut context = Self(std::ptr::null_mut());
 
@@ -71,11 +77,12 @@ impl Context {
 
     /// Returns a mutable reference to the raw FFI pointer.
     ///
+    /// This is intended for internal use and advanced scenarios.
+    ///
     /// # Safety
     ///
-    /// This is intended for internal use and advanced scenarios. The returned pointer
-    /// must not outlive the `Context` object.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLContext {
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLContext {
         &mut self.0
     }
 }
@@ -448,8 +455,16 @@ bitflags::bitflags! {
 }
 
 impl From<ContextFlags> for audionimbus_sys::IPLContextFlags {
+    #[allow(clippy::useless_conversion)]
     fn from(context_flags: ContextFlags) -> Self {
-        Self(context_flags.bits() as _)
+        // The FFI representation is unsigned on most platforms but signed on Windows, where an
+        // `as` cast would silently wrap. Unknown bits are dropped, so that the remaining flags
+        // always fit in either representation.
+        let bits = context_flags.intersection(ContextFlags::all()).bits();
+        Self(
+            bits.try_into()
+                .expect("context flags fit in the FFI representation"),
+        )
     }
 }
 
@@ -457,6 +472,21 @@ impl From<ContextFlags> for audionimbus_sys::IPLContextFlags {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_context_flags_to_ffi() {
+        let flags = audionimbus_sys::IPLContextFlags::from(ContextFlags::VALIDATION);
+        assert_eq!(flags.0, 1);
+
+        let flags = audionimbus_sys::IPLContextFlags::from(ContextFlags::from_bits_retain(1 << 31));
+        assert_eq!(flags.0, 0);
+    }
+
+    #[test]
+    fn test_context_with_validation() {
+        let settings = ContextSettings::new().with_flags(ContextFlags::VALIDATION);
+        assert!(Context::try_new(&settings).is_ok());
+    }
+
     #[test]
     fn test_context_clone() {
         let context = Context::default();
@@ -0,0 +1,156 @@
+//! Flushing of denormal floating-point numbers.
+//!
+//! As a reverb tail decays, its samples eventually become denormal (subnormal) floats.
+//! On most CPUs, arithmetic on denormals is orders of magnitude slower than on normal floats,
+//! which shows up as CPU spikes while long impulse responses fade out.
+//!
+//! Steam Audio runs on the calling thread and inherits its floating-point environment.
+//! [`flush_denormals`] configures the current thread to treat denormals as zero, and is typically
+//! called once at the start of the audio callback:
+//!
+//! ```
+//! # use audionimbus::denormals::flush_denormals;
+//! fn audio_callback() {
+//!     // SAFETY: the callback does not rely on exact denormal results.
+//!     let _guard = unsafe { flush_denormals() };
+//!
+//!     // Apply effects...
+//! }
+//! # audio_callback();
+//! ```
+//!
+//! This is supported on x86 and x86-64 (by setting the FTZ and DAZ bits of the MXCSR register) and
+//! on AArch64 (by setting the FZ bit of the FPCR register). On other architectures, it has no
+//! effect.
+
+use std::marker::PhantomData;
+
+/// Restores the previous handling of denormals when dropped.
+///
+/// The floating-point environment is per thread, which is why this type is neither [`Send`] nor
+/// [`Sync`].
+#[derive(Debug)]
+#[must_use = "denormals are no longer flushed once the guard is dropped"]
+pub struct FlushDenormalsGuard {
+    previous: arch::ControlRegister,
+    _not_send: PhantomData<*const ()>,
+}
+
+/// Flushes denormal floats to zero on the current thread, until the returned guard is dropped.
+///
+/// # Safety
+///
+/// Rust code is compiled assuming the default floating-point environment.
+/// While the guard is alive, floating-point operations on the current thread, including those of
+/// Rust code, may produce results differing from those computed at compile time or on other
+/// threads.
+/// Callers must not rely on exact denormal results while the guard is alive.
+pub unsafe fn flush_denormals() -> FlushDenormalsGuard {
+    let previous = arch::read_control();
+    arch::write_control(previous | arch::FLUSH_DENORMALS);
+
+    FlushDenormalsGuard {
+        previous,
+        _not_send: PhantomData,
+    }
+}
+
+impl Drop for FlushDenormalsGuard {
+    fn drop(&mut self) {
+        arch::write_control(self.previous);
+    }
+}
+
+#[cfg(any(
+    target_arch = "x86_64",
+    all(target_arch = "x86", target_feature = "sse")
+))]
+mod arch {
+    use std::arch::asm;
+
+    pub type ControlRegister = u32;
+
+    /// The flush-to-zero (FTZ) and denormals-are-zero (DAZ) bits of MXCSR.
+    pub const FLUSH_DENORMALS: ControlRegister = (1 << 15) | (1 << 6);
+
+    pub fn read_control() -> ControlRegister {
+        let mut mxcsr: ControlRegister = 0;
+        // SAFETY: stores MXCSR into a valid local.
+        unsafe {
+            asm!("stmxcsr [{}]", in(reg) &raw mut mxcsr, options(nostack, preserves_flags));
+        }
+        mxcsr
+    }
+
+    pub fn write_control(mxcsr: ControlRegister) {
+        // SAFETY: loads MXCSR from a valid local.
+        unsafe {
+            asm!("ldmxcsr [{}]", in(reg) &raw const mxcsr, options(nostack, readonly, preserves_flags));
+        }
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod arch {
+    use std::arch::asm;
+
+    pub type ControlRegister = u64;
+
+    /// The flush-to-zero (FZ) bit of FPCR.
+    pub const FLUSH_DENORMALS: ControlRegister = 1 << 24;
+
+    pub fn read_control() -> ControlRegister {
+        let fpcr: ControlRegister;
+        // SAFETY: reading FPCR has no side effects.
+        unsafe {
+            asm!("mrs {}, fpcr", out(reg) fpcr, options(nomem, nostack, preserves_flags));
+        }
+        fpcr
+    }
+
+    pub fn write_control(fpcr: ControlRegister) {
+        // SAFETY: only changes the floating-point environment of the current thread.
+        unsafe {
+            asm!("msr fpcr, {}", in(reg) fpcr, options(nomem, nostack, preserves_flags));
+        }
+    }
+}
+
+#[cfg(not(any(
+    target_arch = "x86_64",
+    all(target_arch = "x86", target_feature = "sse"),
+    target_arch = "aarch64"
+)))]
+mod arch {
+    pub type ControlRegister = u32;
+
+    pub const FLUSH_DENORMALS: ControlRegister = 0;
+
+    pub fn read_control() -> ControlRegister {
+        0
+    }
+
+    pub fn write_control(_: ControlRegister) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod flush_denormals {
+        use super::*;
+        use std::hint::black_box;
+
+        #[test]
+        #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+        fn test_flushes_until_dropped() {
+            let denormal = || black_box(f32::MIN_POSITIVE) / black_box(2.0);
+
+            let guard = unsafe { flush_denormals() };
+            assert_eq!(denormal(), 0.0);
+
+            drop(guard);
+            assert!(denormal() > 0.0);
+        }
+    }
+}
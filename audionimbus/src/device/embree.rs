@@ -52,7 +52,11 @@ impl EmbreeDevice {
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLEmbreeDevice {
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLEmbreeDevice {
         &mut self.0
     }
 }
@@ -101,7 +101,11 @@ impl OpenClDevice {
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLOpenCLDevice {
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLOpenCLDevice {
         &mut self.0
     }
 }
@@ -211,7 +215,14 @@ impl OpenClDeviceList {
         self.0
     }
 
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLOpenCLDeviceList {
+    /// Returns a mutable reference to the raw FFI pointer.
+    ///
+    /// This is intended for internal use and advanced scenarios.
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLOpenCLDeviceList {
         &mut self.0
     }
 }
@@ -282,8 +293,20 @@ impl std::fmt::Display for OpenClDeviceListError {
 }
 
 /// Specifies requirements that an OpenCL device must meet in order to be considered when listing OpenCL devices.
-#[derive(Debug)]
+///
+/// # Examples
+///
+/// ```
+/// # use audionimbus::*;
+/// // Reserve 8 GPU compute units, split evenly between IR update and convolution.
+/// let settings = OpenClDeviceSettings::default()
+///     .with_device_type(OpenClDeviceType::Gpu)
+///     .with_num_compute_units_to_reserve(8)
+///     .with_fraction_of_compute_units_for_impulse_response_update(0.5);
+/// ```
+#[derive(Debug, Copy, Clone)]
 pub struct OpenClDeviceSettings {
+    /// The type of device to list.
     pub device_type: OpenClDeviceType,
 
     /// The number of GPU compute units (CUs) that should be reserved for use by Steam Audio.
@@ -313,6 +336,52 @@ pub struct OpenClDeviceSettings {
     pub requires_true_audio_next: bool,
 }
 
+impl OpenClDeviceSettings {
+    /// Sets the type of device to list.
+    pub const fn with_device_type(mut self, device_type: OpenClDeviceType) -> Self {
+        self.device_type = device_type;
+        self
+    }
+
+    /// Sets the number of GPU compute units (CUs) to reserve for use by Steam Audio.
+    ///
+    /// Values larger than [`i32::MAX`] are saturated.
+    ///
+    /// See [`Self::num_compute_units_to_reserve`].
+    pub const fn with_num_compute_units_to_reserve(mut self, num_compute_units: u32) -> Self {
+        self.num_compute_units_to_reserve = if num_compute_units > i32::MAX as u32 {
+            i32::MAX
+        } else {
+            num_compute_units as i32
+        };
+        self
+    }
+
+    /// Sets the fraction of reserved CUs to use for impulse response (IR) update.
+    ///
+    /// The fraction is clamped to `[0.0, 1.0]`.
+    ///
+    /// See [`Self::fraction_of_compute_units_for_impulse_response_update`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fraction` is NaN.
+    pub const fn with_fraction_of_compute_units_for_impulse_response_update(
+        mut self,
+        fraction: f32,
+    ) -> Self {
+        assert!(!fraction.is_nan(), "fraction must not be NaN");
+        self.fraction_of_compute_units_for_impulse_response_update = fraction.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets whether the GPU device must support TrueAudio Next.
+    pub const fn with_requires_true_audio_next(mut self, requires_true_audio_next: bool) -> Self {
+        self.requires_true_audio_next = requires_true_audio_next;
+        self
+    }
+}
+
 impl Default for OpenClDeviceSettings {
     fn default() -> Self {
         Self {
@@ -458,6 +527,40 @@ impl TryFrom<&audionimbus_sys::IPLOpenCLDeviceDesc> for OpenClDeviceDescriptor {
 mod tests {
     use crate::*;
 
+    mod open_cl_device_settings {
+        use super::*;
+
+        #[test]
+        fn test_builder() {
+            let settings = OpenClDeviceSettings::default()
+                .with_device_type(OpenClDeviceType::Gpu)
+                .with_num_compute_units_to_reserve(8)
+                .with_fraction_of_compute_units_for_impulse_response_update(0.25)
+                .with_requires_true_audio_next(true);
+
+            assert_eq!(settings.device_type, OpenClDeviceType::Gpu);
+            assert_eq!(settings.num_compute_units_to_reserve, 8);
+            assert_eq!(
+                settings.fraction_of_compute_units_for_impulse_response_update,
+                0.25
+            );
+            assert!(settings.requires_true_audio_next);
+        }
+
+        #[test]
+        fn test_clamping() {
+            let settings = OpenClDeviceSettings::default()
+                .with_num_compute_units_to_reserve(u32::MAX)
+                .with_fraction_of_compute_units_for_impulse_response_update(1.5);
+
+            assert_eq!(settings.num_compute_units_to_reserve, i32::MAX);
+            assert_eq!(
+                settings.fraction_of_compute_units_for_impulse_response_update,
+                1.0
+            );
+        }
+    }
+
     mod open_cl_device {
         use super::*;
 
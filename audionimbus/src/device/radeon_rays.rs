@@ -21,16 +21,33 @@ impl RadeonRaysDevice {
     /// # Errors
     ///
     /// Returns [`SteamAudioError`] if device creation fails.
-    pub fn try_new(open_cl_device: &OpenClDevice) -> Result<Self, SteamAudioError> {
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use audionimbus::*;
+    /// let context = Context::default();
+    /// let audio_settings = AudioSettings::default();
+    ///
+    /// let device_list = OpenClDeviceList::try_new(&context, &OpenClDeviceSettings::default())?;
+    /// let open_cl_device = OpenClDevice::try_new(&context, &device_list, 0)?;
+    /// let radeon_rays_device =
+    ///     RadeonRaysDevice::try_new(&open_cl_device, &RadeonRaysDeviceSettings::default())?;
+    ///
+    /// let simulation_settings = SimulationSettings::new(&audio_settings)
+    ///     .with_radeon_rays(open_cl_device, radeon_rays_device);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_new(
+        open_cl_device: &OpenClDevice,
+        settings: &RadeonRaysDeviceSettings,
+    ) -> Result<Self, SteamAudioError> {
         let mut radeon_rays_device = Self(std::ptr::null_mut());
 
-        let radeon_rays_device_settings: *mut audionimbus_sys::IPLRadeonRaysDeviceSettings =
-            std::ptr::null_mut();
-
         let status = unsafe {
             audionimbus_sys::iplRadeonRaysDeviceCreate(
                 open_cl_device.raw_ptr(),
-                radeon_rays_device_settings,
+                &mut audionimbus_sys::IPLRadeonRaysDeviceSettings::from(settings),
                 radeon_rays_device.raw_ptr_mut(),
             )
         };
@@ -52,7 +69,11 @@ impl RadeonRaysDevice {
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLRadeonRaysDevice {
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLRadeonRaysDevice {
         &mut self.0
     }
 }
@@ -82,6 +103,22 @@ impl Hash for RadeonRaysDevice {
     }
 }
 
+/// Settings used to create a Radeon Rays device.
+///
+/// Steam Audio does not currently expose any Radeon Rays device settings; this type is reserved
+/// for future use.
+#[derive(Debug, Default, Copy, Clone)]
+#[non_exhaustive]
+pub struct RadeonRaysDeviceSettings {}
+
+impl From<&RadeonRaysDeviceSettings> for audionimbus_sys::IPLRadeonRaysDeviceSettings {
+    fn from(_settings: &RadeonRaysDeviceSettings) -> Self {
+        // SAFETY: `IPLRadeonRaysDeviceSettings` is a plain C struct with no meaningful fields, for
+        // which all-zero bytes is a valid value.
+        unsafe { std::mem::zeroed() }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -95,7 +132,9 @@ mod tests {
             return;
         };
         let open_cl_device = OpenClDevice::try_new(&context, &device_list, 0).unwrap();
-        let radeon_rays_device = RadeonRaysDevice::try_new(&open_cl_device).unwrap();
+        let radeon_rays_device =
+            RadeonRaysDevice::try_new(&open_cl_device, &RadeonRaysDeviceSettings::default())
+                .unwrap();
         let clone = radeon_rays_device.clone();
         assert_eq!(radeon_rays_device.raw_ptr(), clone.raw_ptr());
         drop(radeon_rays_device);
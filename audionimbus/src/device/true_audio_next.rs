@@ -1,8 +1,10 @@
 //! TrueAudio Next convolution engine.
 
 use super::open_cl::OpenClDevice;
+use crate::audio_settings::AudioSettings;
 use crate::error::{SteamAudioError, to_option_error};
 use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex, PoisonError, Weak};
 
 /// Application-wide state for the TrueAudio Next convolution engine.
 ///
@@ -12,8 +14,71 @@ use std::hash::{Hash, Hasher};
 /// Cloning it is cheap; it produces a new handle pointing to the same underlying object, while
 /// incrementing a reference count.
 /// The underlying object is destroyed when all handles are dropped.
-#[derive(Debug, PartialEq, Eq)]
-pub struct TrueAudioNextDevice(pub(crate) audionimbus_sys::IPLTrueAudioNextDevice);
+///
+/// The device has one slot per source, as set by [`TrueAudioNextDeviceSettings::max_sources`].
+/// Each source rendered with TrueAudio Next needs its own slot, which identifies its IR on the GPU.
+/// Slots are handed out with [`Self::reserve_slot`] and handed back with [`Self::release_slot`];
+/// all handles to a device share the same slots.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use audionimbus::*;
+/// let context = Context::default();
+/// let audio_settings = AudioSettings::default();
+///
+/// let device_list = OpenClDeviceList::try_new(&context, &OpenClDeviceSettings::default())?;
+/// let open_cl_device = OpenClDevice::try_new(&context, &device_list, 0)?;
+///
+/// // 2-second, 1st order impulse responses for up to 8 sources.
+/// let true_audio_next_device = TrueAudioNextDevice::try_new(
+///     &open_cl_device,
+///     &TrueAudioNextDeviceSettings::new(&audio_settings, 2.0, 1, 8),
+/// )?;
+///
+/// let simulation_settings = SimulationSettings::new(&audio_settings)
+///     .with_reflections(TrueAudioNextSettings {
+///         max_num_rays: 4096,
+///         num_diffuse_samples: 32,
+///         max_duration: 2.0,
+///         max_num_sources: 8,
+///         num_threads: 2,
+///         open_cl_device,
+///         true_audio_next_device,
+///         max_order: 1,
+///     });
+/// let simulator = Simulator::try_new(&context, &simulation_settings)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct TrueAudioNextDevice {
+    inner: audionimbus_sys::IPLTrueAudioNextDevice,
+
+    /// Slots available for sources, shared by all handles to the device.
+    slots: Arc<Mutex<SlotPool>>,
+}
+
+/// Slots of a TrueAudio Next device.
+#[derive(Debug)]
+struct SlotPool {
+    num_slots: u32,
+
+    /// Slots not currently reserved, in reverse order so that the lowest slot is reserved first.
+    free: Vec<u32>,
+}
+
+impl SlotPool {
+    fn new(num_slots: u32) -> Self {
+        Self {
+            num_slots,
+            free: (0..num_slots).rev().collect(),
+        }
+    }
+}
+
+/// Slot pools of the devices created with [`TrueAudioNextDevice::try_new`], keyed by device
+/// address, so that handles wrapping a raw pointer to one of these devices share its slots.
+static SLOT_POOLS: Mutex<Vec<(usize, Weak<Mutex<SlotPool>>)>> = Mutex::new(Vec::new());
 
 impl TrueAudioNextDevice {
     /// Creates a new TrueAudio Next device for GPU-accelerated convolution and returns a handle to
@@ -26,13 +91,13 @@ impl TrueAudioNextDevice {
         open_cl_device: &OpenClDevice,
         settings: &TrueAudioNextDeviceSettings,
     ) -> Result<Self, SteamAudioError> {
-        let mut true_audio_next_device = Self(std::ptr::null_mut());
+        let mut inner = std::ptr::null_mut();
 
         let status = unsafe {
             audionimbus_sys::iplTrueAudioNextDeviceCreate(
                 open_cl_device.raw_ptr(),
                 &mut audionimbus_sys::IPLTrueAudioNextDeviceSettings::from(settings),
-                true_audio_next_device.raw_ptr_mut(),
+                &raw mut inner,
             )
         };
 
@@ -40,33 +105,119 @@ impl TrueAudioNextDevice {
             return Err(error);
         }
 
-        Ok(true_audio_next_device)
+        let slots = Arc::new(Mutex::new(SlotPool::new(settings.max_sources)));
+
+        let mut slot_pools = SLOT_POOLS.lock().unwrap_or_else(PoisonError::into_inner);
+        // A destroyed device may have had the same address.
+        slot_pools.retain(|(address, pool)| *address != inner as usize && pool.strong_count() > 0);
+        slot_pools.push((inner as usize, Arc::downgrade(&slots)));
+        drop(slot_pools);
+
+        Ok(Self { inner, slots })
+    }
+
+    /// Returns the total number of slots of the device, i.e. the maximum number of sources.
+    pub fn num_slots(&self) -> u32 {
+        self.slot_pool().num_slots
+    }
+
+    /// Returns the number of slots that are not currently reserved.
+    pub fn num_available_slots(&self) -> u32 {
+        self.slot_pool().free.len() as u32
+    }
+
+    /// Reserves a slot for a source and returns its index, or returns `None` if all slots are
+    /// reserved.
+    ///
+    /// The index should be used to create
+    /// [`ReflectionEffectParams`](crate::ReflectionEffectParams) for
+    /// [`TrueAudioNext`](crate::TrueAudioNext), and released with [`Self::release_slot`] once the
+    /// source is no longer rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use audionimbus::*;
+    /// # let context = Context::default();
+    /// # let audio_settings = AudioSettings::default();
+    /// # let device_list = OpenClDeviceList::try_new(&context, &OpenClDeviceSettings::default())?;
+    /// # let open_cl_device = OpenClDevice::try_new(&context, &device_list, 0)?;
+    /// let device = TrueAudioNextDevice::try_new(
+    ///     &open_cl_device,
+    ///     &TrueAudioNextDeviceSettings::new(&audio_settings, 2.0, 1, 2),
+    /// )?;
+    ///
+    /// let a = device.reserve_slot().unwrap();
+    /// let b = device.reserve_slot().unwrap();
+    /// assert!(device.reserve_slot().is_none());
+    ///
+    /// device.release_slot(a);
+    /// assert_eq!(device.reserve_slot(), Some(a));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn reserve_slot(&self) -> Option<u32> {
+        self.slot_pool().free.pop()
+    }
+
+    /// Releases a slot reserved with [`Self::reserve_slot`], making it available again.
+    ///
+    /// Releasing a slot that is out of range or not reserved does nothing.
+    pub fn release_slot(&self, slot: u32) {
+        let mut slot_pool = self.slot_pool();
+        if slot < slot_pool.num_slots && !slot_pool.free.contains(&slot) {
+            slot_pool.free.push(slot);
+        }
+    }
+
+    /// Locks the slots of the device.
+    ///
+    /// The pool is left consistent by every operation, so a poisoned lock is recovered.
+    fn slot_pool(&self) -> std::sync::MutexGuard<'_, SlotPool> {
+        self.slots.lock().unwrap_or_else(PoisonError::into_inner)
     }
 
     /// Returns the raw FFI pointer to the underlying TrueAudio Next device.
     ///
     /// This is intended for internal use and advanced scenarios.
     pub const fn raw_ptr(&self) -> audionimbus_sys::IPLTrueAudioNextDevice {
-        self.0
+        self.inner
     }
 
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLTrueAudioNextDevice {
-        &mut self.0
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLTrueAudioNextDevice {
+        &mut self.inner
     }
 }
 
 impl From<audionimbus_sys::IPLTrueAudioNextDevice> for TrueAudioNextDevice {
+    /// Wraps a raw pointer to a TrueAudio Next device.
+    ///
+    /// If the device was created with [`TrueAudioNextDevice::try_new`], the returned handle shares
+    /// its slots.
+    /// Otherwise, since Steam Audio does not expose the number of slots of a device, the returned
+    /// handle has no slots to reserve.
     fn from(ptr: audionimbus_sys::IPLTrueAudioNextDevice) -> Self {
-        Self(ptr)
+        let slots = SLOT_POOLS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .find(|(address, _)| *address == ptr as usize)
+            .and_then(|(_, pool)| pool.upgrade())
+            .unwrap_or_else(|| Arc::new(Mutex::new(SlotPool::new(0))));
+
+        Self { inner: ptr, slots }
     }
 }
 
 impl Drop for TrueAudioNextDevice {
     fn drop(&mut self) {
-        unsafe { audionimbus_sys::iplTrueAudioNextDeviceRelease(&raw mut self.0) }
+        unsafe { audionimbus_sys::iplTrueAudioNextDeviceRelease(&raw mut self.inner) }
     }
 }
 
@@ -78,11 +229,22 @@ impl Clone for TrueAudioNextDevice {
     ///
     /// The returned [`TrueAudioNextDevice`] shares the same underlying Steam Audio object.
     fn clone(&self) -> Self {
-        // SAFETY: The device will not be destroyed until all references are released.
-        Self(unsafe { audionimbus_sys::iplTrueAudioNextDeviceRetain(self.0) })
+        Self {
+            // SAFETY: The device will not be destroyed until all references are released.
+            inner: unsafe { audionimbus_sys::iplTrueAudioNextDeviceRetain(self.inner) },
+            slots: Arc::clone(&self.slots),
+        }
+    }
+}
+
+impl PartialEq for TrueAudioNextDevice {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw_ptr() == other.raw_ptr()
     }
 }
 
+impl Eq for TrueAudioNextDevice {}
+
 impl Hash for TrueAudioNextDevice {
     fn hash<H: Hasher>(&self, state: &mut H) {
         std::ptr::hash(self.raw_ptr(), state);
@@ -90,7 +252,7 @@ impl Hash for TrueAudioNextDevice {
 }
 
 /// Settings used to create a TrueAudio Next device.
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone)]
 pub struct TrueAudioNextDeviceSettings {
     /// The number of samples in an audio frame.
     pub frame_size: u32,
@@ -105,6 +267,37 @@ pub struct TrueAudioNextDeviceSettings {
     pub max_sources: u32,
 }
 
+impl TrueAudioNextDeviceSettings {
+    /// Creates settings for impulse responses of up to `max_duration` seconds and Ambisonic
+    /// order `order`, at the frame size and sampling rate of `audio_settings`.
+    ///
+    /// These should match the `max_duration` and `max_order` of the [`TrueAudioNextSettings`](crate::simulation::TrueAudioNextSettings)
+    /// used to create the simulator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let audio_settings = AudioSettings::default();
+    /// let settings = TrueAudioNextDeviceSettings::new(&audio_settings, 2.0, 1, 8);
+    /// assert_eq!(settings.impulse_response_size, 2 * audio_settings.sampling_rate);
+    /// ```
+    pub fn new(
+        audio_settings: &AudioSettings,
+        max_duration: f32,
+        order: u32,
+        max_sources: u32,
+    ) -> Self {
+        Self {
+            frame_size: audio_settings.frame_size,
+            impulse_response_size: (max_duration * audio_settings.sampling_rate as f32).ceil()
+                as u32,
+            order,
+            max_sources,
+        }
+    }
+}
+
 impl From<&TrueAudioNextDeviceSettings> for audionimbus_sys::IPLTrueAudioNextDeviceSettings {
     fn from(settings: &TrueAudioNextDeviceSettings) -> Self {
         Self {
@@ -142,4 +335,73 @@ mod tests {
         drop(true_audio_next_device);
         assert!(!clone.raw_ptr().is_null());
     }
+
+    #[test]
+    fn test_slots() {
+        let context = Context::default();
+        let open_cl_settings = OpenClDeviceSettings::default();
+        let Ok(device_list) = OpenClDeviceList::try_new(&context, &open_cl_settings) else {
+            // OpenCL not available
+            return;
+        };
+        let open_cl_device = OpenClDevice::try_new(&context, &device_list, 0).unwrap();
+        let true_audio_next_settings = TrueAudioNextDeviceSettings {
+            frame_size: 1024,
+            impulse_response_size: 1024,
+            order: 1,
+            max_sources: 2,
+        };
+        let true_audio_next_device =
+            TrueAudioNextDevice::try_new(&open_cl_device, &true_audio_next_settings).unwrap();
+        let clone = true_audio_next_device.clone();
+
+        assert_eq!(true_audio_next_device.num_slots(), 2);
+        let first = true_audio_next_device.reserve_slot().unwrap();
+        let second = clone.reserve_slot().unwrap();
+        assert_eq!(first, 0);
+        assert_eq!(second, 1);
+        assert!(true_audio_next_device.reserve_slot().is_none());
+        assert_eq!(true_audio_next_device.num_available_slots(), 0);
+
+        clone.release_slot(second);
+        assert_eq!(true_audio_next_device.num_available_slots(), 1);
+
+        // Releasing a slot that is not reserved does nothing.
+        clone.release_slot(second);
+        clone.release_slot(2);
+        assert_eq!(true_audio_next_device.num_available_slots(), 1);
+
+        drop(true_audio_next_device);
+        clone.release_slot(first);
+        assert_eq!(clone.num_available_slots(), 2);
+    }
+
+    #[test]
+    fn test_from_raw_ptr_shares_slots() {
+        let context = Context::default();
+        let open_cl_settings = OpenClDeviceSettings::default();
+        let Ok(device_list) = OpenClDeviceList::try_new(&context, &open_cl_settings) else {
+            // OpenCL not available
+            return;
+        };
+        let open_cl_device = OpenClDevice::try_new(&context, &device_list, 0).unwrap();
+        let true_audio_next_settings = TrueAudioNextDeviceSettings {
+            frame_size: 1024,
+            impulse_response_size: 1024,
+            order: 1,
+            max_sources: 2,
+        };
+        let true_audio_next_device =
+            TrueAudioNextDevice::try_new(&open_cl_device, &true_audio_next_settings).unwrap();
+        let from_raw_ptr = TrueAudioNextDevice::from(unsafe {
+            audionimbus_sys::iplTrueAudioNextDeviceRetain(true_audio_next_device.raw_ptr())
+        });
+
+        assert_eq!(from_raw_ptr, true_audio_next_device);
+        assert_eq!(from_raw_ptr.num_slots(), 2);
+        let slot = from_raw_ptr.reserve_slot().unwrap();
+        assert_eq!(true_audio_next_device.num_available_slots(), 1);
+        true_audio_next_device.release_slot(slot);
+        assert_eq!(from_raw_ptr.num_available_slots(), 2);
+    }
 }
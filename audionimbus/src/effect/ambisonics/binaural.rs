@@ -114,6 +114,8 @@ impl AmbisonicsBinauralEffect {
         I: AsRef<[Sample]>,
         O: AsRef<[Sample]> + AsMut<[Sample]>,
     {
+        profile_scope!("audionimbus::AmbisonicsBinauralEffect::apply");
+
         let required_input_channels =
             num_ambisonics_channels(ambisonics_binaural_effect_params.order);
         let num_input_channels = input_buffer.num_channels();
@@ -199,7 +201,13 @@ impl AmbisonicsBinauralEffect {
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLAmbisonicsBinauralEffect {
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(
+        &mut self,
+    ) -> &mut audionimbus_sys::IPLAmbisonicsBinauralEffect {
         &mut self.0
     }
 }
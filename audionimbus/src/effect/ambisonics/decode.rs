@@ -1,14 +1,15 @@
 //! Decoding Ambisonics to speakers or headphones.
 
-use super::super::{AudioEffectState, EffectError, SpeakerLayout};
+use super::super::binaural_renderer::stereo_output_buffer;
+use super::super::{AudioEffectState, BinauralRenderer, EffectError, SpeakerLayout};
 use crate::audio_buffer::{AudioBuffer, Sample};
 use crate::audio_settings::AudioSettings;
 use crate::context::Context;
 use crate::error::{SteamAudioError, to_option_error};
 use crate::geometry::CoordinateSystem;
 use crate::hrtf::Hrtf;
-use crate::num_ambisonics_channels;
-use crate::{ChannelPointers, ChannelRequirement};
+use crate::{ChannelPointers, ChannelRequirement, Sealed};
+use crate::{ambisonics_order_from_channels, num_ambisonics_channels};
 use std::hash::{Hash, Hasher};
 
 /// Applies a rotation to an ambisonics audio buffer, then decodes it using panning or binaural rendering.
@@ -109,14 +110,9 @@ impl AmbisonicsDecodeEffect {
             num_ambisonics_channels(ambisonics_decode_effect_settings.max_order);
         let num_output_channels = match ambisonics_decode_effect_settings.rendering {
             Rendering::Binaural => 2,
-            Rendering::Panning => match &ambisonics_decode_effect_settings.speaker_layout {
-                SpeakerLayout::Mono => 1,
-                SpeakerLayout::Stereo => 2,
-                SpeakerLayout::Quadraphonic => 4,
-                SpeakerLayout::Surround5_1 => 6,
-                SpeakerLayout::Surround7_1 => 8,
-                SpeakerLayout::Custom { speaker_directions } => speaker_directions.len() as u32,
-            },
+            Rendering::Panning => ambisonics_decode_effect_settings
+                .speaker_layout
+                .num_channels() as u32,
         };
 
         let ambisonics_decode_effect = Self {
@@ -156,6 +152,8 @@ impl AmbisonicsDecodeEffect {
         I: AsRef<[Sample]>,
         O: AsRef<[Sample]> + AsMut<[Sample]>,
     {
+        profile_scope!("audionimbus::AmbisonicsDecodeEffect::apply");
+
         let num_input_channels = input_buffer.num_channels();
         if num_input_channels != self.num_input_channels {
             return Err(EffectError::InvalidInputChannels {
@@ -193,6 +191,101 @@ impl AmbisonicsDecodeEffect {
         Ok(state)
     }
 
+    /// Decodes an ambisonics audio buffer to stereo for playback on headphones, as seen by a
+    /// listener with the given orientation.
+    ///
+    /// This is a convenience over [`Self::apply`] for the last step of the reflections pipeline.
+    /// The ambisonics order is inferred from the number of channels of the input buffer, and
+    /// `output` is resized to hold two channels of as many samples as the input buffer.
+    /// Reusing the same `output` across frames avoids reallocating it.
+    ///
+    /// The effect must have been created with binaural rendering (or with panning to a stereo
+    /// speaker layout).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EffectError`] if:
+    /// - The input buffer does not have the correct number of channels for the Ambisonics order
+    ///   used when creating the effect
+    /// - The effect does not output two channels
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// # let context = Context::default();
+    /// # let audio_settings = AudioSettings::default();
+    /// let hrtf = Hrtf::try_new(&context, &audio_settings, &HrtfSettings::default())?;
+    ///
+    /// let mut effect = AmbisonicsDecodeEffect::try_new(
+    ///     &context,
+    ///     &audio_settings,
+    ///     &AmbisonicsDecodeEffectSettings {
+    ///         speaker_layout: SpeakerLayout::Stereo,
+    ///         hrtf: hrtf.clone(),
+    ///         max_order: 1,
+    ///         rendering: Rendering::Binaural,
+    ///     },
+    /// )?;
+    ///
+    /// // Output of e.g. a reflection effect.
+    /// let ambisonics = vec![0.5; 4 * 1024];
+    /// let ambisonics_buffer = AudioBuffer::try_with_data_and_settings(
+    ///     &ambisonics,
+    ///     AudioBufferSettings::with_num_channels(4),
+    /// )?;
+    ///
+    /// let mut stereo = Vec::new();
+    /// let stereo_buffer = effect.decode_to_stereo(
+    ///     &ambisonics_buffer,
+    ///     &hrtf,
+    ///     &CoordinateSystem::default(),
+    ///     &mut stereo,
+    /// )?;
+    /// assert_eq!(stereo_buffer.num_channels(), 2);
+    /// assert_eq!(stereo_buffer.num_samples(), 1024);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn decode_to_stereo<'a, I, PI: ChannelPointers>(
+        &mut self,
+        input_buffer: &AudioBuffer<I, PI>,
+        hrtf: &Hrtf,
+        listener: &CoordinateSystem,
+        output: &'a mut Vec<Sample>,
+    ) -> Result<AudioBuffer<&'a mut [Sample]>, EffectError>
+    where
+        I: AsRef<[Sample]>,
+    {
+        let order = self.order_of(input_buffer)?;
+        let output_buffer = stereo_output_buffer(input_buffer.num_samples(), output);
+
+        let params = AmbisonicsDecodeEffectParams {
+            order,
+            hrtf: hrtf.clone(),
+            orientation: *listener,
+        };
+        self.apply(&params, input_buffer, &output_buffer)?;
+
+        Ok(output_buffer)
+    }
+
+    /// Returns the ambisonics order of `input_buffer`, inferred from its number of channels.
+    fn order_of<I, PI: ChannelPointers>(
+        &self,
+        input_buffer: &AudioBuffer<I, PI>,
+    ) -> Result<u32, EffectError>
+    where
+        I: AsRef<[Sample]>,
+    {
+        let num_input_channels = input_buffer.num_channels();
+        ambisonics_order_from_channels(num_input_channels).ok_or(
+            EffectError::InvalidInputChannels {
+                expected: ChannelRequirement::Exactly(self.num_input_channels),
+                actual: num_input_channels,
+            },
+        )
+    }
+
     /// Retrieves a single frame of tail samples from an Ambisonics decode effect’s internal buffers.
     ///
     /// After the input to the Ambisonics decode effect has stopped, this function must be called instead of [`Self::apply`] until the return value indicates that no more tail samples remain.
@@ -248,7 +341,11 @@ impl AmbisonicsDecodeEffect {
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLAmbisonicsDecodeEffect {
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLAmbisonicsDecodeEffect {
         &mut self.inner
     }
 }
@@ -291,6 +388,35 @@ impl Hash for AmbisonicsDecodeEffect {
     }
 }
 
+impl Sealed for AmbisonicsDecodeEffect {}
+
+/// The ambisonics order is inferred from the number of channels of the input buffer, and the mix
+/// is decoded with the default orientation.
+/// Use [`AmbisonicsDecodeEffect::apply`] directly to decode for a rotated listener.
+impl BinauralRenderer for AmbisonicsDecodeEffect {
+    fn num_input_channels(&self) -> u32 {
+        self.num_input_channels
+    }
+
+    fn render<I, O, PI: ChannelPointers, PO: ChannelPointers>(
+        &mut self,
+        input_buffer: &AudioBuffer<I, PI>,
+        hrtf: &Hrtf,
+        output_buffer: &AudioBuffer<O, PO>,
+    ) -> Result<AudioEffectState, EffectError>
+    where
+        I: AsRef<[Sample]>,
+        O: AsRef<[Sample]> + AsMut<[Sample]>,
+    {
+        let params = AmbisonicsDecodeEffectParams {
+            order: self.order_of(input_buffer)?,
+            hrtf: hrtf.clone(),
+            orientation: CoordinateSystem::default(),
+        };
+        self.apply(&params, input_buffer, output_buffer)
+    }
+}
+
 /// Settings used to create an ambisonics decode effect.
 #[derive(Debug, Clone)]
 pub struct AmbisonicsDecodeEffectSettings {
@@ -520,6 +646,85 @@ mod tests {
         }
     }
 
+    mod decode_to_stereo {
+        use super::*;
+
+        fn binaural_effect(context: &Context, hrtf: &Hrtf) -> AmbisonicsDecodeEffect {
+            AmbisonicsDecodeEffect::try_new(
+                context,
+                &AudioSettings::default(),
+                &AmbisonicsDecodeEffectSettings {
+                    speaker_layout: SpeakerLayout::Stereo,
+                    hrtf: hrtf.clone(),
+                    max_order: 1,
+                    rendering: Rendering::Binaural,
+                },
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn test_valid() {
+            let context = Context::default();
+            let audio_settings = AudioSettings::default();
+            let hrtf = Hrtf::try_new(&context, &audio_settings, &HrtfSettings::default()).unwrap();
+            let mut effect = binaural_effect(&context, &hrtf);
+
+            let input = vec![0.5; 4 * 1024];
+            let input_buffer = AudioBuffer::try_with_data_and_settings(
+                &input,
+                AudioBufferSettings::with_num_channels(4),
+            )
+            .unwrap();
+
+            // Stale contents from a previous frame are discarded.
+            let mut output = vec![1.0; 3];
+            let output_buffer = effect
+                .decode_to_stereo(
+                    &input_buffer,
+                    &hrtf,
+                    &CoordinateSystem::default(),
+                    &mut output,
+                )
+                .unwrap();
+
+            assert_eq!(output_buffer.num_channels(), 2);
+            assert_eq!(output_buffer.num_samples(), 1024);
+            assert_eq!(output.len(), 2 * 1024);
+        }
+
+        #[test]
+        fn test_invalid_input_channels() {
+            let context = Context::default();
+            let audio_settings = AudioSettings::default();
+            let hrtf = Hrtf::try_new(&context, &audio_settings, &HrtfSettings::default()).unwrap();
+            let mut effect = binaural_effect(&context, &hrtf);
+
+            let input = vec![0.5; 3 * 1024];
+            let input_buffer = AudioBuffer::try_with_data_and_settings(
+                &input,
+                AudioBufferSettings::with_num_channels(3),
+            )
+            .unwrap();
+
+            let mut output = Vec::new();
+            assert_eq!(
+                effect
+                    .decode_to_stereo(
+                        &input_buffer,
+                        &hrtf,
+                        &CoordinateSystem::default(),
+                        &mut output,
+                    )
+                    .map(|_| ()),
+                Err(EffectError::InvalidInputChannels {
+                    expected: ChannelRequirement::Exactly(4),
+                    actual: 3
+                })
+            );
+        }
+    }
+
     mod tail {
         use super::*;
 
@@ -1,14 +1,14 @@
 //! Encoding point sources to Ambisonics format.
 
 use super::super::{AudioEffectState, EffectError};
-use crate::audio_buffer::{AudioBuffer, Sample};
+use crate::audio_buffer::{AudioBuffer, AudioBufferSettings, Sample};
 use crate::audio_settings::AudioSettings;
 use crate::context::Context;
 use crate::error::{SteamAudioError, to_option_error};
 use crate::ffi_wrapper::FFIWrapper;
 use crate::geometry::Direction;
-use crate::num_ambisonics_channels;
 use crate::{ChannelPointers, ChannelRequirement};
+use crate::{ambisonics_order_from_channels, num_ambisonics_channels};
 use std::hash::{Hash, Hasher};
 
 /// Encodes a point source into ambisonics.
@@ -59,6 +59,9 @@ pub struct AmbisonicsEncodeEffect {
 
     /// Number of channels required for the ambisonic order specified when creating the effect.
     num_output_channels: u32,
+
+    /// Scratch samples each direction is encoded into by [`Self::apply_multi`].
+    scratch: Vec<Sample>,
 }
 
 impl AmbisonicsEncodeEffect {
@@ -94,6 +97,7 @@ impl AmbisonicsEncodeEffect {
         let ambisonics_encode_effect = Self {
             inner,
             num_output_channels,
+            scratch: Vec::new(),
         };
 
         Ok(ambisonics_encode_effect)
@@ -121,6 +125,144 @@ impl AmbisonicsEncodeEffect {
         I: AsRef<[Sample]>,
         O: AsRef<[Sample]> + AsMut<[Sample]>,
     {
+        profile_scope!("audionimbus::AmbisonicsEncodeEffect::apply");
+
+        self.validate_channels(input_buffer, output_buffer)?;
+
+        let state = unsafe {
+            audionimbus_sys::iplAmbisonicsEncodeEffectApply(
+                self.raw_ptr(),
+                &raw mut *ambisonics_encode_effect_params.as_ffi(),
+                &raw mut *input_buffer.as_ffi(),
+                &raw mut *output_buffer.as_ffi(),
+            )
+        }
+        .into();
+
+        Ok(state)
+    }
+
+    /// Encodes the same mono audio buffer from several directions at once, each with its own gain,
+    /// and writes the sum of the encoded signals to `output_buffer`.
+    ///
+    /// This models sources spread over an area (e.g. rain, or a crowd) as a small set of
+    /// directions.
+    /// Directions are encoded at the order specified when creating the effect.
+    ///
+    /// Unlike [`Self::apply`], the encoding of each direction is not interpolated from the
+    /// previous frame: the effect is reset before each direction is encoded.
+    /// Changing the directions abruptly between frames may therefore cause audible
+    /// discontinuities.
+    /// This also discards the interpolation state that [`Self::apply`] carries across frames, so
+    /// `apply_multi` must not be mixed with [`Self::apply`] on the same effect; use a separate
+    /// effect for each.
+    ///
+    /// This effect CANNOT be applied in-place.
+    ///
+    /// The input audio buffer must have 1 channel, and the output audio buffer must have as many
+    /// channels as needed for the Ambisonics order used when creating the effect (see
+    /// [`crate::num_ambisonics_channels`]).
+    /// If `directions` is empty, the output is silent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EffectError`] if:
+    /// - The input buffer does not have exactly 1 channel
+    /// - The output buffer does not have the correct number of channels for the Ambisonics order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// # let context = Context::default();
+    /// # let audio_settings = AudioSettings::default();
+    /// let mut effect = AmbisonicsEncodeEffect::try_new(
+    ///     &context,
+    ///     &audio_settings,
+    ///     &AmbisonicsEncodeEffectSettings { max_order: 1 },
+    /// )?;
+    ///
+    /// let rain = vec![0.5; 1024];
+    /// let input_buffer = AudioBuffer::try_with_data(&rain)?;
+    /// let mut output = vec![0.0; 4 * 1024];
+    /// let mut output_buffer = AudioBuffer::try_with_data_and_settings(
+    ///     &mut output,
+    ///     AudioBufferSettings::with_num_channels(4),
+    /// )?;
+    ///
+    /// // Rain falling in front of, and to the left of the listener.
+    /// let directions = [
+    ///     (Direction::new(0.0, 0.0, -1.0), 0.7),
+    ///     (Direction::new(-1.0, 0.0, 0.0), 0.3),
+    /// ];
+    /// effect.apply_multi(&directions, &input_buffer, &mut output_buffer)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn apply_multi<I, O, PI: ChannelPointers, PO: ChannelPointers>(
+        &mut self,
+        directions: &[(Direction, f32)],
+        input_buffer: &AudioBuffer<I, PI>,
+        output_buffer: &mut AudioBuffer<O, PO>,
+    ) -> Result<AudioEffectState, EffectError>
+    where
+        I: AsRef<[Sample]>,
+        O: AsRef<[Sample]> + AsMut<[Sample]>,
+    {
+        profile_scope!("audionimbus::AmbisonicsEncodeEffect::apply_multi");
+
+        self.validate_channels(input_buffer, output_buffer)?;
+
+        for channel in output_buffer.channels_mut() {
+            channel.fill(0.0);
+        }
+
+        let order = ambisonics_order_from_channels(self.num_output_channels)
+            .expect("number of output channels is derived from an ambisonics order");
+
+        let mut scratch = std::mem::take(&mut self.scratch);
+        scratch.resize(
+            self.num_output_channels as usize * input_buffer.num_samples() as usize,
+            0.0,
+        );
+
+        let mut state = AudioEffectState::TailComplete;
+        for &(direction, gain) in directions {
+            let scratch_buffer = AudioBuffer::try_with_data_and_settings(
+                scratch.as_mut_slice(),
+                AudioBufferSettings::with_num_channels(self.num_output_channels),
+            )
+            .expect("scratch holds the output channels of at least one sample");
+
+            self.reset();
+            state = self.apply(
+                &AmbisonicsEncodeEffectParams { direction, order },
+                input_buffer,
+                &scratch_buffer,
+            )?;
+
+            for (output_channel, encoded_channel) in
+                output_buffer.channels_mut().zip(scratch_buffer.channels())
+            {
+                for (output_sample, encoded_sample) in
+                    output_channel.iter_mut().zip(encoded_channel)
+                {
+                    *output_sample += gain * encoded_sample;
+                }
+            }
+        }
+
+        self.scratch = scratch;
+
+        Ok(state)
+    }
+
+    /// Checks that the input buffer is mono, and that the output buffer has as many channels as
+    /// needed for the Ambisonics order specified when creating the effect.
+    fn validate_channels<I, O, PI: ChannelPointers, PO: ChannelPointers>(
+        &self,
+        input_buffer: &AudioBuffer<I, PI>,
+        output_buffer: &AudioBuffer<O, PO>,
+    ) -> Result<(), EffectError> {
         let num_input_channels = input_buffer.num_channels();
         if num_input_channels != 1 {
             return Err(EffectError::InvalidInputChannels {
@@ -137,17 +279,7 @@ impl AmbisonicsEncodeEffect {
             });
         }
 
-        let state = unsafe {
-            audionimbus_sys::iplAmbisonicsEncodeEffectApply(
-                self.raw_ptr(),
-                &raw mut *ambisonics_encode_effect_params.as_ffi(),
-                &raw mut *input_buffer.as_ffi(),
-                &raw mut *output_buffer.as_ffi(),
-            )
-        }
-        .into();
-
-        Ok(state)
+        Ok(())
     }
 
     /// Retrieves a single frame of tail samples from an Ambisonics encode effect’s internal buffers.
@@ -204,7 +336,11 @@ impl AmbisonicsEncodeEffect {
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLAmbisonicsEncodeEffect {
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLAmbisonicsEncodeEffect {
         &mut self.inner
     }
 }
@@ -227,6 +363,7 @@ impl Clone for AmbisonicsEncodeEffect {
         Self {
             inner: unsafe { audionimbus_sys::iplAmbisonicsEncodeEffectRetain(self.inner) },
             num_output_channels: self.num_output_channels,
+            scratch: Vec::new(),
         }
     }
 }
@@ -403,6 +540,101 @@ mod tests {
         }
     }
 
+    mod apply_multi {
+        use super::*;
+
+        fn encode(
+            effect: &mut AmbisonicsEncodeEffect,
+            directions: &[(Direction, f32)],
+        ) -> Vec<Sample> {
+            let input: Vec<Sample> = (0..1024).map(|i| (i as Sample * 0.01).sin()).collect();
+            let input_buffer = AudioBuffer::try_with_data(&input).unwrap();
+
+            let mut output = vec![1.0; 4 * 1024];
+            let mut output_buffer = AudioBuffer::try_with_data_and_settings(
+                &mut output,
+                AudioBufferSettings::with_num_channels(4),
+            )
+            .unwrap();
+            effect
+                .apply_multi(directions, &input_buffer, &mut output_buffer)
+                .unwrap();
+
+            output
+        }
+
+        #[test]
+        fn test_sums_directions() {
+            let context = Context::default();
+            let audio_settings = AudioSettings::default();
+
+            let mut effect = AmbisonicsEncodeEffect::try_new(
+                &context,
+                &audio_settings,
+                &AmbisonicsEncodeEffectSettings { max_order: 1 },
+            )
+            .unwrap();
+
+            let direction = Direction::new(1.0, 0.0, 0.0);
+            let single = encode(&mut effect, &[(direction, 1.0)]);
+            let double = encode(&mut effect, &[(direction, 0.5), (direction, 1.5)]);
+
+            for (single_sample, double_sample) in single.iter().zip(&double) {
+                assert!((2.0 * single_sample - double_sample).abs() < 1e-5);
+            }
+        }
+
+        #[test]
+        fn test_no_directions() {
+            let context = Context::default();
+            let audio_settings = AudioSettings::default();
+
+            let mut effect = AmbisonicsEncodeEffect::try_new(
+                &context,
+                &audio_settings,
+                &AmbisonicsEncodeEffectSettings { max_order: 1 },
+            )
+            .unwrap();
+
+            assert!(encode(&mut effect, &[]).iter().all(|&sample| sample == 0.0));
+        }
+
+        #[test]
+        fn test_invalid_output_channels() {
+            let context = Context::default();
+            let audio_settings = AudioSettings::default();
+
+            let mut effect = AmbisonicsEncodeEffect::try_new(
+                &context,
+                &audio_settings,
+                &AmbisonicsEncodeEffectSettings { max_order: 1 },
+            )
+            .unwrap();
+
+            let input = vec![0.5; 1024];
+            let input_buffer = AudioBuffer::try_with_data(&input).unwrap();
+
+            let mut output = vec![0.0; 2 * 1024];
+            let mut output_buffer = AudioBuffer::try_with_data_and_settings(
+                &mut output,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+
+            assert_eq!(
+                effect.apply_multi(
+                    &[(Direction::new(1.0, 0.0, 0.0), 1.0)],
+                    &input_buffer,
+                    &mut output_buffer
+                ),
+                Err(EffectError::InvalidOutputChannels {
+                    expected: ChannelRequirement::Exactly(4),
+                    actual: 2
+                })
+            );
+        }
+    }
+
     mod tail {
         use super::*;
 
@@ -57,6 +57,9 @@ pub struct AmbisonicsPanningEffect {
 
     /// Number of output channels needed for the speaker layout specified when creating the effect.
     num_output_channels: u32,
+
+    /// Maximum Ambisonic order specified when creating the effect.
+    max_order: u32,
 }
 
 impl AmbisonicsPanningEffect {
@@ -89,17 +92,13 @@ impl AmbisonicsPanningEffect {
             return Err(error);
         }
 
-        let num_output_channels = match &ambisonics_panning_effect_settings.speaker_layout {
-            SpeakerLayout::Mono => 1,
-            SpeakerLayout::Stereo => 2,
-            SpeakerLayout::Quadraphonic => 4,
-            SpeakerLayout::Surround5_1 => 6,
-            SpeakerLayout::Surround7_1 => 8,
-            SpeakerLayout::Custom { speaker_directions } => speaker_directions.len() as u32,
-        };
+        let num_output_channels = ambisonics_panning_effect_settings
+            .speaker_layout
+            .num_channels() as u32;
         let ambisonics_panning_effect = Self {
             inner,
             num_output_channels,
+            max_order: ambisonics_panning_effect_settings.max_order,
         };
 
         Ok(ambisonics_panning_effect)
@@ -118,6 +117,7 @@ impl AmbisonicsPanningEffect {
     /// # Errors
     ///
     /// Returns [`EffectError`] if:
+    /// - The Ambisonics order exceeds the maximum order specified when creating the effect
     /// - The input buffer does not have the correct number of channels for the Ambisonics order
     /// - The output buffer does not have the correct number of channels for the speaker layout
     pub fn apply<I, O, PI: ChannelPointers, PO: ChannelPointers>(
@@ -130,6 +130,15 @@ impl AmbisonicsPanningEffect {
         I: AsRef<[Sample]>,
         O: AsRef<[Sample]> + AsMut<[Sample]>,
     {
+        profile_scope!("audionimbus::AmbisonicsPanningEffect::apply");
+
+        if ambisonics_panning_effect_params.order > self.max_order {
+            return Err(EffectError::AmbisonicsOrderExceedsMax {
+                requested: ambisonics_panning_effect_params.order,
+                max: self.max_order,
+            });
+        }
+
         let required_input_channels =
             num_ambisonics_channels(ambisonics_panning_effect_params.order);
         let num_input_channels = input_buffer.num_channels();
@@ -216,7 +225,11 @@ impl AmbisonicsPanningEffect {
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLAmbisonicsPanningEffect {
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLAmbisonicsPanningEffect {
         &mut self.inner
     }
 }
@@ -408,6 +421,46 @@ mod tests {
                 })
             );
         }
+
+        #[test]
+        fn test_order_exceeds_max() {
+            let context = Context::default();
+            let audio_settings = AudioSettings::default();
+
+            let mut effect = AmbisonicsPanningEffect::try_new(
+                &context,
+                &audio_settings,
+                &AmbisonicsPanningEffectSettings {
+                    speaker_layout: SpeakerLayout::Stereo,
+                    max_order: 1,
+                },
+            )
+            .unwrap();
+
+            let params = AmbisonicsPanningEffectParams { order: 2 };
+
+            let mut input = vec![0.5; 9 * 1024];
+            let input_buffer = AudioBuffer::try_with_data_and_settings(
+                &mut input,
+                AudioBufferSettings::with_num_channels(9),
+            )
+            .unwrap();
+
+            let mut output = vec![0.0; 2 * 1024];
+            let output_buffer = AudioBuffer::try_with_data_and_settings(
+                &mut output,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+
+            assert_eq!(
+                effect.apply(&params, &input_buffer, &output_buffer),
+                Err(EffectError::AmbisonicsOrderExceedsMax {
+                    requested: 2,
+                    max: 1,
+                })
+            );
+        }
     }
 
     mod tail {
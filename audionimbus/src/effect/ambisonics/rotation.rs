@@ -54,6 +54,52 @@ use std::hash::{Hash, Hasher};
 /// let _ = effect.apply(&params, &input_buffer, &output_buffer);
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
+///
+/// ## Multiple listeners
+///
+/// A sound field encoded once in world space can be re-oriented for several listeners (e.g.,
+/// split-screen) by rotating the same input buffer with one effect per listener.
+/// Each listener needs its own effect, since the effect smoothly interpolates from the previous
+/// orientation it was applied with.
+///
+/// ```
+/// # use audionimbus::*;
+/// # let context = Context::default();
+/// # let audio_settings = AudioSettings::default();
+/// let settings = AmbisonicsRotationEffectSettings { max_order: 1 };
+/// let mut effects = [
+///     AmbisonicsRotationEffect::try_new(&context, &audio_settings, &settings)?,
+///     AmbisonicsRotationEffect::try_new(&context, &audio_settings, &settings)?,
+/// ];
+/// let listeners = [
+///     CoordinateSystem::default(),
+///     CoordinateSystem {
+///         right: Vector3::new(0.0, 0.0, -1.0),
+///         up: Vector3::new(0.0, 1.0, 0.0),
+///         ahead: Vector3::new(-1.0, 0.0, 0.0),
+///         origin: Point::new(0.0, 0.0, 0.0),
+///     },
+/// ];
+///
+/// const FRAME_SIZE: usize = 1024;
+/// let input = vec![0.5; 4 * FRAME_SIZE];
+/// let input_buffer =
+///     AudioBuffer::try_with_data_and_settings(&input, AudioBufferSettings::with_num_channels(4))?;
+///
+/// for (effect, listener) in effects.iter_mut().zip(listeners) {
+///     let mut output = vec![0.0; 4 * FRAME_SIZE];
+///     let output_buffer = AudioBuffer::try_with_data_and_settings(
+///         &mut output,
+///         AudioBufferSettings::with_num_channels(4),
+///     )?;
+///     let params = AmbisonicsRotationEffectParams {
+///         orientation: listener,
+///         order: 1,
+///     };
+///     effect.apply(&params, &input_buffer, &output_buffer)?;
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
 #[derive(Debug)]
 pub struct AmbisonicsRotationEffect {
     inner: audionimbus_sys::IPLAmbisonicsRotationEffect,
@@ -122,6 +168,8 @@ impl AmbisonicsRotationEffect {
         I: AsRef<[Sample]>,
         O: AsRef<[Sample]> + AsMut<[Sample]>,
     {
+        profile_scope!("audionimbus::AmbisonicsRotationEffect::apply");
+
         let num_input_channels = input_buffer.num_channels();
         if num_input_channels != self.num_channels {
             return Err(EffectError::InvalidInputChannels {
@@ -206,7 +254,13 @@ impl AmbisonicsRotationEffect {
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLAmbisonicsRotationEffect {
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(
+        &mut self,
+    ) -> &mut audionimbus_sys::IPLAmbisonicsRotationEffect {
         &mut self.inner
     }
 }
@@ -1,4 +1,5 @@
 use crate::geometry::Direction;
+use std::f32::consts::FRAC_1_SQRT_2;
 use std::fmt;
 use std::ops::{Deref, DerefMut};
 
@@ -28,6 +29,73 @@ pub enum SpeakerLayout {
 }
 
 impl SpeakerLayout {
+    /// Returns the number of channels (speakers) of this layout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// assert_eq!(SpeakerLayout::Surround5_1.num_channels(), 6);
+    ///
+    /// let layout = SpeakerLayout::Custom {
+    ///     speaker_directions: SpeakerLayout::Quadraphonic.speaker_directions(),
+    /// };
+    /// assert_eq!(layout.num_channels(), 4);
+    /// ```
+    pub fn num_channels(&self) -> usize {
+        match self {
+            Self::Mono => 1,
+            Self::Stereo => 2,
+            Self::Quadraphonic => 4,
+            Self::Surround5_1 => 6,
+            Self::Surround7_1 => 8,
+            Self::Custom { speaker_directions } => speaker_directions.len(),
+        }
+    }
+
+    /// Returns the unit-length direction of each speaker, in channel order.
+    ///
+    /// Directions follow Steam Audio's convention (+x right, +y up, -z ahead), and match the
+    /// placement Steam Audio uses for the standard layouts:
+    /// - Front speakers are 45° to each side, and rear speakers 135°.
+    /// - Side speakers are 90° to each side.
+    /// - The center and LFE speakers are straight ahead.
+    /// - The single speaker of [`Self::Mono`] is straight ahead.
+    pub fn speaker_directions(&self) -> Vec<Direction> {
+        const LEFT: Direction = Direction::new(-1.0, 0.0, 0.0);
+        const RIGHT: Direction = Direction::new(1.0, 0.0, 0.0);
+        const CENTER: Direction = Direction::new(0.0, 0.0, -1.0);
+        const FRONT_LEFT: Direction = Direction::new(-FRAC_1_SQRT_2, 0.0, -FRAC_1_SQRT_2);
+        const FRONT_RIGHT: Direction = Direction::new(FRAC_1_SQRT_2, 0.0, -FRAC_1_SQRT_2);
+        const REAR_LEFT: Direction = Direction::new(-FRAC_1_SQRT_2, 0.0, FRAC_1_SQRT_2);
+        const REAR_RIGHT: Direction = Direction::new(FRAC_1_SQRT_2, 0.0, FRAC_1_SQRT_2);
+
+        match self {
+            Self::Mono => vec![CENTER],
+            Self::Stereo => vec![LEFT, RIGHT],
+            Self::Quadraphonic => vec![FRONT_LEFT, FRONT_RIGHT, REAR_LEFT, REAR_RIGHT],
+            Self::Surround5_1 => vec![
+                FRONT_LEFT,
+                FRONT_RIGHT,
+                CENTER,
+                CENTER,
+                REAR_LEFT,
+                REAR_RIGHT,
+            ],
+            Self::Surround7_1 => vec![
+                FRONT_LEFT,
+                FRONT_RIGHT,
+                CENTER,
+                CENTER,
+                REAR_LEFT,
+                REAR_RIGHT,
+                LEFT,
+                RIGHT,
+            ],
+            Self::Custom { speaker_directions } => speaker_directions.clone(),
+        }
+    }
+
     /// Returns the name of this speaker layout.
     const fn name(&self) -> &'static str {
         match self {
@@ -103,3 +171,43 @@ impl DerefMut for SpeakerLayoutFfi {
         &mut self.layout
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod speaker_directions {
+        use super::*;
+
+        #[test]
+        fn test_matches_num_channels() {
+            for layout in [
+                SpeakerLayout::Mono,
+                SpeakerLayout::Stereo,
+                SpeakerLayout::Quadraphonic,
+                SpeakerLayout::Surround5_1,
+                SpeakerLayout::Surround7_1,
+            ] {
+                let directions = layout.speaker_directions();
+                assert_eq!(directions.len(), layout.num_channels(), "{layout}");
+
+                for direction in directions {
+                    let length_squared = direction.x * direction.x
+                        + direction.y * direction.y
+                        + direction.z * direction.z;
+                    assert!((length_squared - 1.0).abs() < 1e-6, "{layout}");
+                }
+            }
+        }
+
+        #[test]
+        fn test_custom() {
+            let speaker_directions = vec![Direction::new(0.0, 1.0, 0.0)];
+            let layout = SpeakerLayout::Custom {
+                speaker_directions: speaker_directions.clone(),
+            };
+            assert_eq!(layout.num_channels(), 1);
+            assert_eq!(layout.speaker_directions(), speaker_directions);
+        }
+    }
+}
@@ -36,26 +36,24 @@ use std::hash::{Hash, Hasher};
 ///     &BinauralEffectSettings { hrtf: hrtf.clone() },
 /// )?;
 ///
-/// let params = BinauralEffectParams {
-///     direction: Direction::new(1.0, 0.0, 0.0), // Sound from the right
-///     interpolation: HrtfInterpolation::Nearest,
-///     spatial_blend: 1.0,
-///     hrtf,
-///     peak_delays: None,
-/// };
+/// // Sound from the right.
+/// let params = BinauralEffectParams::new(Direction::new(1.0, 0.0, 0.0), hrtf);
 ///
 /// let input_buffer = AudioBuffer::try_with_data([1.0; 1024])?;
 /// let mut output_container = vec![0.0; 2 * input_buffer.num_samples() as usize];
-/// let mut output_buffer = AudioBuffer::try_with_data_and_settings(
-///     &mut output_container,
-///     AudioBufferSettings::with_num_channels(2),
-/// )?;
+/// let mut output_buffer = AudioBuffer::try_output(&mut output_container, 2)?;
 ///
 /// let _ = effect.apply(&params, &input_buffer, &mut output_buffer);
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
-#[derive(Debug, PartialEq, Eq)]
-pub struct BinauralEffect(audionimbus_sys::IPLBinauralEffect);
+#[derive(Debug)]
+pub struct BinauralEffect {
+    inner: audionimbus_sys::IPLBinauralEffect,
+
+    /// HRTF the effect was created with.
+    /// Used to validate the HRTF passed when applying the effect.
+    hrtf: Hrtf,
+}
 
 impl BinauralEffect {
     /// Creates a new binaural effect and returns a handle to it.
@@ -68,14 +66,14 @@ impl BinauralEffect {
         audio_settings: &AudioSettings,
         binaural_effect_settings: &BinauralEffectSettings,
     ) -> Result<Self, SteamAudioError> {
-        let mut binaural_effect = Self(std::ptr::null_mut());
+        let mut inner = std::ptr::null_mut();
 
         let status = unsafe {
             audionimbus_sys::iplBinauralEffectCreate(
                 context.raw_ptr(),
                 &mut audionimbus_sys::IPLAudioSettings::from(audio_settings),
                 &mut audionimbus_sys::IPLBinauralEffectSettings::from(binaural_effect_settings),
-                binaural_effect.raw_ptr_mut(),
+                &raw mut inner,
             )
         };
 
@@ -83,6 +81,11 @@ impl BinauralEffect {
             return Err(error);
         }
 
+        let binaural_effect = Self {
+            inner,
+            hrtf: binaural_effect_settings.hrtf.clone(),
+        };
+
         Ok(binaural_effect)
     }
 
@@ -92,6 +95,9 @@ impl BinauralEffect {
     ///
     /// The input audio buffer must have 1 or 2 channels, and the output audio buffer must have 2 channels.
     ///
+    /// `binaural_effect_params.hrtf` may differ from the HRTF the effect was created with, which
+    /// allows switching HRTFs at runtime. Use [`Self::check_hrtf`] to opt into validating it.
+    ///
     /// # Errors
     ///
     /// Returns [`EffectError`] if:
@@ -107,6 +113,8 @@ impl BinauralEffect {
         I: AsRef<[Sample]>,
         O: AsRef<[Sample]> + AsMut<[Sample]>,
     {
+        profile_scope!("audionimbus::BinauralEffect::apply");
+
         let num_input_channels = input_buffer.num_channels();
         if !(1..=2).contains(&num_input_channels) {
             return Err(EffectError::InvalidInputChannels {
@@ -180,24 +188,52 @@ impl BinauralEffect {
         unsafe { audionimbus_sys::iplBinauralEffectReset(self.raw_ptr()) };
     }
 
+    /// Returns the HRTF the effect was created with.
+    pub fn hrtf(&self) -> &Hrtf {
+        &self.hrtf
+    }
+
+    /// Checks that the HRTF in `binaural_effect_params` is the one the effect was created with.
+    ///
+    /// [`Self::apply`] accepts any HRTF so that it can be switched at runtime; this is an opt-in
+    /// check for callers that never switch HRTFs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EffectError::HrtfMismatch`] if the HRTFs differ.
+    pub fn check_hrtf(
+        &self,
+        binaural_effect_params: &BinauralEffectParams,
+    ) -> Result<(), EffectError> {
+        if binaural_effect_params.hrtf != self.hrtf {
+            return Err(EffectError::HrtfMismatch);
+        }
+
+        Ok(())
+    }
+
     /// Returns the raw FFI pointer to the underlying binaural effect.
     ///
     /// This is intended for internal use and advanced scenarios.
     pub const fn raw_ptr(&self) -> audionimbus_sys::IPLBinauralEffect {
-        self.0
+        self.inner
     }
 
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLBinauralEffect {
-        &mut self.0
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLBinauralEffect {
+        &mut self.inner
     }
 }
 
 impl Drop for BinauralEffect {
     fn drop(&mut self) {
-        unsafe { audionimbus_sys::iplBinauralEffectRelease(&raw mut self.0) }
+        unsafe { audionimbus_sys::iplBinauralEffectRelease(&raw mut self.inner) }
     }
 }
 
@@ -210,10 +246,21 @@ impl Clone for BinauralEffect {
     /// The returned [`BinauralEffect`] shares the same underlying Steam Audio object.
     fn clone(&self) -> Self {
         // SAFETY: The binaural effect will not be destroyed until all references are released.
-        Self(unsafe { audionimbus_sys::iplBinauralEffectRetain(self.0) })
+        Self {
+            inner: unsafe { audionimbus_sys::iplBinauralEffectRetain(self.inner) },
+            hrtf: self.hrtf.clone(),
+        }
+    }
+}
+
+impl PartialEq for BinauralEffect {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw_ptr() == other.raw_ptr()
     }
 }
 
+impl Eq for BinauralEffect {}
+
 impl Hash for BinauralEffect {
     fn hash<H: Hasher>(&self, state: &mut H) {
         std::ptr::hash(self.raw_ptr(), state);
@@ -248,6 +295,11 @@ pub struct BinauralEffectParams {
     ///
     /// When set to 0.0, output audio is not spatialized at all and is close to input audio.
     /// If set to 1.0, output audio is fully spatialized.
+    ///
+    /// This is the means Steam Audio provides to give a source some width: values below 1.0
+    /// soften the localization of sources that should not sound like a single point, such as a
+    /// waterfall or a crowd.
+    /// See [`Self::with_spread`].
     pub spatial_blend: f32,
 
     /// The HRTF to use.
@@ -259,6 +311,57 @@ pub struct BinauralEffectParams {
 }
 
 impl BinauralEffectParams {
+    /// Creates parameters that fully spatialize a source in the given direction.
+    ///
+    /// Uses [`HrtfInterpolation::Bilinear`], which avoids the stepping artifacts of
+    /// nearest-neighbor filtering on moving sources, and does not write peak delays.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// # let context = Context::default();
+    /// # let audio_settings = AudioSettings::default();
+    /// # let hrtf = Hrtf::try_new(&context, &audio_settings, &HrtfSettings::default())?;
+    /// let params = BinauralEffectParams::new(Direction::new(1.0, 0.0, 0.0), hrtf);
+    /// assert_eq!(params.interpolation, HrtfInterpolation::Bilinear);
+    /// assert_eq!(params.spatial_blend, 1.0);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(direction: Direction, hrtf: Hrtf) -> Self {
+        Self {
+            direction,
+            interpolation: HrtfInterpolation::default(),
+            spatial_blend: 1.0,
+            hrtf,
+            peak_delays: None,
+        }
+    }
+
+    /// Sets how spread out the source sounds, from 0.0 (a point source) to 1.0 (not localized at
+    /// all).
+    ///
+    /// Steam Audio has no notion of source extent, so this sets [`Self::spatial_blend`] to
+    /// `1.0 - spread`, blending the spatialized audio with the input audio.
+    /// `spread` is clamped to `[0.0, 1.0]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// # let context = Context::default();
+    /// # let audio_settings = AudioSettings::default();
+    /// # let hrtf = Hrtf::try_new(&context, &audio_settings, &HrtfSettings::default())?;
+    /// // Ambience that should only loosely come from its direction.
+    /// let params = BinauralEffectParams::new(Direction::new(1.0, 0.0, 0.0), hrtf).with_spread(0.75);
+    /// assert_eq!(params.spatial_blend, 0.25);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_spread(mut self, spread: f32) -> Self {
+        self.spatial_blend = 1.0 - spread.clamp(0.0, 1.0);
+        self
+    }
+
     pub(crate) fn as_ffi(&self) -> FFIWrapper<'_, audionimbus_sys::IPLBinauralEffectParams, Self> {
         let peak_delays_ptr = self
             .peak_delays
@@ -441,6 +544,44 @@ mod tests {
         }
     }
 
+    mod hrtf {
+        use super::*;
+
+        #[test]
+        fn test_mismatched_hrtf() {
+            let context = Context::default();
+            let audio_settings = AudioSettings::default();
+            let hrtf = Hrtf::try_new(&context, &audio_settings, &HrtfSettings::default()).unwrap();
+            let other_hrtf =
+                Hrtf::try_new(&context, &audio_settings, &HrtfSettings::default()).unwrap();
+
+            let mut effect = BinauralEffect::try_new(
+                &context,
+                &audio_settings,
+                &BinauralEffectSettings { hrtf: hrtf.clone() },
+            )
+            .unwrap();
+            assert_eq!(effect.hrtf(), &hrtf);
+
+            let input = vec![0.5; 1024];
+            let input_buffer = AudioBuffer::try_with_data(&input).unwrap();
+            let mut output = vec![0.0; 2 * 1024];
+            let output_buffer = AudioBuffer::try_with_data_and_settings(
+                &mut output,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+
+            let params = BinauralEffectParams::new(Direction::new(1.0, 0.0, 0.0), other_hrtf);
+            assert_eq!(effect.check_hrtf(&params), Err(EffectError::HrtfMismatch));
+            assert!(effect.apply(&params, &input_buffer, &output_buffer).is_ok());
+
+            let params = BinauralEffectParams::new(Direction::new(1.0, 0.0, 0.0), hrtf);
+            assert_eq!(effect.check_hrtf(&params), Ok(()));
+            assert!(effect.apply(&params, &input_buffer, &output_buffer).is_ok());
+        }
+    }
+
     mod tail {
         use super::*;
 
@@ -497,6 +638,23 @@ mod tests {
         }
     }
 
+    mod with_spread {
+        use super::*;
+
+        #[test]
+        fn test_clamps_spread() {
+            let context = Context::default();
+            let audio_settings = AudioSettings::default();
+            let hrtf = Hrtf::try_new(&context, &audio_settings, &HrtfSettings::default()).unwrap();
+            let params = BinauralEffectParams::new(Direction::new(1.0, 0.0, 0.0), hrtf);
+
+            assert_eq!(params.with_spread(0.0).spatial_blend, 1.0);
+
+            let params = BinauralEffectParams::new(Direction::new(1.0, 0.0, 0.0), params.hrtf);
+            assert_eq!(params.with_spread(2.0).spatial_blend, 0.0);
+        }
+    }
+
     mod clone {
         use super::*;
 
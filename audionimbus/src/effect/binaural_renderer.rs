@@ -0,0 +1,119 @@
+//! Rendering multi-channel audio to stereo for headphones.
+
+use super::{AudioEffectState, EffectError};
+use crate::audio_buffer::{AudioBuffer, AudioBufferSettings, Sample};
+use crate::hrtf::Hrtf;
+use crate::{ChannelPointers, Sealed};
+
+/// An effect that renders a multi-channel mix to stereo for playback on headphones.
+///
+/// Both [`VirtualSurroundEffect`](super::VirtualSurroundEffect) (speaker-based mixes) and
+/// [`AmbisonicsDecodeEffect`](super::AmbisonicsDecodeEffect) (ambisonics mixes) implement this
+/// trait, so that code generic over [`BinauralRenderer`] can switch between them by changing only
+/// the effect it is given.
+///
+/// # Examples
+///
+/// ```
+/// # use audionimbus::*;
+/// # let context = Context::default();
+/// # let audio_settings = AudioSettings::default();
+/// let hrtf = Hrtf::try_new(&context, &audio_settings, &HrtfSettings::default())?;
+///
+/// fn render(
+///     renderer: &mut impl BinauralRenderer,
+///     hrtf: &Hrtf,
+/// ) -> Result<Vec<Sample>, EffectError> {
+///     let mix = vec![0.5; renderer.num_input_channels() as usize * 1024];
+///     let mix_buffer = AudioBuffer::try_with_data_and_settings(
+///         &mix,
+///         AudioBufferSettings::with_num_channels(renderer.num_input_channels()),
+///     )
+///     .unwrap();
+///
+///     let mut stereo = Vec::new();
+///     renderer.render_to_stereo(&mix_buffer, hrtf, &mut stereo)?;
+///     Ok(stereo)
+/// }
+///
+/// let mut virtual_surround = VirtualSurroundEffect::try_new(
+///     &context,
+///     &audio_settings,
+///     &VirtualSurroundEffectSettings {
+///         speaker_layout: SpeakerLayout::Surround7_1,
+///         hrtf: hrtf.clone(),
+///     },
+/// )?;
+/// let mut ambisonics_decode = AmbisonicsDecodeEffect::try_new(
+///     &context,
+///     &audio_settings,
+///     &AmbisonicsDecodeEffectSettings {
+///         speaker_layout: SpeakerLayout::Stereo,
+///         hrtf: hrtf.clone(),
+///         max_order: 1,
+///         rendering: Rendering::Binaural,
+///     },
+/// )?;
+///
+/// let a = render(&mut virtual_surround, &hrtf)?;
+/// let b = render(&mut ambisonics_decode, &hrtf)?;
+/// assert_eq!(a.len(), b.len());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub trait BinauralRenderer: Sealed {
+    /// Returns the number of channels input audio buffers must have.
+    fn num_input_channels(&self) -> u32;
+
+    /// Renders `input_buffer` to the 2-channel `output_buffer` using `hrtf`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EffectError`] if the input buffer does not have
+    /// [`Self::num_input_channels`] channels, or if the effect does not output two channels.
+    fn render<I, O, PI: ChannelPointers, PO: ChannelPointers>(
+        &mut self,
+        input_buffer: &AudioBuffer<I, PI>,
+        hrtf: &Hrtf,
+        output_buffer: &AudioBuffer<O, PO>,
+    ) -> Result<AudioEffectState, EffectError>
+    where
+        I: AsRef<[Sample]>,
+        O: AsRef<[Sample]> + AsMut<[Sample]>;
+
+    /// Renders `input_buffer` to stereo using `hrtf`.
+    ///
+    /// `output` is resized to hold two channels of as many samples as the input buffer.
+    /// Reusing the same `output` across frames avoids reallocating it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EffectError`] under the same conditions as [`Self::render`].
+    fn render_to_stereo<'a, I, PI: ChannelPointers>(
+        &mut self,
+        input_buffer: &AudioBuffer<I, PI>,
+        hrtf: &Hrtf,
+        output: &'a mut Vec<Sample>,
+    ) -> Result<AudioBuffer<&'a mut [Sample]>, EffectError>
+    where
+        I: AsRef<[Sample]>,
+    {
+        let output_buffer = stereo_output_buffer(input_buffer.num_samples(), output);
+        self.render(input_buffer, hrtf, &output_buffer)?;
+        Ok(output_buffer)
+    }
+}
+
+/// Resizes `output` to hold two channels of `num_samples` samples, and returns a stereo audio
+/// buffer over it.
+pub(crate) fn stereo_output_buffer(
+    num_samples: u32,
+    output: &mut Vec<Sample>,
+) -> AudioBuffer<&mut [Sample]> {
+    output.clear();
+    output.resize(2 * num_samples as usize, 0.0);
+    AudioBuffer::try_with_data_and_settings(
+        output.as_mut_slice(),
+        AudioBufferSettings::with_num_channels(2),
+    )
+    .expect("output holds two channels of at least one sample")
+}
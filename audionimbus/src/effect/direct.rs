@@ -96,11 +96,14 @@ impl DirectEffect {
     ///
     /// The input and output audio buffers must have as many channels as specified when creating
     /// the effect.
+    /// Each channel is processed independently, with the same attenuation and filtering.
     ///
     /// # Errors
     ///
     /// Returns [`EffectError`] if the input or output buffers have a number of channels different
-    /// from that specified when creating the effect.
+    /// from that specified when creating the effect, or
+    /// [`EffectError::TransmissionWithoutOcclusion`] if
+    /// [`DirectEffectParams::transmission`] is set without [`DirectEffectParams::occlusion`].
     pub fn apply<I, O, PI: ChannelPointers, PO: ChannelPointers>(
         &mut self,
         direct_effect_params: &DirectEffectParams,
@@ -111,6 +114,8 @@ impl DirectEffect {
         I: AsRef<[Sample]>,
         O: AsRef<[Sample]> + AsMut<[Sample]>,
     {
+        profile_scope!("audionimbus::DirectEffect::apply");
+
         let num_input_channels = input_buffer.num_channels();
         if num_input_channels != self.num_channels {
             return Err(EffectError::InvalidInputChannels {
@@ -127,6 +132,10 @@ impl DirectEffect {
             });
         }
 
+        if direct_effect_params.transmission.is_some() && direct_effect_params.occlusion.is_none() {
+            return Err(EffectError::TransmissionWithoutOcclusion);
+        }
+
         let state = unsafe {
             audionimbus_sys::iplDirectEffectApply(
                 self.raw_ptr(),
@@ -195,7 +204,11 @@ impl DirectEffect {
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLDirectEffect {
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLDirectEffect {
         &mut self.inner
     }
 }
@@ -240,6 +253,9 @@ impl Hash for DirectEffect {
 #[derive(Debug)]
 pub struct DirectEffectSettings {
     /// Number of channels that will be used by input and output buffers.
+    ///
+    /// Input and output buffers must have the same number of channels, since the effect does not
+    /// mix channels: the same attenuation and filtering are applied to each of them.
     pub num_channels: u32,
 }
 
@@ -267,6 +283,9 @@ pub struct DirectEffectParams {
     pub occlusion: Option<f32>,
 
     /// Optional transmission.
+    ///
+    /// Requires [`Self::occlusion`] to be set: applying a direct effect with transmission but no
+    /// occlusion returns [`EffectError::TransmissionWithoutOcclusion`].
     pub transmission: Option<Transmission>,
 }
 
@@ -358,13 +377,192 @@ impl DirectEffectParams {
     }
 }
 
+/// Smooths the parameters of a direct effect at audio rate.
+///
+/// Simulations are typically run far less often than audio frames are processed, especially with
+/// occlusion enabled.
+/// Applying each new simulation result as-is makes the attenuation jump between frames, which
+/// causes audible zipper noise.
+///
+/// `SmoothedDirectParams` holds the latest simulation result as a target, and moves the
+/// parameters towards it every frame, following an exponential curve with a configurable time
+/// constant.
+/// After one time constant, about 63% of the way to the target has been covered; after five, more
+/// than 99%.
+///
+/// Distance attenuation, air absorption, directivity, occlusion and transmission are all smoothed.
+/// A parameter that is disabled in the target (`None`) is disabled immediately, and a parameter
+/// that gets enabled (or a transmission that changes type) jumps to the target value.
+///
+/// # Examples
+///
+/// ```
+/// # use audionimbus::*;
+/// let audio_settings = AudioSettings::default();
+/// let mut smoothed_params = SmoothedDirectParams::new(0.05);
+///
+/// // Whenever the simulation produces new results.
+/// smoothed_params.set_target(DirectEffectParams {
+///     occlusion: Some(1.0),
+///     ..Default::default()
+/// });
+///
+/// // The first frame starts at the target.
+/// assert_eq!(smoothed_params.advance(&audio_settings).occlusion, Some(1.0));
+///
+/// // The source becomes occluded.
+/// smoothed_params.set_target(DirectEffectParams {
+///     occlusion: Some(0.0),
+///     ..Default::default()
+/// });
+///
+/// // In the audio callback, once per frame.
+/// let params = smoothed_params.advance(&audio_settings);
+/// let occlusion = params.occlusion.unwrap();
+/// assert!(0.0 < occlusion && occlusion < 1.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SmoothedDirectParams {
+    /// Time constant, in seconds.
+    time_constant: f32,
+
+    /// Latest simulation result.
+    target: DirectEffectParams,
+
+    /// Parameters of the last frame, or `None` if no frame has been processed since the last
+    /// reset.
+    current: Option<DirectEffectParams>,
+}
+
+impl SmoothedDirectParams {
+    /// Creates a new smoother with the given time constant, in seconds.
+    ///
+    /// A time constant of 0.0 (or less) disables smoothing.
+    pub fn new(time_constant: f32) -> Self {
+        Self {
+            time_constant,
+            target: DirectEffectParams::default(),
+            current: None,
+        }
+    }
+
+    /// Returns the time constant, in seconds.
+    pub fn time_constant(&self) -> f32 {
+        self.time_constant
+    }
+
+    /// Sets the time constant, in seconds.
+    pub fn set_time_constant(&mut self, time_constant: f32) {
+        self.time_constant = time_constant;
+    }
+
+    /// Returns the parameters being smoothed towards.
+    pub fn target(&self) -> &DirectEffectParams {
+        &self.target
+    }
+
+    /// Sets the parameters to smooth towards, typically the latest simulation result.
+    pub fn set_target(&mut self, target: DirectEffectParams) {
+        self.target = target;
+    }
+
+    /// Returns the parameters of the last frame, if any.
+    pub fn current(&self) -> Option<&DirectEffectParams> {
+        self.current.as_ref()
+    }
+
+    /// Makes the next frame jump to the target, e.g. when the source is teleported or starts
+    /// playing.
+    pub fn reset(&mut self) {
+        self.current = None;
+    }
+
+    /// Moves the parameters towards the target by one frame, and returns them.
+    ///
+    /// This must be called once per audio frame, before applying the direct effect.
+    /// The duration of a frame is derived from `audio_settings`.
+    /// The first frame after creation or [`Self::reset`] starts at the target.
+    pub fn advance(&mut self, audio_settings: &AudioSettings) -> &DirectEffectParams {
+        let frame_duration = audio_settings.frame_size as f32 / audio_settings.sampling_rate as f32;
+        let alpha = if self.time_constant > 0.0 {
+            1.0 - (-frame_duration / self.time_constant).exp()
+        } else {
+            1.0
+        };
+
+        let current = match self.current.take() {
+            Some(current) => Self::smooth(&current, &self.target, alpha),
+            None => self.target.clone(),
+        };
+
+        self.current.insert(current)
+    }
+
+    fn smooth(
+        current: &DirectEffectParams,
+        target: &DirectEffectParams,
+        alpha: f32,
+    ) -> DirectEffectParams {
+        let value = |current: f32, target: f32| current + (target - current) * alpha;
+
+        let scalar = |current: Option<f32>, target: Option<f32>| match (current, target) {
+            (Some(current), Some(target)) => Some(value(current, target)),
+            (_, target) => target,
+        };
+
+        let equalizer = |current: &Equalizer<3>, target: &Equalizer<3>| {
+            Equalizer(std::array::from_fn(|band| {
+                value(current[band], target[band])
+            }))
+        };
+
+        let air_absorption = match (&current.air_absorption, &target.air_absorption) {
+            (Some(current), Some(target)) => Some(equalizer(current, target)),
+            (_, target) => *target,
+        };
+
+        let transmission = match (&current.transmission, &target.transmission) {
+            (
+                Some(Transmission::FrequencyIndependent(current)),
+                Some(Transmission::FrequencyIndependent(target)),
+            ) => Some(Transmission::FrequencyIndependent(equalizer(
+                current, target,
+            ))),
+            (
+                Some(Transmission::FrequencyDependent(current)),
+                Some(Transmission::FrequencyDependent(target)),
+            ) => Some(Transmission::FrequencyDependent(equalizer(current, target))),
+            (_, target) => *target,
+        };
+
+        DirectEffectParams {
+            distance_attenuation: scalar(current.distance_attenuation, target.distance_attenuation),
+            air_absorption,
+            directivity: scalar(current.directivity, target.directivity),
+            occlusion: scalar(current.occlusion, target.occlusion),
+            transmission,
+        }
+    }
+}
+
 /// Transmission parameters.
+///
+/// Both variants hold the 3-band transmission coefficients, with values between 0.0 and 1.0, as
+/// obtained from a direct simulation; they only differ in how the coefficients are applied.
+///
+/// Transmission only affects occluded sound, so it requires [`DirectEffectParams::occlusion`] to
+/// be set.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Transmission {
     /// Frequency-independent transmission.
+    ///
+    /// Transmission is modeled as a single attenuation factor applied to all frequencies.
     FrequencyIndependent(Equalizer<3>),
 
     /// Frequency-dependent transmission.
+    ///
+    /// Transmission is modeled as a 3-band EQ, so that, for example, low frequencies can pass
+    /// through walls more easily than high frequencies.
     FrequencyDependent(Equalizer<3>),
 }
 
@@ -413,6 +611,40 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_transmission_without_occlusion() {
+            let input_container = vec![0.5; 1024];
+            let input_buffer = AudioBuffer::try_with_data(&input_container).unwrap();
+
+            let mut output_container = vec![0.0; input_buffer.num_samples() as usize];
+            let output_buffer = AudioBuffer::try_with_data(&mut output_container).unwrap();
+
+            let context = Context::default();
+
+            let audio_settings = AudioSettings {
+                frame_size: input_buffer.num_samples(),
+                ..Default::default()
+            };
+
+            let direct_effect_settings = DirectEffectSettings { num_channels: 1 };
+
+            let mut direct_effect =
+                DirectEffect::try_new(&context, &audio_settings, &direct_effect_settings).unwrap();
+
+            let direct_effect_params = DirectEffectParams {
+                distance_attenuation: None,
+                air_absorption: None,
+                directivity: None,
+                occlusion: None,
+                transmission: Some(Transmission::FrequencyDependent(Equalizer([0.3, 0.2, 0.1]))),
+            };
+
+            assert_eq!(
+                direct_effect.apply(&direct_effect_params, &input_buffer, &output_buffer),
+                Err(EffectError::TransmissionWithoutOcclusion)
+            );
+        }
+
         #[test]
         fn test_invalid_input_num_channels() {
             const FRAME_SIZE: usize = 1024;
@@ -554,6 +786,97 @@ mod tests {
         }
     }
 
+    mod smoothed_direct_params {
+        use super::*;
+
+        fn occluded(occlusion: f32) -> DirectEffectParams {
+            DirectEffectParams {
+                occlusion: Some(occlusion),
+                transmission: Some(Transmission::FrequencyDependent(Equalizer([occlusion; 3]))),
+                ..Default::default()
+            }
+        }
+
+        #[test]
+        fn test_first_frame_starts_at_target() {
+            let mut smoothed = SmoothedDirectParams::new(0.1);
+            smoothed.set_target(occluded(0.5));
+            assert_eq!(smoothed.advance(&AudioSettings::default()), &occluded(0.5));
+        }
+
+        #[test]
+        fn test_converges_monotonically() {
+            let audio_settings = AudioSettings::default();
+            let mut smoothed = SmoothedDirectParams::new(0.1);
+            smoothed.set_target(occluded(1.0));
+            smoothed.advance(&audio_settings);
+
+            smoothed.set_target(occluded(0.0));
+            let mut previous = 1.0;
+            for _ in 0..10 {
+                let occlusion = smoothed.advance(&audio_settings).occlusion.unwrap();
+                assert!(occlusion < previous && occlusion > 0.0);
+                previous = occlusion;
+            }
+
+            // One time constant is ~4.7 frames of 1024 samples at 48 kHz.
+            for _ in 0..100 {
+                smoothed.advance(&audio_settings);
+            }
+            let params = smoothed.current().unwrap();
+            assert!(params.occlusion.unwrap() < 1e-6);
+            let Some(Transmission::FrequencyDependent(transmission)) = params.transmission else {
+                panic!("unexpected transmission: {:?}", params.transmission);
+            };
+            assert!(transmission.iter().all(|&band| band < 1e-6));
+        }
+
+        #[test]
+        fn test_no_smoothing() {
+            let audio_settings = AudioSettings::default();
+            let mut smoothed = SmoothedDirectParams::new(0.0);
+            smoothed.set_target(occluded(1.0));
+            smoothed.advance(&audio_settings);
+
+            smoothed.set_target(occluded(0.0));
+            assert_eq!(smoothed.advance(&audio_settings), &occluded(0.0));
+        }
+
+        #[test]
+        fn test_toggled_parameters_jump() {
+            let audio_settings = AudioSettings::default();
+            let mut smoothed = SmoothedDirectParams::new(0.1);
+            smoothed.set_target(occluded(1.0));
+            smoothed.advance(&audio_settings);
+
+            smoothed.set_target(DirectEffectParams {
+                distance_attenuation: Some(0.5),
+                transmission: Some(Transmission::FrequencyIndependent(Equalizer([0.5; 3]))),
+                ..Default::default()
+            });
+            let params = smoothed.advance(&audio_settings);
+            assert_eq!(params.distance_attenuation, Some(0.5));
+            assert_eq!(params.occlusion, None);
+            assert_eq!(
+                params.transmission,
+                Some(Transmission::FrequencyIndependent(Equalizer([0.5; 3])))
+            );
+        }
+
+        #[test]
+        fn test_reset() {
+            let audio_settings = AudioSettings::default();
+            let mut smoothed = SmoothedDirectParams::new(0.1);
+            smoothed.set_target(occluded(1.0));
+            smoothed.advance(&audio_settings);
+
+            smoothed.set_target(occluded(0.0));
+            smoothed.reset();
+            assert_eq!(smoothed.current(), None);
+            assert_eq!(smoothed.advance(&audio_settings), &occluded(0.0));
+        }
+    }
+
     mod clone {
         use super::*;
 
@@ -2,6 +2,34 @@
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Equalizer<const N: usize>(pub [f32; N]);
 
+impl<const N: usize> Equalizer<N> {
+    /// Creates an equalizer from band gains in decibels.
+    ///
+    /// Gains are converted to linear coefficients with `10^(dB / 20)`: 0 dB is a coefficient of
+    /// 1.0 (unaffected), -6 dB about 0.5, and `f32::NEG_INFINITY` 0.0 (silence).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let transmission = Equalizer::from_db(&[-6.0, -12.0, -24.0]);
+    /// assert!((transmission[0] - 0.501).abs() < 1e-3);
+    /// assert!((transmission[1] - 0.251).abs() < 1e-3);
+    /// assert!((transmission[2] - 0.063).abs() < 1e-3);
+    /// ```
+    pub fn from_db(gains_db: &[f32; N]) -> Self {
+        Self(gains_db.map(|gain_db| 10f32.powf(gain_db / 20.0)))
+    }
+
+    /// Returns the band gains in decibels.
+    ///
+    /// This is the inverse of [`Self::from_db`].
+    /// A coefficient of 0.0 yields `f32::NEG_INFINITY`.
+    pub fn to_db(&self) -> [f32; N] {
+        self.0.map(|coefficient| 20.0 * coefficient.log10())
+    }
+}
+
 impl<const N: usize> Default for Equalizer<N> {
     fn default() -> Self {
         Self([0.0; N])
@@ -21,3 +49,38 @@ impl<const N: usize> std::ops::DerefMut for Equalizer<N> {
         &mut self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod from_db {
+        use super::*;
+
+        #[test]
+        fn test_unity_and_silence() {
+            assert_eq!(
+                Equalizer::from_db(&[0.0, f32::NEG_INFINITY]),
+                Equalizer([1.0, 0.0])
+            );
+        }
+
+        #[test]
+        fn test_round_trip() {
+            let gains_db = [-6.0, -12.0, -24.0];
+            let round_trip = Equalizer::from_db(&gains_db).to_db();
+            for (gain_db, expected) in round_trip.into_iter().zip(gains_db) {
+                assert!((gain_db - expected).abs() < 1e-4);
+            }
+        }
+    }
+
+    mod to_db {
+        use super::*;
+
+        #[test]
+        fn test_silence() {
+            assert_eq!(Equalizer([0.0]).to_db(), [f32::NEG_INFINITY]);
+        }
+    }
+}
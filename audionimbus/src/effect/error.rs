@@ -14,6 +14,16 @@ pub enum EffectError {
         expected: ChannelRequirement,
         actual: u32,
     },
+
+    /// The HRTF passed when applying an effect is not the one the effect was created with.
+    HrtfMismatch,
+
+    /// Transmission was requested without occlusion, which Steam Audio requires to apply it.
+    TransmissionWithoutOcclusion,
+
+    /// The Ambisonic order passed when applying an effect exceeds the maximum set during effect
+    /// creation.
+    AmbisonicsOrderExceedsMax { requested: u32, max: u32 },
 }
 
 impl std::error::Error for EffectError {}
@@ -33,6 +43,21 @@ impl std::fmt::Display for EffectError {
                     "invalid number of output channels: expected {expected}, got {actual}",
                 )
             }
+            Self::HrtfMismatch => {
+                write!(
+                    f,
+                    "the HRTF does not match the one the effect was created with"
+                )
+            }
+            Self::TransmissionWithoutOcclusion => {
+                write!(f, "transmission cannot be applied without occlusion")
+            }
+            Self::AmbisonicsOrderExceedsMax { requested, max } => {
+                write!(
+                    f,
+                    "requested Ambisonic order {requested}, but maximum is {max} (set during effect creation)",
+                )
+            }
         }
     }
 }
@@ -21,6 +21,9 @@
 //! - [`AmbisonicsBinauralEffect`] - Decode Ambisonics using HRTF rendering
 //! - [`AmbisonicsRotationEffect`] - Rotate Ambisonics to listener's orientation
 //!
+//! [`VirtualSurroundEffect`] and [`AmbisonicsDecodeEffect`] both implement [`BinauralRenderer`],
+//! so either can be used to render a mix to headphones.
+//!
 //! # Typical Usage
 //!
 //! ```
@@ -38,7 +41,7 @@
 //!
 //! let params = BinauralEffectParams {
 //!     direction: Direction::new(1.0, 0.0, 0.0), // Sound from the right
-//!     interpolation: HrtfInterpolation::Nearest,
+//!     interpolation: HrtfInterpolation::Bilinear,
 //!     spatial_blend: 1.0,
 //!     hrtf,
 //!     peak_delays: None,
@@ -61,6 +64,9 @@ pub use ambisonics::*;
 pub mod binaural;
 pub use binaural::*;
 
+mod binaural_renderer;
+pub use binaural_renderer::BinauralRenderer;
+
 pub mod direct;
 pub use direct::*;
 
@@ -87,14 +87,7 @@ impl PanningEffect {
             return Err(error);
         }
 
-        let num_output_channels = match &panning_effect_settings.speaker_layout {
-            SpeakerLayout::Mono => 1,
-            SpeakerLayout::Stereo => 2,
-            SpeakerLayout::Quadraphonic => 4,
-            SpeakerLayout::Surround5_1 => 6,
-            SpeakerLayout::Surround7_1 => 8,
-            SpeakerLayout::Custom { speaker_directions } => speaker_directions.len() as u32,
-        };
+        let num_output_channels = panning_effect_settings.speaker_layout.num_channels() as u32;
 
         Ok(Self {
             inner,
@@ -125,6 +118,8 @@ impl PanningEffect {
         I: AsRef<[Sample]>,
         O: AsRef<[Sample]> + AsMut<[Sample]>,
     {
+        profile_scope!("audionimbus::PanningEffect::apply");
+
         let num_input_channels = input_buffer.num_channels();
         if num_input_channels != 1 {
             return Err(EffectError::InvalidInputChannels {
@@ -209,7 +204,11 @@ impl PanningEffect {
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLPanningEffect {
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLPanningEffect {
         &mut self.inner
     }
 }
@@ -7,7 +7,7 @@ use crate::audio_settings::AudioSettings;
 use crate::context::Context;
 use crate::error::{SteamAudioError, to_option_error};
 use crate::ffi_wrapper::FFIWrapper;
-use crate::geometry::CoordinateSystem;
+use crate::geometry::{CoordinateSystem, Direction};
 use crate::hrtf::Hrtf;
 use crate::num_ambisonics_channels;
 use crate::{ChannelPointers, ChannelRequirement};
@@ -191,6 +191,14 @@ pub struct PathEffect {
     /// Number of output channels needed for the ambisonics order specified when creating the
     /// effect.
     num_output_channels: u32,
+
+    /// If spatialization is enabled, the number of channels needed for the speaker layout
+    /// specified when creating the effect.
+    num_speaker_channels: Option<u32>,
+
+    /// Whether the last call to [`Self::apply`] rendered binaural audio, which determines the
+    /// number of channels [`Self::tail`] renders when spatialization is enabled.
+    binaural: bool,
 }
 
 impl PathEffect {
@@ -238,9 +246,16 @@ impl PathEffect {
             return Err(error);
         }
 
+        let num_speaker_channels = path_effect_settings
+            .spatialization
+            .as_ref()
+            .map(|spatialization| spatialization.speaker_layout.num_channels() as u32);
+
         let path_effect = Self {
             inner,
             num_output_channels: num_ambisonics_channels(path_effect_settings.max_order),
+            num_speaker_channels,
+            binaural: false,
         };
 
         Ok(path_effect)
@@ -250,16 +265,19 @@ impl PathEffect {
     ///
     /// This effect CANNOT be applied in-place.
     ///
-    /// The input audio buffer must have one channel, and the output audio buffer must have as many
-    /// channels as needed for the ambisonics order specified when creating the effect (see
-    /// [`num_ambisonics_channels`]).
+    /// The input audio buffer must have one channel.
+    /// The output audio buffer must have:
+    /// - As many channels as needed for the ambisonics order specified when creating the effect
+    ///   (see [`num_ambisonics_channels`]) if [`PathEffectSettings::spatialization`] is `None`
+    /// - 2 channels if spatialization is enabled and [`PathEffectParams::binaural`] is `true`
+    /// - As many channels as needed for the speaker layout if spatialization is enabled and
+    ///   [`PathEffectParams::binaural`] is `false`
     ///
     /// # Errors
     ///
     /// Returns [`EffectError`] if:
     /// - The input buffer has more than one channel
-    /// - The output buffer has a number of channels different from that needed for the ambisonics
-    ///   order specified when creating the effect
+    /// - The output buffer does not have the number of channels required by the rendering mode
     pub fn apply<I, O, PI: ChannelPointers, PO: ChannelPointers>(
         &mut self,
         path_effect_params: &PathEffectParams,
@@ -270,6 +288,8 @@ impl PathEffect {
         I: AsRef<[Sample]>,
         O: AsRef<[Sample]> + AsMut<[Sample]>,
     {
+        profile_scope!("audionimbus::PathEffect::apply");
+
         let num_input_channels = input_buffer.num_channels();
         if num_input_channels != 1 {
             return Err(EffectError::InvalidInputChannels {
@@ -278,10 +298,12 @@ impl PathEffect {
             });
         }
 
+        let expected_num_output_channels =
+            self.expected_num_output_channels(path_effect_params.binaural);
         let num_output_channels = output_buffer.num_channels();
-        if num_output_channels != self.num_output_channels {
+        if num_output_channels != expected_num_output_channels {
             return Err(EffectError::InvalidOutputChannels {
-                expected: ChannelRequirement::Exactly(self.num_output_channels),
+                expected: ChannelRequirement::Exactly(expected_num_output_channels),
                 actual: num_output_channels,
             });
         }
@@ -296,27 +318,41 @@ impl PathEffect {
         }
         .into();
 
+        self.binaural = path_effect_params.binaural;
+
         Ok(state)
     }
 
+    /// Returns the number of output channels required to render the effect, depending on whether
+    /// it renders binaural audio.
+    fn expected_num_output_channels(&self, binaural: bool) -> u32 {
+        match self.num_speaker_channels {
+            None => self.num_output_channels,
+            Some(_) if binaural => 2,
+            Some(num_speaker_channels) => num_speaker_channels,
+        }
+    }
+
     /// Retrieves a single frame of tail samples from a path effect’s internal buffers.
     ///
     /// After the input to the path effect has stopped, this function must be called instead of [`Self::apply`] until the return value indicates that no more tail samples remain.
     ///
-    /// The output audio buffer must have as many channels as needed for the Ambisonics order specified when creating the effect.
+    /// The output audio buffer must have as many channels as [`Self::apply`] requires, using the
+    /// [`PathEffectParams::binaural`] value of the last call to [`Self::apply`].
     ///
     /// # Errors
     ///
-    /// Returns [`EffectError`] if the output buffer has a number of channels different from that
-    /// needed for the ambisonics order specified when creating the effect.
+    /// Returns [`EffectError`] if the output buffer does not have the number of channels required
+    /// by the rendering mode.
     pub fn tail<O>(&self, output_buffer: &AudioBuffer<O>) -> Result<AudioEffectState, EffectError>
     where
         O: AsRef<[Sample]> + AsMut<[Sample]>,
     {
+        let expected_num_output_channels = self.expected_num_output_channels(self.binaural);
         let num_output_channels = output_buffer.num_channels();
-        if num_output_channels != self.num_output_channels {
+        if num_output_channels != expected_num_output_channels {
             return Err(EffectError::InvalidOutputChannels {
-                expected: ChannelRequirement::Exactly(self.num_output_channels),
+                expected: ChannelRequirement::Exactly(expected_num_output_channels),
                 actual: num_output_channels,
             });
         }
@@ -351,7 +387,11 @@ impl PathEffect {
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLPathEffect {
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLPathEffect {
         &mut self.inner
     }
 }
@@ -374,6 +414,8 @@ impl Clone for PathEffect {
         Self {
             inner: unsafe { audionimbus_sys::iplPathEffectRetain(self.inner) },
             num_output_channels: self.num_output_channels,
+            num_speaker_channels: self.num_speaker_channels,
+            binaural: self.binaural,
         }
     }
 }
@@ -470,6 +512,84 @@ impl From<audionimbus_sys::IPLPathEffectParams> for PathEffectParams {
 }
 
 impl PathEffectParams {
+    /// Creates parameters for rendering un-spatialized Ambisonic audio of the given order.
+    ///
+    /// Use this with a [`PathEffect`] created without [`PathEffectSettings::spatialization`].
+    /// The output buffer must then have [`num_ambisonics_channels`] channels for `order`.
+    ///
+    /// [`Self::eq_coeffs`] are set to `1.0` and [`Self::sh_coeffs`] are zero-initialized; they are
+    /// typically overwritten with the output of a pathing simulation.
+    pub fn ambisonics(order: u32) -> Self {
+        Self {
+            eq_coeffs: [1.0, 1.0, 1.0],
+            sh_coeffs: vec![0.0; num_ambisonics_channels(order) as usize],
+            order,
+            binaural: false,
+            hrtf: Hrtf::from(std::ptr::null_mut()),
+            listener: CoordinateSystem::default(),
+            normalize_eq: false,
+        }
+    }
+
+    /// Creates parameters for rendering paths binaurally using the given HRTF and listener.
+    ///
+    /// Use this with a [`PathEffect`] created with [`PathEffectSettings::spatialization`] set to
+    /// `Some`.
+    /// The output buffer must then have 2 channels.
+    ///
+    /// [`Self::eq_coeffs`] are set to `1.0` and [`Self::sh_coeffs`] are zero-initialized; they are
+    /// typically overwritten with the output of a pathing simulation.
+    pub fn binaural(order: u32, hrtf: Hrtf, listener: CoordinateSystem) -> Self {
+        Self {
+            binaural: true,
+            hrtf,
+            listener,
+            ..Self::ambisonics(order)
+        }
+    }
+
+    /// Returns the world-space direction from which most of the sound reaches the listener, or
+    /// `None` if it cannot be determined.
+    ///
+    /// This is the direction of the first-order component of [`Self::sh_coeffs`], which points
+    /// around corners along the paths found by pathing simulation, rather than straight at the
+    /// source.
+    /// It is typically used to show where a sound is perceived to come from, for example with a
+    /// UI arrow; [`relative_direction`](crate::geometry::relative_direction) or the listener's
+    /// axes can then be used to express it relative to the listener.
+    ///
+    /// `None` is returned if [`Self::order`] is 0, or if the sound field has no directional
+    /// component, for example when no path was found.
+    ///
+    /// The frequency-dependent attenuation along the paths is available in [`Self::eq_coeffs`].
+    pub fn arrival_direction(&self) -> Option<Direction> {
+        if self.order == 0 {
+            return None;
+        }
+        let &[_, y, z, x, ..] = self.sh_coeffs.as_slice() else {
+            return None;
+        };
+
+        // First-order coefficients follow ACN ordering (Y, Z, X), in the Ambisonics coordinate
+        // system (+x ahead, +y left, +z up), whereas Steam Audio has +x right, +y up, -z ahead.
+        let direction = Direction::new(-y, z, -x);
+        if direction == Direction::default() {
+            return None;
+        }
+
+        Some(direction.normalize())
+    }
+
+    /// Switches these parameters to binaural rendering using the given HRTF and listener.
+    ///
+    /// Useful to spatialize the parameters returned by [`Source::get_pathing_outputs`].
+    pub fn with_binaural(mut self, hrtf: Hrtf, listener: CoordinateSystem) -> Self {
+        self.binaural = true;
+        self.hrtf = hrtf;
+        self.listener = listener;
+        self
+    }
+
     pub(crate) fn as_ffi(&self) -> FFIWrapper<'_, audionimbus_sys::IPLPathEffectParams, Self> {
         let path_effect_params = audionimbus_sys::IPLPathEffectParams {
             eqCoeffs: self.eq_coeffs,
@@ -648,6 +768,159 @@ mod tests {
                 })
             );
         }
+
+        #[test]
+        fn test_valid_binaural() {
+            let context = Context::default();
+
+            const FRAME_SIZE: u32 = 1024;
+            const MAX_ORDER: u32 = 1;
+
+            let audio_settings = AudioSettings::default();
+            let hrtf_settings = HrtfSettings::default();
+            let hrtf = Hrtf::try_new(&context, &audio_settings, &hrtf_settings).unwrap();
+
+            let path_effect_settings = PathEffectSettings {
+                max_order: MAX_ORDER,
+                spatialization: Some(Spatialization {
+                    speaker_layout: SpeakerLayout::Surround5_1,
+                    hrtf: hrtf.clone(),
+                }),
+            };
+            let mut path_effect =
+                PathEffect::try_new(&context, &audio_settings, &path_effect_settings).unwrap();
+
+            let input_container = vec![0.5; FRAME_SIZE as usize];
+            let input_buffer = AudioBuffer::try_with_data(&input_container).unwrap();
+
+            let mut output_container = vec![0.0; 2 * input_buffer.num_samples() as usize];
+            let output_buffer = AudioBuffer::try_with_data_and_settings(
+                &mut output_container,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+
+            let mut path_effect_params =
+                PathEffectParams::binaural(MAX_ORDER, hrtf, CoordinateSystem::default());
+            path_effect_params.sh_coeffs[0] = 1.0;
+
+            assert!(
+                path_effect
+                    .apply(&path_effect_params, &input_buffer, &output_buffer)
+                    .is_ok()
+            );
+        }
+
+        #[test]
+        fn test_invalid_output_num_channels_speaker_layout() {
+            let context = Context::default();
+
+            const FRAME_SIZE: u32 = 1024;
+            const MAX_ORDER: u32 = 1;
+
+            let audio_settings = AudioSettings::default();
+            let hrtf_settings = HrtfSettings::default();
+            let hrtf = Hrtf::try_new(&context, &audio_settings, &hrtf_settings).unwrap();
+
+            let path_effect_settings = PathEffectSettings {
+                max_order: MAX_ORDER,
+                spatialization: Some(Spatialization {
+                    speaker_layout: SpeakerLayout::Surround5_1,
+                    hrtf,
+                }),
+            };
+            let mut path_effect =
+                PathEffect::try_new(&context, &audio_settings, &path_effect_settings).unwrap();
+
+            let input_container = vec![0.5; FRAME_SIZE as usize];
+            let input_buffer = AudioBuffer::try_with_data(&input_container).unwrap();
+
+            // Ambisonics output is not valid once spatialization is enabled.
+            let mut output_container = vec![0.0; 4 * input_buffer.num_samples() as usize];
+            let output_buffer = AudioBuffer::try_with_data_and_settings(
+                &mut output_container,
+                AudioBufferSettings::with_num_channels(4),
+            )
+            .unwrap();
+
+            let path_effect_params = PathEffectParams::ambisonics(MAX_ORDER);
+
+            assert_eq!(
+                path_effect.apply(&path_effect_params, &input_buffer, &output_buffer),
+                Err(EffectError::InvalidOutputChannels {
+                    expected: ChannelRequirement::Exactly(6),
+                    actual: 4
+                })
+            );
+        }
+    }
+
+    mod arrival_direction {
+        use super::*;
+
+        fn encode(direction: Direction) -> PathEffectParams {
+            let context = Context::default();
+            let audio_settings = AudioSettings::default();
+            let mut encode_effect = AmbisonicsEncodeEffect::try_new(
+                &context,
+                &audio_settings,
+                &AmbisonicsEncodeEffectSettings { max_order: 1 },
+            )
+            .unwrap();
+
+            let input_container = vec![1.0; audio_settings.frame_size as usize];
+            let input_buffer = AudioBuffer::try_with_data(&input_container).unwrap();
+            let mut output_container = vec![0.0; 4 * audio_settings.frame_size as usize];
+            let output_buffer = AudioBuffer::try_with_data_and_settings(
+                &mut output_container,
+                AudioBufferSettings::with_num_channels(4),
+            )
+            .unwrap();
+
+            let params = AmbisonicsEncodeEffectParams {
+                direction,
+                order: 1,
+            };
+            // The second frame is past any interpolation from the initial state.
+            for _ in 0..2 {
+                encode_effect
+                    .apply(&params, &input_buffer, &output_buffer)
+                    .unwrap();
+            }
+
+            let mut path_effect_params = PathEffectParams::ambisonics(1);
+            path_effect_params.sh_coeffs = output_buffer
+                .channels()
+                .map(|channel| *channel.last().unwrap())
+                .collect();
+            path_effect_params
+        }
+
+        #[test]
+        fn test_matches_encoded_direction() {
+            for direction in [
+                Direction::new(1.0, 0.0, 0.0),
+                Direction::new(-1.0, 0.0, 0.0),
+                Direction::new(0.0, 1.0, 0.0),
+                Direction::new(0.0, 0.0, -1.0),
+                Direction::new(0.6, 0.0, 0.8),
+            ] {
+                let arrival_direction = encode(direction).arrival_direction().unwrap();
+                assert!(
+                    (arrival_direction - direction).length() < 1e-4,
+                    "{arrival_direction:?} != {direction:?}"
+                );
+            }
+        }
+
+        #[test]
+        fn test_omnidirectional() {
+            let mut path_effect_params = PathEffectParams::ambisonics(1);
+            path_effect_params.sh_coeffs[0] = 1.0;
+            assert_eq!(path_effect_params.arrival_direction(), None);
+
+            assert_eq!(PathEffectParams::ambisonics(0).arrival_direction(), None);
+        }
     }
 
     mod tail {
@@ -710,6 +983,60 @@ mod tests {
                 })
             );
         }
+
+        #[test]
+        fn test_spatialized() {
+            let context = Context::default();
+
+            const FRAME_SIZE: usize = 1024;
+            const MAX_ORDER: u32 = 1;
+
+            let audio_settings = AudioSettings::default();
+            let hrtf = Hrtf::try_new(&context, &audio_settings, &HrtfSettings::default()).unwrap();
+            let path_effect_settings = PathEffectSettings {
+                max_order: MAX_ORDER,
+                spatialization: Some(Spatialization {
+                    speaker_layout: SpeakerLayout::Surround5_1,
+                    hrtf: hrtf.clone(),
+                }),
+            };
+            let mut path_effect =
+                PathEffect::try_new(&context, &audio_settings, &path_effect_settings).unwrap();
+
+            // Before any binaural rendering, the tail uses the speaker layout.
+            let mut speaker_container = vec![0.0; 6 * FRAME_SIZE];
+            let speaker_buffer = AudioBuffer::try_with_data_and_settings(
+                &mut speaker_container,
+                AudioBufferSettings::with_num_channels(6),
+            )
+            .unwrap();
+            assert!(path_effect.tail(&speaker_buffer).is_ok());
+
+            let input_container = vec![0.5; FRAME_SIZE];
+            let input_buffer = AudioBuffer::try_with_data(&input_container).unwrap();
+            let mut output_container = vec![0.0; 2 * FRAME_SIZE];
+            let output_buffer = AudioBuffer::try_with_data_and_settings(
+                &mut output_container,
+                AudioBufferSettings::with_num_channels(2),
+            )
+            .unwrap();
+            let path_effect_params =
+                PathEffectParams::binaural(MAX_ORDER, hrtf, CoordinateSystem::default());
+            assert!(
+                path_effect
+                    .apply(&path_effect_params, &input_buffer, &output_buffer)
+                    .is_ok()
+            );
+
+            assert!(path_effect.tail(&output_buffer).is_ok());
+            assert_eq!(
+                path_effect.tail(&speaker_buffer),
+                Err(EffectError::InvalidOutputChannels {
+                    expected: ChannelRequirement::Exactly(2),
+                    actual: 6
+                })
+            );
+        }
     }
 
     mod clone {
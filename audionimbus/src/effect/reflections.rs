@@ -5,19 +5,22 @@ use super::audio_effect_state::AudioEffectState;
 use super::equalizer::Equalizer;
 use super::error::{ImpulseResponseSizeExceedsMaxError, NumChannelsExceedsMaxError};
 use crate::Sealed;
-use crate::audio_buffer::{AudioBuffer, Sample};
+use crate::audio_buffer::{AudioBuffer, Sample, num_ambisonics_channels};
 use crate::audio_settings::AudioSettings;
 use crate::context::Context;
 use crate::device::true_audio_next::TrueAudioNextDevice;
 use crate::error::{SteamAudioError, to_option_error};
 use crate::ffi_wrapper::FFIWrapper;
+use crate::ray_tracing::RayTracer;
 use crate::simulation::{
-    ConvolutionParameters, HybridParameters, ParametricParameters, ReflectionsSimulationParameters,
-    TrueAudioNextParameters,
+    ConvolutionParameters, HybridParameters, ParametricParameters, Reflections,
+    ReflectionsSimulationParameters, SimulationSettings, TrueAudioNextParameters,
 };
 use crate::{ChannelPointers, ChannelRequirement};
+use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
+use std::sync::{Arc, Mutex, Weak};
 
 /// Multi-channel convolution reverb.
 ///
@@ -232,13 +235,11 @@ use crate::simulation::{SimulationOutputs, Simulator, Source};
 /// let params = source.get_reflections_outputs()?;
 ///
 /// const NUM_CHANNELS: u32 = num_ambisonics_channels(1); // 1st order ambisonics
+/// // Match the IR dimensions to the simulator's `max_order` and `max_duration`.
 /// let mut effect = ReflectionEffect::<Convolution>::try_new(
 ///     &context,
 ///     &audio_settings,
-///     &ReflectionEffectSettings {
-///         impulse_response_size: 2 * audio_settings.sampling_rate, // 2 seconds
-///         num_channels: NUM_CHANNELS,
-///     },
+///     &ReflectionEffectSettings::for_simulation(&simulation_settings),
 /// )?;
 ///
 /// let input = vec![0.5; audio_settings.frame_size as usize];
@@ -336,7 +337,8 @@ use crate::simulation::{SimulationOutputs, Simulator, Source};
 /// let _ = reverb_effect.apply(&reverb_params, &input_buffer, &output_buffer);
 ///
 /// // Mix with dry signal (e.g., 70% dry, 30% reverb)
-/// // Then decode the ambisonics output for final playback
+/// // Then decode the ambisonics output for final playback, e.g. to headphones with
+/// // `AmbisonicsDecodeEffect::decode_to_stereo`.
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 #[derive(Debug)]
@@ -409,7 +411,11 @@ impl<T: ReflectionEffectType> ReflectionEffect<T> {
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLReflectionEffect {
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLReflectionEffect {
         &mut self.inner
     }
 }
@@ -440,6 +446,8 @@ impl<T: ReflectionEffectType + CanApplyDirectly> ReflectionEffect<T> {
         I: AsRef<[Sample]>,
         O: AsRef<[Sample]> + AsMut<[Sample]>,
     {
+        profile_scope!("audionimbus::ReflectionEffect::apply");
+
         let num_input_channels = input_buffer.num_channels();
         if num_input_channels != 1 {
             return Err(EffectError::InvalidInputChannels {
@@ -543,6 +551,8 @@ impl<T: ReflectionEffectType + CanUseReflectionMixer> ReflectionEffect<T> {
         I: AsRef<[Sample]>,
         O: AsRef<[Sample]> + AsMut<[Sample]>,
     {
+        profile_scope!("audionimbus::ReflectionEffect::apply_into_mixer");
+
         let num_input_channels = input_buffer.num_channels();
         if num_input_channels != 1 {
             return Err(EffectError::InvalidInputChannels {
@@ -663,7 +673,7 @@ impl<T: ReflectionEffectType> Hash for ReflectionEffect<T> {
 }
 
 /// Settings used to create a reflection effect.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ReflectionEffectSettings {
     /// Number of samples per channel in the IR.
     pub impulse_response_size: u32,
@@ -672,6 +682,349 @@ pub struct ReflectionEffectSettings {
     pub num_channels: u32,
 }
 
+impl ReflectionEffectSettings {
+    /// Creates settings matching the impulse responses produced by a simulator.
+    ///
+    /// The number of channels is derived from the simulator's maximum Ambisonic order, and the
+    /// IR size from its maximum duration and sampling rate.
+    /// A reflection effect whose dimensions disagree with the simulator's would otherwise
+    /// silently produce garbled reflections.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let audio_settings = AudioSettings::default();
+    /// let simulation_settings = SimulationSettings::new(&audio_settings)
+    ///     .with_reflections(ConvolutionSettings {
+    ///         max_num_rays: 4096,
+    ///         num_diffuse_samples: 32,
+    ///         max_duration: 2.0,
+    ///         max_num_sources: 8,
+    ///         num_threads: 1,
+    ///         max_order: 1,
+    ///     });
+    ///
+    /// let settings = ReflectionEffectSettings::for_simulation(&simulation_settings);
+    /// assert_eq!(settings.num_channels, num_ambisonics_channels(1));
+    /// assert_eq!(settings.impulse_response_size, 2 * audio_settings.sampling_rate);
+    /// ```
+    pub fn for_simulation<T: RayTracer, D, P, RE>(
+        simulation_settings: &SimulationSettings<T, D, Reflections, P, RE>,
+    ) -> Self {
+        let ffi_settings = simulation_settings.to_ffi();
+        let sampling_rate = simulation_settings.audio_settings().sampling_rate;
+
+        Self {
+            impulse_response_size: (ffi_settings.maxDuration * sampling_rate as f32).ceil() as u32,
+            num_channels: num_ambisonics_channels(ffi_settings.maxOrder as u32),
+        }
+    }
+}
+
+/// A pool of reflection effects, keyed on the [`ReflectionEffectSettings`] they were created with.
+///
+/// Creating a reflection effect allocates buffers sized from its impulse response, which is
+/// costly when sources are spawned and despawned frequently.
+/// Instead, the pool hands out effects from [`Self::acquire`] wrapped in a
+/// [`PooledReflectionEffect`], which returns the effect to the pool when dropped.
+/// Effects are cleared with [`ReflectionEffect::reset`] before being handed out again, so no
+/// reverb tail leaks from one source to the next.
+/// At most [`Self::max_idle`] idle effects are kept per settings; effects returned beyond that
+/// are released.
+///
+/// Cloning the pool returns a handle to the same set of idle effects.
+///
+/// # Examples
+///
+/// ```
+/// # use audionimbus::*;
+/// let context = Context::default();
+/// let audio_settings = AudioSettings::default();
+/// let settings = ReflectionEffectSettings {
+///     impulse_response_size: 2 * audio_settings.sampling_rate,
+///     num_channels: 4,
+/// };
+///
+/// let pool = ReflectionEffectPool::<Convolution>::new(&context, &audio_settings);
+///
+/// let effect = pool.acquire(&settings)?;
+/// assert_eq!(pool.num_idle(&settings), 0);
+///
+/// drop(effect);
+/// assert_eq!(pool.num_idle(&settings), 1);
+///
+/// // Reuses the idle effect instead of creating a new one.
+/// let _effect = pool.acquire(&settings)?;
+/// assert_eq!(pool.num_idle(&settings), 0);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct ReflectionEffectPool<T: ReflectionEffectType> {
+    context: Context,
+    audio_settings: AudioSettings,
+    idle: Arc<Mutex<IdleReflectionEffects<T>>>,
+}
+
+#[derive(Debug)]
+struct IdleReflectionEffects<T: ReflectionEffectType> {
+    effects: HashMap<ReflectionEffectSettings, Vec<ReflectionEffect<T>>>,
+    max_idle: usize,
+}
+
+impl<T: ReflectionEffectType> ReflectionEffectPool<T> {
+    /// The default maximum number of idle effects kept per [`ReflectionEffectSettings`].
+    pub const DEFAULT_MAX_IDLE: usize = 16;
+
+    /// Creates an empty pool whose effects are created with the given context and audio settings.
+    ///
+    /// The pool keeps at most [`Self::DEFAULT_MAX_IDLE`] idle effects per settings; use
+    /// [`Self::set_max_idle`] to change it.
+    pub fn new(context: &Context, audio_settings: &AudioSettings) -> Self {
+        Self {
+            context: context.clone(),
+            audio_settings: *audio_settings,
+            idle: Arc::new(Mutex::new(IdleReflectionEffects {
+                effects: HashMap::new(),
+                max_idle: Self::DEFAULT_MAX_IDLE,
+            })),
+        }
+    }
+
+    /// Returns the maximum number of idle effects kept per [`ReflectionEffectSettings`].
+    pub fn max_idle(&self) -> usize {
+        self.idle.lock().unwrap().max_idle
+    }
+
+    /// Sets the maximum number of idle effects kept per [`ReflectionEffectSettings`].
+    ///
+    /// Idle effects in excess of the new maximum are released.
+    pub fn set_max_idle(&self, max_idle: usize) {
+        let mut idle = self.idle.lock().unwrap();
+        idle.max_idle = max_idle;
+        for effects in idle.effects.values_mut() {
+            effects.truncate(max_idle);
+        }
+    }
+
+    /// Hands out a reflection effect created with the given settings.
+    ///
+    /// An idle effect with matching settings is reset and reused if available; otherwise a new
+    /// effect is created.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SteamAudioError`] if a new effect needs to be created and creation fails.
+    pub fn acquire(
+        &self,
+        settings: &ReflectionEffectSettings,
+    ) -> Result<PooledReflectionEffect<T>, SteamAudioError> {
+        let idle_effect = self
+            .idle
+            .lock()
+            .unwrap()
+            .effects
+            .get_mut(settings)
+            .and_then(Vec::pop);
+
+        let effect = match idle_effect {
+            Some(mut effect) => {
+                effect.reset();
+                effect
+            }
+            None => ReflectionEffect::try_new(&self.context, &self.audio_settings, settings)?,
+        };
+
+        Ok(PooledReflectionEffect {
+            effect: Some(effect),
+            settings: *settings,
+            pool: Arc::downgrade(&self.idle),
+        })
+    }
+
+    /// Returns the number of idle effects with the given settings.
+    pub fn num_idle(&self, settings: &ReflectionEffectSettings) -> usize {
+        self.idle
+            .lock()
+            .unwrap()
+            .effects
+            .get(settings)
+            .map_or(0, Vec::len)
+    }
+
+    /// Releases all idle effects.
+    ///
+    /// Effects currently handed out are unaffected and still return to the pool when dropped.
+    pub fn clear(&self) {
+        self.idle.lock().unwrap().effects.clear();
+    }
+}
+
+impl<T: ReflectionEffectType> Clone for ReflectionEffectPool<T> {
+    fn clone(&self) -> Self {
+        Self {
+            context: self.context.clone(),
+            audio_settings: self.audio_settings,
+            idle: Arc::clone(&self.idle),
+        }
+    }
+}
+
+/// A reflection effect handed out by a [`ReflectionEffectPool`].
+///
+/// Forwards processing to the underlying [`ReflectionEffect`], and returns it to the pool when
+/// dropped.
+/// If the pool has been dropped in the meantime, or already holds [`ReflectionEffectPool::max_idle`]
+/// idle effects with the same settings, the effect is released instead.
+///
+/// The underlying effect is not exposed, since a handle cloned from it would alias the effect
+/// once the pool hands it out to another source; use [`Self::into_inner`] to take it out of the
+/// pool instead.
+#[derive(Debug)]
+pub struct PooledReflectionEffect<T: ReflectionEffectType> {
+    /// Always `Some`, except after being taken in [`Self::into_inner`] or [`Drop::drop`].
+    effect: Option<ReflectionEffect<T>>,
+    settings: ReflectionEffectSettings,
+    pool: Weak<Mutex<IdleReflectionEffects<T>>>,
+}
+
+impl<T: ReflectionEffectType> PooledReflectionEffect<T> {
+    /// Returns the settings the effect was created with.
+    pub const fn settings(&self) -> &ReflectionEffectSettings {
+        &self.settings
+    }
+
+    /// Detaches the effect from its pool, so that it is not returned to it when dropped.
+    pub fn into_inner(mut self) -> ReflectionEffect<T> {
+        self.effect.take().unwrap()
+    }
+
+    /// Returns the number of tail samples remaining in the effect’s internal buffers.
+    ///
+    /// See [`ReflectionEffect::tail_size`].
+    pub fn tail_size(&self) -> usize {
+        self.effect().tail_size()
+    }
+
+    /// Resets the internal processing state of the effect.
+    ///
+    /// See [`ReflectionEffect::reset`].
+    pub fn reset(&mut self) {
+        self.effect_mut().reset();
+    }
+
+    fn effect(&self) -> &ReflectionEffect<T> {
+        self.effect.as_ref().unwrap()
+    }
+
+    fn effect_mut(&mut self) -> &mut ReflectionEffect<T> {
+        self.effect.as_mut().unwrap()
+    }
+}
+
+impl<T: ReflectionEffectType + CanApplyDirectly> PooledReflectionEffect<T> {
+    /// Applies the effect to an audio buffer.
+    ///
+    /// See [`ReflectionEffect::apply`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EffectError`] under the same conditions as [`ReflectionEffect::apply`].
+    pub fn apply<I, O, PI: ChannelPointers, PO: ChannelPointers>(
+        &mut self,
+        reflection_effect_params: &ReflectionEffectParams<T>,
+        input_buffer: &AudioBuffer<I, PI>,
+        output_buffer: &AudioBuffer<O, PO>,
+    ) -> Result<AudioEffectState, EffectError>
+    where
+        I: AsRef<[Sample]>,
+        O: AsRef<[Sample]> + AsMut<[Sample]>,
+    {
+        self.effect_mut()
+            .apply(reflection_effect_params, input_buffer, output_buffer)
+    }
+
+    /// Retrieves a single frame of tail samples from the effect’s internal buffers.
+    ///
+    /// See [`ReflectionEffect::tail`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EffectError`] under the same conditions as [`ReflectionEffect::tail`].
+    pub fn tail<O>(&self, output_buffer: &AudioBuffer<O>) -> Result<AudioEffectState, EffectError>
+    where
+        O: AsRef<[Sample]> + AsMut<[Sample]>,
+    {
+        self.effect().tail(output_buffer)
+    }
+}
+
+impl<T: ReflectionEffectType + CanUseReflectionMixer> PooledReflectionEffect<T> {
+    /// Applies the effect to an audio buffer, mixing its output into the given mixer.
+    ///
+    /// See [`ReflectionEffect::apply_into_mixer`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EffectError`] under the same conditions as
+    /// [`ReflectionEffect::apply_into_mixer`].
+    pub fn apply_into_mixer<I, O, PI: ChannelPointers, PO: ChannelPointers>(
+        &mut self,
+        reflection_effect_params: &ReflectionEffectParams<T>,
+        input_buffer: &AudioBuffer<I, PI>,
+        output_buffer: &AudioBuffer<O, PO>,
+        mixer: &ReflectionMixer<T>,
+    ) -> Result<AudioEffectState, EffectError>
+    where
+        I: AsRef<[Sample]>,
+        O: AsRef<[Sample]> + AsMut<[Sample]>,
+    {
+        self.effect_mut().apply_into_mixer(
+            reflection_effect_params,
+            input_buffer,
+            output_buffer,
+            mixer,
+        )
+    }
+
+    /// Retrieves a single frame of tail samples from the effect’s internal buffers, mixing them
+    /// into the given mixer.
+    ///
+    /// See [`ReflectionEffect::tail_into_mixer`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EffectError`] under the same conditions as
+    /// [`ReflectionEffect::tail_into_mixer`].
+    pub fn tail_into_mixer<O>(
+        &self,
+        output_buffer: &AudioBuffer<O>,
+        mixer: &ReflectionMixer<T>,
+    ) -> Result<AudioEffectState, EffectError>
+    where
+        O: AsRef<[Sample]> + AsMut<[Sample]>,
+    {
+        self.effect().tail_into_mixer(output_buffer, mixer)
+    }
+}
+
+impl<T: ReflectionEffectType> Drop for PooledReflectionEffect<T> {
+    fn drop(&mut self) {
+        let (Some(effect), Some(pool)) = (self.effect.take(), self.pool.upgrade()) else {
+            return;
+        };
+
+        // Don't panic while dropping; a poisoned pool simply releases the effect.
+        if let Ok(mut idle) = pool.lock() {
+            let max_idle = idle.max_idle;
+            let effects = idle.effects.entry(self.settings).or_default();
+            if effects.len() < max_idle {
+                effects.push(effect);
+            }
+        }
+    }
+}
+
 /// Parameters for applying a reflection effect to an audio buffer.
 #[derive(Debug, PartialEq)]
 pub struct ReflectionEffectParams<T: ReflectionEffectType> {
@@ -827,7 +1180,7 @@ impl ReflectionEffectParams<TrueAudioNext> {
     /// - `num_channels`: number of IR channels to process. May be less than the number of channels specified when creating the effect, in which case CPU usage will be reduced.
     /// - `impulse_response_size`: number of IR samples per channel to process. May be less than the number of samples specified when creating the effect, in which case CPU usage will be reduced.
     /// - `device`: the TrueAudio Next device to use for convolution processing.
-    /// - `slot`: the TrueAudio Next slot index to use for convolution processing. The slot identifies the IR to use.
+    /// - `slot`: the TrueAudio Next slot index to use for convolution processing. The slot identifies the IR to use, and is the index of a slot reserved with [`TrueAudioNextDevice::reserve_slot`].
     pub fn new(
         num_channels: u32,
         impulse_response_size: u32,
@@ -898,6 +1251,55 @@ impl<T: ReflectionEffectType> ReflectionEffectParams<T> {
         Ok(())
     }
 
+    /// Returns a copy of these params processing fewer impulse response channels and samples, for
+    /// a given quality level.
+    ///
+    /// This is typically used for level of detail: distant or quiet sources can be rendered with
+    /// a lower `quality` to reduce CPU usage.
+    ///
+    /// `quality` is clamped to `[0.0, 1.0]`. A quality of `1.0` leaves the params unchanged.
+    /// - The number of channels is scaled by `quality`, then rounded down to the nearest number of
+    ///   channels of a full Ambisonics order (1, 4, 9, 16...).
+    /// - The impulse response size is scaled by `quality`, rounded up.
+    ///
+    /// Both are at least 1, and never exceed the values of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// // Third-order Ambisonics (16 channels), 1 second at 48 kHz.
+    /// let params = ReflectionEffectParams::<Parametric>::new([1.0, 0.8, 0.5], 16, 48_000);
+    ///
+    /// // Halving the quality keeps first-order Ambisonics (4 channels, since 8 is not a valid
+    /// // channel count) and half a second of impulse response.
+    /// let distant_params = params.with_quality(0.5);
+    /// ```
+    pub fn with_quality(&self, quality: f32) -> Self {
+        let quality = if quality.is_nan() {
+            1.0
+        } else {
+            quality.clamp(0.0, 1.0)
+        };
+
+        // Full quality keeps channel counts that are not those of a full Ambisonics order.
+        if quality == 1.0 {
+            return self.clone();
+        }
+
+        // Channel counts of full Ambisonics orders are perfect squares.
+        let order_plus_one = (self.num_channels as f32 * quality).sqrt().floor() as u32;
+        let num_channels = (order_plus_one * order_plus_one).clamp(1, self.num_channels.max(1));
+
+        let impulse_response_size = ((self.impulse_response_size as f32 * quality).ceil() as u32)
+            .clamp(1, self.impulse_response_size.max(1));
+
+        let mut params = self.clone();
+        params.num_channels = num_channels;
+        params.impulse_response_size = impulse_response_size;
+        params
+    }
+
     /// Constructs params from FFI representation.
     ///
     /// # Safety
@@ -910,7 +1312,7 @@ impl<T: ReflectionEffectType> ReflectionEffectParams<T> {
         let device = if params.tanDevice.is_null() {
             None
         } else {
-            Some(TrueAudioNextDevice(
+            Some(TrueAudioNextDevice::from(
                 // SAFETY: Safety invariants upheld by the caller.
                 unsafe { audionimbus_sys::iplTrueAudioNextDeviceRetain(params.tanDevice) },
             ))
@@ -949,6 +1351,43 @@ impl<T: ReflectionEffectType> Drop for ReflectionEffectParams<T> {
     }
 }
 
+impl<T: ReflectionEffectType> Clone for ReflectionEffectParams<T> {
+    /// Returns a shallow copy of the params.
+    ///
+    /// The impulse response is not copied: both params point to the same IR, owned by Steam Audio.
+    /// For params retrieved from a [`Source`](crate::Source), that IR is overwritten in place by
+    /// subsequent reflection simulations for the source, so a clone kept from a previous frame
+    /// compares equal to the latest params while its IR contents have already changed.
+    /// Comparing clones is therefore only meaningful for the other parameters, such as the
+    /// number of channels, the IR size or the reverb times.
+    ///
+    /// The source the params were retrieved from, if any, and the TrueAudio Next device are
+    /// retained, so the clone is valid for as long as the original.
+    fn clone(&self) -> Self {
+        let source = if self._source.is_null() {
+            std::ptr::null_mut()
+        } else {
+            // SAFETY: The source will not be destroyed until all references are released.
+            unsafe { audionimbus_sys::iplSourceRetain(self._source) }
+        };
+
+        Self {
+            impulse_response: ReflectionEffectIR(self.impulse_response.0),
+            reverb_times: self.reverb_times,
+            equalizer: self.equalizer,
+            delay: self.delay,
+            num_channels: self.num_channels,
+            impulse_response_size: self.impulse_response_size,
+            max_num_channels: self.max_num_channels,
+            max_impulse_response_size: self.max_impulse_response_size,
+            true_audio_next_device: self.true_audio_next_device.clone(),
+            true_audio_next_slot: self.true_audio_next_slot,
+            _source: source,
+            _marker: PhantomData,
+        }
+    }
+}
+
 /// The impulse response of [`ReflectionEffectParams`].
 #[derive(Debug, Eq, PartialEq)]
 pub struct ReflectionEffectIR(pub audionimbus_sys::IPLReflectionEffectIR);
@@ -985,12 +1424,30 @@ impl<T: ReflectionEffectType> ReflectionEffectParams<T> {
 ///
 /// Using this is optional. Depending on the reflection effect algorithm used, a reflection mixer may provide a reduction in CPU usage.
 ///
+/// Only [`Convolution`] and [`TrueAudioNext`] effects can be mixed, which is enforced by the
+/// [`CanUseReflectionMixer`] bound:
+///
+/// ```compile_fail
+/// # use audionimbus::*;
+/// # let context = Context::default();
+/// # let audio_settings = AudioSettings::default();
+/// let mixer = ReflectionMixer::<Parametric>::try_new(
+///     &context,
+///     &audio_settings,
+///     &ReflectionEffectSettings {
+///         impulse_response_size: 2 * audio_settings.sampling_rate,
+///         num_channels: 4,
+///     },
+/// )?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
 /// `ReflectionMixer` is a reference-counted handle to an underlying Steam Audio object.
 /// Cloning it is cheap; it produces a new handle pointing to the same underlying object, while
 /// incrementing a reference count.
 /// The underlying object is destroyed when all handles are dropped.
 #[derive(Debug)]
-pub struct ReflectionMixer<T: ReflectionEffectType> {
+pub struct ReflectionMixer<T: ReflectionEffectType + CanUseReflectionMixer> {
     inner: audionimbus_sys::IPLReflectionMixer,
 
     /// Number of output channels required.
@@ -999,7 +1456,7 @@ pub struct ReflectionMixer<T: ReflectionEffectType> {
     _marker: PhantomData<T>,
 }
 
-impl<T: ReflectionEffectType> ReflectionMixer<T> {
+impl<T: ReflectionEffectType + CanUseReflectionMixer> ReflectionMixer<T> {
     /// Creates a new reflection mixer and returns a handle to it.
     ///
     /// # Errors
@@ -1039,14 +1496,12 @@ impl<T: ReflectionEffectType> ReflectionMixer<T> {
     /// Retrieves the contents of the reflection mixer and places it into the audio buffer.
     ///
     /// The output audio buffer must have as many channels as the impulse response specified when
-    /// creating the effect (for convolution, hybrid, and TrueAudioNext) or at least one channel
-    /// (for parametric).
+    /// creating the mixer.
     ///
     /// # Errors
     ///
     /// Returns [`EffectError`] if the output audio buffer does not have as many channels as the
-    /// impulse impulse response specified when creating the effect (for convolution, hybrid, and
-    /// TrueAudioNext) or at least one channel (for parametric).
+    /// impulse response specified when creating the mixer.
     pub fn apply<O, PO: ChannelPointers>(
         &mut self,
         reflection_effect_params: &mut ReflectionEffectParams<T>,
@@ -1055,6 +1510,8 @@ impl<T: ReflectionEffectType> ReflectionMixer<T> {
     where
         O: AsRef<[Sample]> + AsMut<[Sample]>,
     {
+        profile_scope!("audionimbus::ReflectionMixer::apply");
+
         let num_output_channels = output_buffer.num_channels();
         if !self
             .num_output_channels
@@ -1093,21 +1550,25 @@ impl<T: ReflectionEffectType> ReflectionMixer<T> {
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLReflectionMixer {
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLReflectionMixer {
         &mut self.inner
     }
 }
 
-impl<T: ReflectionEffectType> Drop for ReflectionMixer<T> {
+impl<T: ReflectionEffectType + CanUseReflectionMixer> Drop for ReflectionMixer<T> {
     fn drop(&mut self) {
         unsafe { audionimbus_sys::iplReflectionMixerRelease(&raw mut self.inner) }
     }
 }
 
-unsafe impl<T: ReflectionEffectType> Send for ReflectionMixer<T> {}
-unsafe impl<T: ReflectionEffectType> Sync for ReflectionMixer<T> {}
+unsafe impl<T: ReflectionEffectType + CanUseReflectionMixer> Send for ReflectionMixer<T> {}
+unsafe impl<T: ReflectionEffectType + CanUseReflectionMixer> Sync for ReflectionMixer<T> {}
 
-impl<T: ReflectionEffectType> Clone for ReflectionMixer<T> {
+impl<T: ReflectionEffectType + CanUseReflectionMixer> Clone for ReflectionMixer<T> {
     /// Retains an additional reference to the reflection mixer.
     ///
     /// The returned [`ReflectionMixer`] shares the same underlying Steam Audio object.
@@ -1121,15 +1582,15 @@ impl<T: ReflectionEffectType> Clone for ReflectionMixer<T> {
     }
 }
 
-impl<T: ReflectionEffectType> PartialEq for ReflectionMixer<T> {
+impl<T: ReflectionEffectType + CanUseReflectionMixer> PartialEq for ReflectionMixer<T> {
     fn eq(&self, other: &Self) -> bool {
         self.raw_ptr() == other.raw_ptr()
     }
 }
 
-impl<T: ReflectionEffectType> Eq for ReflectionMixer<T> {}
+impl<T: ReflectionEffectType + CanUseReflectionMixer> Eq for ReflectionMixer<T> {}
 
-impl<T: ReflectionEffectType> Hash for ReflectionMixer<T> {
+impl<T: ReflectionEffectType + CanUseReflectionMixer> Hash for ReflectionMixer<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         std::ptr::hash(self.raw_ptr(), state);
     }
@@ -1823,164 +2284,96 @@ mod tests {
         }
     }
 
-    mod reflection_mixer {
+    mod reflection_effect_params {
         use super::*;
 
-        mod apply {
+        mod clone {
             use super::*;
 
             #[test]
-            fn test_valid() {
-                let context = Context::default();
-
-                let audio_settings = AudioSettings::default();
-                let impulse_response_size = 2 * audio_settings.sampling_rate;
-
-                let simulation_settings = SimulationSettings::new(&audio_settings)
-                    .with_reflections(ConvolutionSettings {
-                        max_num_rays: 4096,
-                        num_diffuse_samples: 32,
-                        max_duration: 2.0,
-                        max_num_sources: 8,
-                        num_threads: 1,
-                        max_order: 1,
-                    });
-                let mut simulator = Simulator::try_new(&context, &simulation_settings).unwrap();
-
-                let scene = Scene::try_new(&context).unwrap();
-                simulator.set_scene(&scene);
-
-                let source = Source::try_new(&simulator).unwrap();
-                simulator.add_source(&source);
-
-                let simulation_shared_inputs = SimulationSharedInputs::new(
-                    CoordinateSystem::default(),
-                )
-                .with_reflections(ReflectionsSharedInputs {
-                    num_rays: 4096,
-                    num_bounces: 16,
-                    duration: 2.0,
-                    order: 1,
-                    irradiance_min_distance: 1.0,
-                });
-                simulator
-                    .set_shared_reflections_inputs(&simulation_shared_inputs)
-                    .unwrap();
-
-                simulator.commit();
-
-                assert!(simulator.run_reflections().is_ok());
-                let mut reflection_effect_params = source.get_reflections_outputs().unwrap();
-
-                let num_output_channels = num_ambisonics_channels(1);
-                let reflection_effect_settings = ReflectionEffectSettings {
-                    impulse_response_size,
-                    num_channels: num_output_channels,
-                };
-
-                let mut mixer = ReflectionMixer::<Convolution>::try_new(
-                    &context,
-                    &audio_settings,
-                    &reflection_effect_settings,
-                )
-                .unwrap();
-
-                let mut output_container =
-                    vec![0.0; (num_output_channels * audio_settings.frame_size) as usize];
-                let output_buffer = AudioBuffer::try_with_data_and_settings(
-                    &mut output_container,
-                    AudioBufferSettings::with_num_channels(num_output_channels),
-                )
-                .unwrap();
-
-                assert!(
-                    mixer
-                        .apply(&mut reflection_effect_params, &output_buffer)
-                        .is_ok()
-                );
+            fn test_parametric() {
+                let params = ReflectionEffectParams::<Parametric>::new([1.0, 0.8, 0.5], 4, 1024);
+                let clone = params.clone();
+                assert_eq!(clone, params);
             }
 
             #[test]
-            fn test_invalid_output_num_channels() {
+            fn test_outlives_original() {
                 let context = Context::default();
-
                 let audio_settings = AudioSettings::default();
-                let impulse_response_size = 2 * audio_settings.sampling_rate;
-
                 let simulation_settings = SimulationSettings::new(&audio_settings)
                     .with_reflections(ConvolutionSettings {
-                        max_num_rays: 4096,
+                        max_num_rays: 1024,
                         num_diffuse_samples: 32,
-                        max_duration: 2.0,
-                        max_num_sources: 8,
+                        max_duration: 1.0,
+                        max_num_sources: 1,
                         num_threads: 1,
                         max_order: 1,
                     });
-                let mut simulator = Simulator::try_new(&context, &simulation_settings).unwrap();
-
-                let scene = Scene::try_new(&context).unwrap();
-                simulator.set_scene(&scene);
-
+                let simulator = Simulator::try_new(&context, &simulation_settings).unwrap();
                 let source = Source::try_new(&simulator).unwrap();
-                simulator.add_source(&source);
 
-                let simulation_shared_inputs = SimulationSharedInputs::new(
-                    CoordinateSystem::default(),
-                )
-                .with_reflections(ReflectionsSharedInputs {
-                    num_rays: 4096,
-                    num_bounces: 16,
-                    duration: 2.0,
-                    order: 1,
-                    irradiance_min_distance: 1.0,
-                });
-                simulator
-                    .set_shared_reflections_inputs(&simulation_shared_inputs)
-                    .unwrap();
+                let params = source.get_reflections_outputs().unwrap();
+                let clone = params.clone();
+                assert_eq!(clone, params);
 
-                simulator.commit();
+                drop(params);
+                drop(source);
+                assert!(!clone._source.is_null());
+            }
+        }
 
-                assert!(simulator.run_reflections().is_ok());
-                let mut reflection_effect_params = source.get_reflections_outputs().unwrap();
+        mod with_quality {
+            use super::*;
 
-                let num_output_channels = num_ambisonics_channels(1);
-                let reflection_effect_settings = ReflectionEffectSettings {
-                    impulse_response_size,
-                    num_channels: num_output_channels,
-                };
+            #[test]
+            fn test_full_quality() {
+                let params = ReflectionEffectParams::<Parametric>::new([1.0, 0.8, 0.5], 9, 1000);
+                assert_eq!(params.with_quality(1.0), params);
+            }
 
-                let mut mixer = ReflectionMixer::<Convolution>::try_new(
-                    &context,
-                    &audio_settings,
-                    &reflection_effect_settings,
-                )
-                .unwrap();
+            #[test]
+            fn test_full_quality_partial_order() {
+                let params = ReflectionEffectParams::<Parametric>::new([1.0, 0.8, 0.5], 6, 1000);
+                assert_eq!(params.with_quality(1.0), params);
+                assert_eq!(params.with_quality(f32::NAN), params);
+                assert_eq!(params.with_quality(0.99).num_channels, 4);
+            }
 
-                let mut output_container = vec![0.0; (2 * audio_settings.frame_size) as usize];
-                let output_buffer = AudioBuffer::try_with_data_and_settings(
-                    &mut output_container,
-                    AudioBufferSettings::with_num_channels(2),
-                )
-                .unwrap();
+            #[test]
+            fn test_rounds_to_ambisonics_order() {
+                let params = ReflectionEffectParams::<Parametric>::new([1.0, 0.8, 0.5], 16, 1000);
+                let reduced = params.with_quality(0.5);
+                assert_eq!(reduced.num_channels, 4);
+                assert_eq!(reduced.impulse_response_size, 500);
+                assert_eq!(reduced.max_num_channels, 16);
+            }
 
-                assert_eq!(
-                    mixer.apply(&mut reflection_effect_params, &output_buffer),
-                    Err(EffectError::InvalidOutputChannels {
-                        expected: ChannelRequirement::Exactly(4),
-                        actual: 2
-                    })
-                );
+            #[test]
+            fn test_minimum() {
+                let params = ReflectionEffectParams::<Parametric>::new([1.0, 0.8, 0.5], 4, 1000);
+                let reduced = params.with_quality(0.0);
+                assert_eq!(reduced.num_channels, 1);
+                assert_eq!(reduced.impulse_response_size, 1);
             }
+        }
+    }
+
+    mod reflection_mixer {
+        use super::*;
+
+        mod apply {
+            use super::*;
 
             #[test]
-            fn test_parametric_valid() {
+            fn test_valid() {
                 let context = Context::default();
 
                 let audio_settings = AudioSettings::default();
                 let impulse_response_size = 2 * audio_settings.sampling_rate;
 
                 let simulation_settings = SimulationSettings::new(&audio_settings)
-                    .with_reflections(ParametricSettings {
+                    .with_reflections(ConvolutionSettings {
                         max_num_rays: 4096,
                         num_diffuse_samples: 32,
                         max_duration: 2.0,
@@ -2021,7 +2414,7 @@ mod tests {
                     num_channels: num_output_channels,
                 };
 
-                let mut mixer = ReflectionMixer::<Parametric>::try_new(
+                let mut mixer = ReflectionMixer::<Convolution>::try_new(
                     &context,
                     &audio_settings,
                     &reflection_effect_settings,
@@ -2044,14 +2437,14 @@ mod tests {
             }
 
             #[test]
-            fn test_parametric_at_least_one_channel() {
+            fn test_invalid_output_num_channels() {
                 let context = Context::default();
 
                 let audio_settings = AudioSettings::default();
                 let impulse_response_size = 2 * audio_settings.sampling_rate;
 
                 let simulation_settings = SimulationSettings::new(&audio_settings)
-                    .with_reflections(ParametricSettings {
+                    .with_reflections(ConvolutionSettings {
                         max_num_rays: 4096,
                         num_diffuse_samples: 32,
                         max_duration: 2.0,
@@ -2092,21 +2485,26 @@ mod tests {
                     num_channels: num_output_channels,
                 };
 
-                let mut mixer = ReflectionMixer::<Parametric>::try_new(
+                let mut mixer = ReflectionMixer::<Convolution>::try_new(
                     &context,
                     &audio_settings,
                     &reflection_effect_settings,
                 )
                 .unwrap();
 
-                // Test with 1 channel (minimum for parametric)
-                let mut output_container = vec![0.0; audio_settings.frame_size as usize];
-                let output_buffer = AudioBuffer::try_with_data(&mut output_container).unwrap();
+                let mut output_container = vec![0.0; (2 * audio_settings.frame_size) as usize];
+                let output_buffer = AudioBuffer::try_with_data_and_settings(
+                    &mut output_container,
+                    AudioBufferSettings::with_num_channels(2),
+                )
+                .unwrap();
 
-                assert!(
-                    mixer
-                        .apply(&mut reflection_effect_params, &output_buffer)
-                        .is_ok()
+                assert_eq!(
+                    mixer.apply(&mut reflection_effect_params, &output_buffer),
+                    Err(EffectError::InvalidOutputChannels {
+                        expected: ChannelRequirement::Exactly(4),
+                        actual: 2
+                    })
                 );
             }
         }
@@ -2167,4 +2565,142 @@ mod tests {
             }
         }
     }
+
+    mod reflection_effect_settings {
+        use super::*;
+
+        mod for_simulation {
+            use super::*;
+
+            #[test]
+            fn test_matches_simulation_settings() {
+                let audio_settings = AudioSettings {
+                    sampling_rate: 44100,
+                    frame_size: 512,
+                };
+                let simulation_settings = SimulationSettings::new(&audio_settings)
+                    .with_reflections(HybridSettings {
+                        max_num_rays: 4096,
+                        num_diffuse_samples: 32,
+                        max_duration: 1.5,
+                        max_num_sources: 8,
+                        num_threads: 1,
+                        max_order: 2,
+                    });
+
+                let settings = ReflectionEffectSettings::for_simulation(&simulation_settings);
+                assert_eq!(settings.num_channels, 9);
+                assert_eq!(settings.impulse_response_size, 66150);
+            }
+
+            #[test]
+            fn test_creates_effect() {
+                let context = Context::default();
+                let audio_settings = AudioSettings::default();
+                let simulation_settings = SimulationSettings::new(&audio_settings)
+                    .with_reflections(ConvolutionSettings::default());
+
+                let settings = ReflectionEffectSettings::for_simulation(&simulation_settings);
+                assert!(
+                    ReflectionEffect::<Convolution>::try_new(&context, &audio_settings, &settings)
+                        .is_ok()
+                );
+            }
+        }
+    }
+
+    mod reflection_effect_pool {
+        use super::*;
+
+        fn settings() -> ReflectionEffectSettings {
+            ReflectionEffectSettings {
+                impulse_response_size: 1024,
+                num_channels: 4,
+            }
+        }
+
+        #[test]
+        fn test_reuses_released_effect() {
+            let context = Context::default();
+            let pool =
+                ReflectionEffectPool::<Convolution>::new(&context, &AudioSettings::default());
+
+            let effect = pool.acquire(&settings()).unwrap();
+            let raw_ptr = effect.effect().raw_ptr();
+            drop(effect);
+            assert_eq!(pool.num_idle(&settings()), 1);
+
+            let effect = pool.acquire(&settings()).unwrap();
+            assert_eq!(effect.effect().raw_ptr(), raw_ptr);
+            assert_eq!(pool.num_idle(&settings()), 0);
+        }
+
+        #[test]
+        fn test_keyed_on_settings() {
+            let context = Context::default();
+            let pool =
+                ReflectionEffectPool::<Convolution>::new(&context, &AudioSettings::default());
+
+            drop(pool.acquire(&settings()).unwrap());
+
+            let other_settings = ReflectionEffectSettings {
+                num_channels: 9,
+                ..settings()
+            };
+            let effect = pool.acquire(&other_settings).unwrap();
+            assert_eq!(effect.settings(), &other_settings);
+            assert_eq!(pool.num_idle(&settings()), 1);
+            assert_eq!(pool.num_idle(&other_settings), 0);
+        }
+
+        #[test]
+        fn test_into_inner_detaches() {
+            let context = Context::default();
+            let pool = ReflectionEffectPool::<Parametric>::new(&context, &AudioSettings::default());
+
+            let effect = pool.acquire(&settings()).unwrap().into_inner();
+            drop(effect);
+            assert_eq!(pool.num_idle(&settings()), 0);
+        }
+
+        #[test]
+        fn test_outlives_pool() {
+            let context = Context::default();
+            let pool =
+                ReflectionEffectPool::<Convolution>::new(&context, &AudioSettings::default());
+
+            let mut effect = pool.acquire(&settings()).unwrap();
+            drop(pool);
+            effect.reset();
+        }
+
+        #[test]
+        fn test_clear() {
+            let context = Context::default();
+            let pool =
+                ReflectionEffectPool::<Convolution>::new(&context, &AudioSettings::default());
+
+            drop(pool.acquire(&settings()).unwrap());
+            pool.clear();
+            assert_eq!(pool.num_idle(&settings()), 0);
+        }
+
+        #[test]
+        fn test_max_idle() {
+            let context = Context::default();
+            let pool =
+                ReflectionEffectPool::<Convolution>::new(&context, &AudioSettings::default());
+            pool.set_max_idle(1);
+
+            let effects = [
+                pool.acquire(&settings()).unwrap(),
+                pool.acquire(&settings()).unwrap(),
+            ];
+            drop(effects);
+            assert_eq!(pool.num_idle(&settings()), 1);
+
+            pool.set_max_idle(0);
+            assert_eq!(pool.num_idle(&settings()), 0);
+        }
+    }
 }
@@ -1,13 +1,13 @@
 //! Virtual surround sound rendering for headphones using HRTF.
 
 use super::audio_effect_state::AudioEffectState;
-use super::{EffectError, SpeakerLayout};
+use super::{BinauralRenderer, EffectError, SpeakerLayout};
 use crate::audio_buffer::{AudioBuffer, Sample};
 use crate::audio_settings::AudioSettings;
 use crate::context::Context;
 use crate::error::{SteamAudioError, to_option_error};
 use crate::ffi_wrapper::FFIWrapper;
-use crate::{ChannelPointers, ChannelRequirement, Hrtf};
+use crate::{ChannelPointers, ChannelRequirement, Hrtf, Sealed};
 use std::hash::{Hash, Hasher};
 
 /// Spatializes multi-channel speaker-based audio (e.g., stereo, quadraphonic, 5.1, or 7.1) using HRTF-based binaural rendering.
@@ -96,14 +96,9 @@ impl VirtualSurroundEffect {
             return Err(error);
         }
 
-        let num_input_channels = match &virtual_surround_effect_settings.speaker_layout {
-            SpeakerLayout::Mono => 1,
-            SpeakerLayout::Stereo => 2,
-            SpeakerLayout::Quadraphonic => 4,
-            SpeakerLayout::Surround5_1 => 6,
-            SpeakerLayout::Surround7_1 => 8,
-            SpeakerLayout::Custom { speaker_directions } => speaker_directions.len() as u32,
-        };
+        let num_input_channels = virtual_surround_effect_settings
+            .speaker_layout
+            .num_channels() as u32;
 
         Ok(Self {
             inner,
@@ -133,6 +128,8 @@ impl VirtualSurroundEffect {
         I: AsRef<[Sample]>,
         O: AsRef<[Sample]> + AsMut<[Sample]>,
     {
+        profile_scope!("audionimbus::VirtualSurroundEffect::apply");
+
         let num_input_channels = input_buffer.num_channels();
         if num_input_channels != self.num_input_channels {
             return Err(EffectError::InvalidInputChannels {
@@ -216,7 +213,11 @@ impl VirtualSurroundEffect {
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLVirtualSurroundEffect {
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLVirtualSurroundEffect {
         &mut self.inner
     }
 }
@@ -257,6 +258,28 @@ impl Hash for VirtualSurroundEffect {
     }
 }
 
+impl Sealed for VirtualSurroundEffect {}
+
+impl BinauralRenderer for VirtualSurroundEffect {
+    fn num_input_channels(&self) -> u32 {
+        self.num_input_channels
+    }
+
+    fn render<I, O, PI: ChannelPointers, PO: ChannelPointers>(
+        &mut self,
+        input_buffer: &AudioBuffer<I, PI>,
+        hrtf: &Hrtf,
+        output_buffer: &AudioBuffer<O, PO>,
+    ) -> Result<AudioEffectState, EffectError>
+    where
+        I: AsRef<[Sample]>,
+        O: AsRef<[Sample]> + AsMut<[Sample]>,
+    {
+        let params = VirtualSurroundEffectParams { hrtf: hrtf.clone() };
+        self.apply(&params, input_buffer, output_buffer)
+    }
+}
+
 /// Settings used to create a virtual surround effect.
 #[derive(Debug, Clone)]
 pub struct VirtualSurroundEffectSettings {
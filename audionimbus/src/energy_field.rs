@@ -124,6 +124,67 @@ impl EnergyField {
         Ok(data)
     }
 
+    /// Returns the total energy in each frequency band, summed over all bins.
+    ///
+    /// Only the first (omnidirectional) Ambisonic channel is taken into account.
+    pub fn band_energies(&self) -> [f32; NUM_BANDS as usize] {
+        std::array::from_fn(|band_index| {
+            // The first channel and all bands always exist.
+            self.band(0, band_index as u32)
+                .map(|bins| bins.iter().sum())
+                .unwrap_or_default()
+        })
+    }
+
+    /// Returns the largest difference, in dB, between the band energies of `self` and `other`.
+    ///
+    /// See [`Self::band_energies`].
+    /// Bands that are silent in both energy fields are ignored; a band that is silent in only one
+    /// of them yields an infinite difference.
+    pub fn max_band_energy_difference_db(&self, other: &Self) -> f32 {
+        self.band_energies()
+            .into_iter()
+            .zip(other.band_energies())
+            .map(|(energy, other_energy)| {
+                if energy == other_energy {
+                    0.0
+                } else {
+                    (10.0 * (energy / other_energy).log10()).abs()
+                }
+            })
+            .fold(0.0, f32::max)
+    }
+
+    /// Returns `true` if the band energies of `self` and `other` differ by at most
+    /// `tolerance_db`.
+    ///
+    /// Reflection simulations are not deterministic (see the
+    /// [simulation documentation](crate::simulation#determinism)), so this is how results should
+    /// be compared in regression tests.
+    /// The tolerance should account for the variance due to the number of rays traced: a
+    /// tolerance of 1 dB is typically enough with a few thousand rays.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let context = Context::default();
+    /// let settings = EnergyFieldSettings {
+    ///     duration: 1.0,
+    ///     order: 1,
+    /// };
+    /// let golden = EnergyField::try_new(&context, &settings)?;
+    /// let energy_field = EnergyField::try_new(&context, &settings)?;
+    ///
+    /// // Fill both energy fields, e.g. from a stored bake and a new one...
+    ///
+    /// assert!(energy_field.approx_eq(&golden, 1.0));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn approx_eq(&self, other: &Self, tolerance_db: f32) -> bool {
+        self.max_band_energy_difference_db(other) <= tolerance_db
+    }
+
     /// Resets all values stored in the energy field to zero.
     pub fn reset(&mut self) {
         unsafe { audionimbus_sys::iplEnergyFieldReset(self.raw_ptr()) }
@@ -171,7 +232,11 @@ impl EnergyField {
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLEnergyField {
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLEnergyField {
         &mut self.0
     }
 }
@@ -400,6 +465,55 @@ mod tests {
             }
         }
 
+        mod approx_eq {
+            use super::*;
+
+            fn energy_field_with_band_energies(energies: [f32; 3]) -> EnergyField {
+                let context = Context::default();
+                let settings = EnergyFieldSettings {
+                    duration: 1.0,
+                    order: 1,
+                };
+                let energy_field = EnergyField::try_new(&context, &settings).unwrap();
+
+                for (band_index, energy) in energies.into_iter().enumerate() {
+                    let ptr = unsafe {
+                        audionimbus_sys::iplEnergyFieldGetBand(
+                            energy_field.raw_ptr(),
+                            0,
+                            band_index as i32,
+                        )
+                    };
+                    unsafe { *ptr = energy };
+                }
+
+                energy_field
+            }
+
+            #[test]
+            fn test_band_energies() {
+                let energy_field = energy_field_with_band_energies([1.0, 2.0, 3.0]);
+                assert_eq!(energy_field.band_energies(), [1.0, 2.0, 3.0]);
+            }
+
+            #[test]
+            fn test_within_tolerance() {
+                let a = energy_field_with_band_energies([1.0, 1.0, 1.0]);
+                let b = energy_field_with_band_energies([1.1, 1.0, 0.9]);
+                assert!(a.approx_eq(&b, 1.0));
+                assert!(!a.approx_eq(&b, 0.1));
+            }
+
+            #[test]
+            fn test_silent() {
+                let silent = energy_field_with_band_energies([0.0; 3]);
+                assert!(silent.approx_eq(&silent.clone(), 0.0));
+
+                let loud = energy_field_with_band_energies([0.0, 0.0, 1.0]);
+                assert_eq!(silent.max_band_energy_difference_db(&loud), f32::INFINITY);
+            }
+        }
+
         #[test]
         fn test_clone() {
             let context = Context::default();
@@ -2,10 +2,15 @@ use crate::context::Context;
 use crate::hrtf::Hrtf;
 use crate::ray_tracing::RayTracer;
 use crate::simulation::{SimulationSettings, Source};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether an [`FmodIntegration`] guard is currently alive.
+static INTEGRATION_ACTIVE: AtomicBool = AtomicBool::new(false);
 
 /// Initializes the FMOD Studio integration.
 ///
 /// This function must be called before creating any Steam Audio DSP effects.
+/// [`FmodIntegration`] calls it for you, and calls [`terminate`] when it goes out of scope.
 pub fn initialize(context: &Context) {
     unsafe { audionimbus_sys::fmod::iplFMODInitialize(context.raw_ptr()) }
 }
@@ -80,9 +85,130 @@ pub fn version() -> FmodStudioIntegrationVersion {
 }
 
 /// The version of the FMOD Studio integration.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct FmodStudioIntegrationVersion {
     pub major: usize,
     pub minor: usize,
     pub patch: usize,
 }
+
+impl std::fmt::Display for FmodStudioIntegrationVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// A guard that keeps the FMOD Studio integration initialized for as long as it is alive.
+///
+/// The integration is initialized when the guard is created, and shut down when it is dropped.
+/// The guard's methods can only be called while it is alive, and so while the integration is
+/// initialized, as long as [`initialize`] and [`terminate`] are not also called directly.
+///
+/// Steam Audio DSP effects must be destroyed before the guard is dropped.
+/// The guard keeps the [`Context`] it was created from alive.
+#[derive(Debug)]
+pub struct FmodIntegration {
+    _context: Context,
+}
+
+impl FmodIntegration {
+    /// Initializes the FMOD Studio integration and returns a guard that shuts it down when dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FmodIntegrationError::AlreadyInitialized`] if another [`FmodIntegration`] guard
+    /// is alive.
+    pub fn try_new(context: &Context) -> Result<Self, FmodIntegrationError> {
+        activate()?;
+        initialize(context);
+
+        Ok(Self {
+            _context: context.clone(),
+        })
+    }
+
+    /// Specifies the simulation settings used by the game engine for simulating direct and/or indirect sound propagation.
+    ///
+    /// See [`set_simulation_settings`].
+    pub fn set_simulation_settings<T: RayTracer, D, R, P, RE>(
+        &self,
+        simulation_settings: &SimulationSettings<T, D, R, P, RE>,
+    ) {
+        set_simulation_settings(simulation_settings);
+    }
+
+    /// Specifies the HRTF to use for spatialization in subsequent audio frames.
+    ///
+    /// See [`set_hrtf`].
+    pub fn set_hrtf(&self, hrtf: &Hrtf) {
+        set_hrtf(hrtf);
+    }
+
+    /// Enables or disables HRTF.
+    ///
+    /// See [`set_hrtf_disabled`].
+    pub fn set_hrtf_disabled(&self, disabled: bool) {
+        set_hrtf_disabled(disabled);
+    }
+
+    /// Registers a source for use by Steam Audio DSP effects in the audio thread, and returns the corresponding handle.
+    ///
+    /// See [`add_source`].
+    pub fn add_source(&self, source: &Source) -> SourceHandle {
+        add_source(source)
+    }
+
+    /// Unregisters a [`Source`] associated with the given handle, so the Steam Audio DSP effects can no longer use it.
+    ///
+    /// See [`remove_source`].
+    pub fn remove_source(&self, handle: SourceHandle) {
+        remove_source(handle);
+    }
+
+    /// Specifies the [`Source`] used by the game engine for simulating reverb.
+    ///
+    /// See [`set_reverb_source`].
+    pub fn set_reverb_source(&self, source: &Source) {
+        set_reverb_source(source);
+    }
+}
+
+impl Drop for FmodIntegration {
+    fn drop(&mut self) {
+        terminate();
+        INTEGRATION_ACTIVE.store(false, Ordering::Release);
+    }
+}
+
+/// Marks the integration as active.
+///
+/// # Errors
+///
+/// Returns [`FmodIntegrationError::AlreadyInitialized`] if an [`FmodIntegration`] guard is
+/// already alive.
+fn activate() -> Result<(), FmodIntegrationError> {
+    if INTEGRATION_ACTIVE.swap(true, Ordering::AcqRel) {
+        return Err(FmodIntegrationError::AlreadyInitialized);
+    }
+
+    Ok(())
+}
+
+/// [`FmodIntegration`] errors.
+#[derive(Debug, PartialEq, Eq)]
+pub enum FmodIntegrationError {
+    /// Another [`FmodIntegration`] guard is alive.
+    AlreadyInitialized,
+}
+
+impl std::error::Error for FmodIntegrationError {}
+
+impl std::fmt::Display for FmodIntegrationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::AlreadyInitialized => {
+                write!(f, "the FMOD Studio integration is already initialized")
+            }
+        }
+    }
+}
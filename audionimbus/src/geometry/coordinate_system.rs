@@ -4,6 +4,9 @@ use super::{Point, Vector3};
 use bevy::prelude::GlobalTransform;
 
 /// A 3D coordinate system, expressed relative to a canonical coordinate system.
+///
+/// Like that of [`Vector3`], [`PartialEq`] compares components exactly; prefer [`Self::approx_eq`]
+/// to compare computed coordinate systems.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct CoordinateSystem {
     /// Unit vector pointing to the right (local +x axis).
@@ -30,6 +33,171 @@ impl Default for CoordinateSystem {
     }
 }
 
+impl CoordinateSystem {
+    /// Returns `true` if the axes and origin of this coordinate system are each approximately
+    /// equal to those of `other`, as defined by [`Vector3::approx_eq`].
+    pub const fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.right.approx_eq(&other.right, epsilon)
+            && self.up.approx_eq(&other.up, epsilon)
+            && self.ahead.approx_eq(&other.ahead, epsilon)
+            && self.origin.approx_eq(&other.origin, epsilon)
+    }
+}
+
+#[cfg(feature = "std")]
+impl CoordinateSystem {
+    /// Interpolates between this coordinate system and `other`.
+    ///
+    /// The orientation is spherically interpolated, so that it rotates at a constant rate along
+    /// the shortest arc, and the origin is linearly interpolated.
+    /// `t` is clamped to `[0.0, 1.0]`: 0.0 returns `self` and 1.0 returns `other`, up to
+    /// re-orthonormalization of their axes.
+    ///
+    /// This is typically used to feed intermediate listener or source poses to effects at audio
+    /// rate, while running simulations less often.
+    ///
+    /// The axes of the result are orthonormal, and have the same handedness as those of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let previous = CoordinateSystem {
+    ///     right: Vector3::new(1.0, 0.0, 0.0),
+    ///     up: Vector3::new(0.0, 1.0, 0.0),
+    ///     ahead: Vector3::new(0.0, 0.0, -1.0),
+    ///     origin: Point::new(0.0, 0.0, 0.0),
+    /// };
+    /// // Turned 90° to the left and moved forward.
+    /// let next = CoordinateSystem {
+    ///     right: Vector3::new(0.0, 0.0, -1.0),
+    ///     up: Vector3::new(0.0, 1.0, 0.0),
+    ///     ahead: Vector3::new(-1.0, 0.0, 0.0),
+    ///     origin: Point::new(0.0, 0.0, -2.0),
+    /// };
+    ///
+    /// let halfway = previous.slerp(&next, 0.5);
+    /// let ahead = Vector3::new(-1.0, 0.0, -1.0).normalize();
+    /// assert!(halfway.ahead.approx_eq(&ahead, 1e-6));
+    /// assert_eq!(halfway.origin, Point::new(0.0, 0.0, -1.0));
+    /// ```
+    pub fn slerp(&self, other: &Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+
+        // `ahead` is flipped if needed, so that both bases are rotations.
+        let handedness = if self.right.dot(&self.up.cross(&self.ahead)) < 0.0 {
+            -1.0
+        } else {
+            1.0
+        };
+
+        let from = rotation_to_quaternion(self.up, self.ahead * handedness);
+        let to = rotation_to_quaternion(other.up, other.ahead * handedness);
+        let [right, up, ahead] = quaternion_to_rotation(slerp_quaternions(from, to, t));
+
+        let lerp = |a: f32, b: f32| a * (1.0 - t) + b * t;
+
+        Self {
+            right,
+            up,
+            ahead: ahead * handedness,
+            origin: Point::new(
+                lerp(self.origin.x, other.origin.x),
+                lerp(self.origin.y, other.origin.y),
+                lerp(self.origin.z, other.origin.z),
+            ),
+        }
+    }
+}
+
+/// Unit quaternion, as `[w, x, y, z]`.
+#[cfg(feature = "std")]
+type Quaternion = [f32; 4];
+
+/// Returns the rotation whose y and z axes are `y` and `z` once orthonormalized.
+#[cfg(feature = "std")]
+fn rotation_to_quaternion(y: Vector3, z: Vector3) -> Quaternion {
+    // Gram-Schmidt, keeping the direction of `z`.
+    let z = z.normalize();
+    let y = (y - z * y.dot(&z)).normalize();
+    let x = y.cross(&z);
+
+    let trace = x.x + y.y + z.z;
+    let q = if trace > 0.0 {
+        let s = (trace + 1.0).sqrt() * 2.0;
+        [0.25 * s, (y.z - z.y) / s, (z.x - x.z) / s, (x.y - y.x) / s]
+    } else if x.x > y.y && x.x > z.z {
+        let s = (1.0 + x.x - y.y - z.z).sqrt() * 2.0;
+        [(y.z - z.y) / s, 0.25 * s, (y.x + x.y) / s, (z.x + x.z) / s]
+    } else if y.y > z.z {
+        let s = (1.0 + y.y - x.x - z.z).sqrt() * 2.0;
+        [(z.x - x.z) / s, (y.x + x.y) / s, 0.25 * s, (z.y + y.z) / s]
+    } else {
+        let s = (1.0 + z.z - x.x - y.y).sqrt() * 2.0;
+        [(x.y - y.x) / s, (z.x + x.z) / s, (z.y + y.z) / s, 0.25 * s]
+    };
+
+    normalize_quaternion(q)
+}
+
+/// Returns the x, y and z axes of the rotation `q`.
+#[cfg(feature = "std")]
+fn quaternion_to_rotation([w, x, y, z]: Quaternion) -> [Vector3; 3] {
+    [
+        Vector3::new(
+            1.0 - 2.0 * (y * y + z * z),
+            2.0 * (x * y + w * z),
+            2.0 * (x * z - w * y),
+        ),
+        Vector3::new(
+            2.0 * (x * y - w * z),
+            1.0 - 2.0 * (x * x + z * z),
+            2.0 * (y * z + w * x),
+        ),
+        Vector3::new(
+            2.0 * (x * z + w * y),
+            2.0 * (y * z - w * x),
+            1.0 - 2.0 * (x * x + y * y),
+        ),
+    ]
+}
+
+#[cfg(feature = "std")]
+fn normalize_quaternion(q: Quaternion) -> Quaternion {
+    let length = q.iter().map(|c| c * c).sum::<f32>().sqrt();
+    q.map(|c| c / length)
+}
+
+#[cfg(feature = "std")]
+fn slerp_quaternions(from: Quaternion, to: Quaternion, t: f32) -> Quaternion {
+    let mut cos_theta: f32 = from.iter().zip(&to).map(|(a, b)| a * b).sum();
+
+    // `to` and `-to` are the same rotation; take the shortest arc.
+    let to = if cos_theta < 0.0 {
+        cos_theta = -cos_theta;
+        to.map(|c| -c)
+    } else {
+        to
+    };
+
+    let (from_weight, to_weight) = if cos_theta > 0.9995 {
+        // Nearly identical rotations: linear interpolation avoids dividing by ~0.
+        (1.0 - t, t)
+    } else {
+        let theta = cos_theta.acos();
+        let sin_theta = theta.sin();
+        (
+            ((1.0 - t) * theta).sin() / sin_theta,
+            (t * theta).sin() / sin_theta,
+        )
+    };
+
+    normalize_quaternion(core::array::from_fn(|i| {
+        from_weight * from[i] + to_weight * to[i]
+    }))
+}
+
+#[cfg(feature = "std")]
 impl From<CoordinateSystem> for audionimbus_sys::IPLCoordinateSpace3 {
     fn from(coordinate_system: CoordinateSystem) -> Self {
         Self {
@@ -41,6 +209,7 @@ impl From<CoordinateSystem> for audionimbus_sys::IPLCoordinateSpace3 {
     }
 }
 
+#[cfg(feature = "std")]
 impl From<audionimbus_sys::IPLCoordinateSpace3> for CoordinateSystem {
     fn from(coordinate_system: audionimbus_sys::IPLCoordinateSpace3) -> Self {
         Self {
@@ -81,4 +250,89 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_coordinate_system_approx_eq() {
+        let cs = CoordinateSystem::default();
+        let mut nudged = cs;
+        nudged.origin.x += 1e-4;
+
+        assert_ne!(cs, nudged);
+        assert!(cs.approx_eq(&nudged, 1e-3));
+        assert!(!cs.approx_eq(&nudged, 1e-5));
+    }
+
+    #[cfg(feature = "std")]
+    mod slerp {
+        use super::*;
+
+        fn assert_orthonormal(cs: &CoordinateSystem) {
+            for axis in [cs.right, cs.up, cs.ahead] {
+                assert!((axis.dot(&axis) - 1.0).abs() < 1e-5);
+            }
+            assert!(cs.right.dot(&cs.up).abs() < 1e-5);
+            assert!(cs.right.dot(&cs.ahead).abs() < 1e-5);
+            assert!(cs.up.dot(&cs.ahead).abs() < 1e-5);
+        }
+
+        /// Steam Audio's convention, with `ahead` along -z.
+        fn facing(ahead: Vector3, origin: Point) -> CoordinateSystem {
+            let up = Vector3::new(0.0, 1.0, 0.0);
+            CoordinateSystem {
+                right: ahead.cross(&up),
+                up,
+                ahead,
+                origin,
+            }
+        }
+
+        #[test]
+        fn test_endpoints() {
+            let a = facing(Vector3::new(0.0, 0.0, -1.0), Point::new(0.0, 0.0, 0.0));
+            let b = facing(Vector3::new(1.0, 0.0, 0.0), Point::new(2.0, 4.0, 6.0));
+
+            for (t, expected) in [(0.0, a), (1.0, b), (-1.0, a), (2.0, b)] {
+                let cs = a.slerp(&b, t);
+                assert!(cs.approx_eq(&expected, 1e-5), "{cs:?} != {expected:?}");
+            }
+        }
+
+        #[test]
+        fn test_midpoint() {
+            let a = facing(Vector3::new(0.0, 0.0, -1.0), Point::new(0.0, 0.0, 0.0));
+            let b = facing(Vector3::new(1.0, 0.0, 0.0), Point::new(2.0, 4.0, 6.0));
+
+            let cs = a.slerp(&b, 0.5);
+            let expected = facing(
+                Vector3::new(1.0, 0.0, -1.0).normalize(),
+                Point::new(1.0, 2.0, 3.0),
+            );
+            assert!(cs.approx_eq(&expected, 1e-5), "{cs:?} != {expected:?}");
+        }
+
+        #[test]
+        fn test_orthonormalizes() {
+            let a = CoordinateSystem {
+                right: Vector3::new(2.0, 0.0, 0.0),
+                up: Vector3::new(0.0, 1.0, 0.1),
+                ahead: Vector3::new(0.0, 0.0, -3.0),
+                origin: Point::default(),
+            };
+            let b = facing(Vector3::new(0.0, 0.0, 1.0), Point::default());
+
+            for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+                let cs = a.slerp(&b, t);
+                assert_orthonormal(&cs);
+                // Handedness is preserved.
+                assert!(cs.right.dot(&cs.up.cross(&cs.ahead)) < 0.0);
+            }
+        }
+
+        #[test]
+        fn test_default_handedness() {
+            let a = CoordinateSystem::default();
+            let cs = a.slerp(&a, 0.5);
+            assert!(cs.approx_eq(&a, 1e-5), "{cs:?} != {a:?}");
+        }
+    }
 }
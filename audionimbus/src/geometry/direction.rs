@@ -2,3 +2,104 @@ use super::Vector3;
 
 /// A direction in 3D space.
 pub type Direction = Vector3;
+
+#[cfg(feature = "std")]
+impl Vector3 {
+    /// Creates a unit vector from spherical coordinates, in radians.
+    ///
+    /// Follows Steam Audio's right-handed convention (+x right, +y up, -z ahead):
+    /// - `azimuth` is measured around the y-axis, starting ahead (-z) and increasing towards the
+    ///   left (-x). A source at `FRAC_PI_2` is to the left, and one at `-FRAC_PI_2` to the right.
+    /// - `elevation` is measured from the horizontal plane, positive upwards. A source at
+    ///   `FRAC_PI_2` is directly above.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// use std::f32::consts::FRAC_PI_4;
+    ///
+    /// // 45° to the left, 10° up.
+    /// let direction = Direction::from_spherical(FRAC_PI_4, 10f32.to_radians());
+    /// assert!(direction.x < 0.0 && direction.y > 0.0 && direction.z < 0.0);
+    /// ```
+    pub fn from_spherical(azimuth: f32, elevation: f32) -> Self {
+        let (sin_azimuth, cos_azimuth) = azimuth.sin_cos();
+        let (sin_elevation, cos_elevation) = elevation.sin_cos();
+
+        Self {
+            x: -sin_azimuth * cos_elevation,
+            y: sin_elevation,
+            z: -cos_azimuth * cos_elevation,
+        }
+    }
+
+    /// Returns the `(azimuth, elevation)` of this vector, in radians.
+    ///
+    /// This is the inverse of [`Self::from_spherical`], and uses the same convention.
+    /// The azimuth is in `[-PI, PI]` and the elevation in `[-FRAC_PI_2, FRAC_PI_2]`.
+    /// The vector does not need to be normalized; the zero vector yields `(0.0, 0.0)`.
+    pub fn to_spherical(&self) -> (f32, f32) {
+        let length = (self.x * self.x + self.y * self.y + self.z * self.z).sqrt();
+        if length == 0.0 {
+            return (0.0, 0.0);
+        }
+
+        let azimuth = (-self.x).atan2(-self.z);
+        let elevation = (self.y / length).clamp(-1.0, 1.0).asin();
+
+        (azimuth, elevation)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    mod from_spherical {
+        use super::*;
+
+        #[test]
+        fn test_axes() {
+            assert!(
+                Direction::from_spherical(0.0, 0.0).approx_eq(&Vector3::new(0.0, 0.0, -1.0), 1e-6)
+            );
+            assert!(
+                Direction::from_spherical(FRAC_PI_2, 0.0)
+                    .approx_eq(&Vector3::new(-1.0, 0.0, 0.0), 1e-6)
+            );
+            assert!(
+                Direction::from_spherical(-FRAC_PI_2, 0.0)
+                    .approx_eq(&Vector3::new(1.0, 0.0, 0.0), 1e-6)
+            );
+            assert!(
+                Direction::from_spherical(0.0, FRAC_PI_2)
+                    .approx_eq(&Vector3::new(0.0, 1.0, 0.0), 1e-6)
+            );
+        }
+    }
+
+    mod to_spherical {
+        use super::*;
+
+        #[test]
+        fn test_round_trip() {
+            let (azimuth, elevation) = Direction::from_spherical(FRAC_PI_4, 0.3).to_spherical();
+            assert!((azimuth - FRAC_PI_4).abs() < 1e-6);
+            assert!((elevation - 0.3).abs() < 1e-6);
+        }
+
+        #[test]
+        fn test_unnormalized() {
+            let (azimuth, elevation) = Vector3::new(2.0, 0.0, 0.0).to_spherical();
+            assert!((azimuth + FRAC_PI_2).abs() < 1e-6);
+            assert_eq!(elevation, 0.0);
+        }
+
+        #[test]
+        fn test_zero() {
+            assert_eq!(Vector3::default().to_spherical(), (0.0, 0.0));
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use super::{Material, Vector3};
 
-#[cfg(doc)]
+#[cfg(all(doc, feature = "std"))]
 use crate::ray_tracing::CustomRayTracer;
 
 /// Information about a ray’s intersection with 3D geometry.
@@ -29,6 +29,7 @@ pub struct Hit {
     pub material: Option<Material>,
 }
 
+#[cfg(feature = "std")]
 impl From<audionimbus_sys::IPLHit> for Hit {
     fn from(hit: audionimbus_sys::IPLHit) -> Self {
         Self {
@@ -19,6 +19,7 @@ use std::marker::PhantomData;
 pub struct InstancedMesh<T: RayTracer = DefaultRayTracer> {
     inner: audionimbus_sys::IPLInstancedMesh,
     pub(crate) sub_scene: Scene<T>,
+    pub(crate) transform: Matrix<f32, 4, 4>,
     _marker: PhantomData<T>,
 }
 
@@ -54,6 +55,7 @@ impl<T: RayTracer> InstancedMesh<T> {
         let instanced_mesh = Self {
             inner,
             sub_scene: settings.sub_scene.clone(),
+            transform: settings.transform,
             _marker: PhantomData,
         };
 
@@ -70,7 +72,11 @@ impl<T: RayTracer> InstancedMesh<T> {
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLInstancedMesh {
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLInstancedMesh {
         &mut self.inner
     }
 }
@@ -93,6 +99,7 @@ impl<T: RayTracer> Clone for InstancedMesh<T> {
         Self {
             inner: unsafe { audionimbus_sys::iplInstancedMeshRetain(self.inner) },
             sub_scene: self.sub_scene.clone(),
+            transform: self.transform,
             _marker: PhantomData,
         }
     }
@@ -97,8 +97,153 @@ impl Material {
         scattering: 0.05,
         transmission: [0.015, 0.002, 0.001],
     };
+
+    /// Creates a material, checking that every coefficient is between 0.0 and 1.0.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MaterialError`] for the first coefficient that is out of range or NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let material = Material::try_new([0.1, 0.2, 0.3], 0.05, [0.1, 0.05, 0.03])?;
+    /// assert_eq!(material, Material::GENERIC);
+    ///
+    /// assert_eq!(
+    ///     Material::try_new([0.1, 1.2, 0.3], 0.05, [0.1, 0.05, 0.03]),
+    ///     Err(MaterialError::AbsorptionOutOfRange {
+    ///         band: 1,
+    ///         value: 1.2
+    ///     })
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_new(
+        absorption: [f32; 3],
+        scattering: f32,
+        transmission: [f32; 3],
+    ) -> Result<Self, MaterialError> {
+        let material = Self {
+            absorption,
+            scattering,
+            transmission,
+        };
+        material.validate()?;
+
+        Ok(material)
+    }
+
+    /// Checks that every coefficient of this material is between 0.0 and 1.0.
+    ///
+    /// This is useful for materials built from struct literals or loaded from external data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MaterialError`] for the first coefficient that is out of range or NaN.
+    pub fn validate(&self) -> Result<(), MaterialError> {
+        let in_range = |value: f32| (0.0..=1.0).contains(&value);
+
+        if let Some(band) = self.absorption.iter().position(|&value| !in_range(value)) {
+            return Err(MaterialError::AbsorptionOutOfRange {
+                band,
+                value: self.absorption[band],
+            });
+        }
+
+        if !in_range(self.scattering) {
+            return Err(MaterialError::ScatteringOutOfRange {
+                value: self.scattering,
+            });
+        }
+
+        if let Some(band) = self.transmission.iter().position(|&value| !in_range(value)) {
+            return Err(MaterialError::TransmissionOutOfRange {
+                band,
+                value: self.transmission[band],
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Linearly interpolates between this material and `other`.
+    ///
+    /// Each absorption, scattering and transmission coefficient is interpolated independently.
+    /// `t` is clamped to `[0.0, 1.0]`: 0.0 returns `self` and 1.0 returns `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// // A wall that is mostly brick, with a window.
+    /// let wall = Material::BRICK.lerp(&Material::GLASS, 0.25);
+    /// assert_eq!(wall.scattering, 0.05);
+    /// ```
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let lerp = |a: f32, b: f32| a * (1.0 - t) + b * t;
+
+        Self {
+            absorption: core::array::from_fn(|band| {
+                lerp(self.absorption[band], other.absorption[band])
+            }),
+            scattering: lerp(self.scattering, other.scattering),
+            transmission: core::array::from_fn(|band| {
+                lerp(self.transmission[band], other.transmission[band])
+            }),
+        }
+    }
 }
 
+/// Error returned when a [`Material`] coefficient is outside `[0.0, 1.0]`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum MaterialError {
+    /// An absorption coefficient is out of range.
+    AbsorptionOutOfRange {
+        /// Index of the frequency band (0 for low, 1 for middle, 2 for high).
+        band: usize,
+        /// The invalid coefficient.
+        value: f32,
+    },
+
+    /// The scattering coefficient is out of range.
+    ScatteringOutOfRange {
+        /// The invalid coefficient.
+        value: f32,
+    },
+
+    /// A transmission coefficient is out of range.
+    TransmissionOutOfRange {
+        /// Index of the frequency band (0 for low, 1 for middle, 2 for high).
+        band: usize,
+        /// The invalid coefficient.
+        value: f32,
+    },
+}
+
+impl core::error::Error for MaterialError {}
+
+impl core::fmt::Display for MaterialError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::AbsorptionOutOfRange { band, value } => write!(
+                f,
+                "absorption coefficient {value} for band {band} is outside [0.0, 1.0]"
+            ),
+            Self::ScatteringOutOfRange { value } => {
+                write!(f, "scattering coefficient {value} is outside [0.0, 1.0]")
+            }
+            Self::TransmissionOutOfRange { band, value } => write!(
+                f,
+                "transmission coefficient {value} for band {band} is outside [0.0, 1.0]"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
 impl From<Material> for audionimbus_sys::IPLMaterial {
     fn from(material: Material) -> Self {
         Self {
@@ -109,6 +254,7 @@ impl From<Material> for audionimbus_sys::IPLMaterial {
     }
 }
 
+#[cfg(feature = "std")]
 impl From<&audionimbus_sys::IPLMaterial> for Material {
     fn from(material: &audionimbus_sys::IPLMaterial) -> Self {
         Self {
@@ -170,4 +316,85 @@ mod tests {
             }
         }
     }
+
+    mod try_new {
+        use super::*;
+
+        #[test]
+        fn test_valid() {
+            assert_eq!(
+                Material::try_new([0.0, 0.5, 1.0], 1.0, [1.0, 0.0, 0.5]),
+                Ok(Material {
+                    absorption: [0.0, 0.5, 1.0],
+                    scattering: 1.0,
+                    transmission: [1.0, 0.0, 0.5],
+                })
+            );
+        }
+
+        #[test]
+        fn test_out_of_range() {
+            assert_eq!(
+                Material::try_new([0.1, 0.2, -0.3], 0.05, [0.1, 0.1, 0.1]),
+                Err(MaterialError::AbsorptionOutOfRange {
+                    band: 2,
+                    value: -0.3
+                })
+            );
+            assert_eq!(
+                Material::try_new([0.1, 0.2, 0.3], 1.5, [0.1, 0.1, 0.1]),
+                Err(MaterialError::ScatteringOutOfRange { value: 1.5 })
+            );
+            assert_eq!(
+                Material::try_new([0.1, 0.2, 0.3], 0.05, [2.0, 0.1, 0.1]),
+                Err(MaterialError::TransmissionOutOfRange {
+                    band: 0,
+                    value: 2.0
+                })
+            );
+        }
+
+        #[test]
+        fn test_nan() {
+            assert!(Material::try_new([0.1, 0.2, 0.3], f32::NAN, [0.1, 0.1, 0.1]).is_err());
+        }
+    }
+
+    mod lerp {
+        use super::*;
+
+        #[test]
+        fn test_endpoints() {
+            assert_eq!(Material::BRICK.lerp(&Material::GLASS, 0.0), Material::BRICK);
+            assert_eq!(Material::BRICK.lerp(&Material::GLASS, 1.0), Material::GLASS);
+        }
+
+        #[test]
+        fn test_midpoint() {
+            let a = Material {
+                absorption: [0.0, 0.25, 0.5],
+                scattering: 0.0,
+                transmission: [1.0, 0.5, 0.0],
+            };
+            let b = Material {
+                absorption: [1.0, 0.75, 0.5],
+                scattering: 1.0,
+                transmission: [0.0, 0.5, 1.0],
+            };
+
+            let m = a.lerp(&b, 0.5);
+            assert_eq!(m.absorption, [0.5, 0.5, 0.5]);
+            assert_eq!(m.scattering, 0.5);
+            assert_eq!(m.transmission, [0.5, 0.5, 0.5]);
+        }
+
+        #[test]
+        fn test_clamps_t() {
+            assert_eq!(
+                Material::BRICK.lerp(&Material::GLASS, -1.0),
+                Material::BRICK
+            );
+            assert_eq!(Material::BRICK.lerp(&Material::GLASS, 2.0), Material::GLASS);
+        }
+    }
 }
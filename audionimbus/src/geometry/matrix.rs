@@ -44,6 +44,7 @@ impl<T, const ROWS: usize, const COLS: usize> Matrix<T, ROWS, COLS> {
     }
 }
 
+#[cfg(feature = "std")]
 impl From<Matrix<f32, 4, 4>> for audionimbus_sys::IPLMatrix4x4 {
     fn from(matrix: Matrix<f32, 4, 4>) -> Self {
         Self {
@@ -52,6 +53,7 @@ impl From<Matrix<f32, 4, 4>> for audionimbus_sys::IPLMatrix4x4 {
     }
 }
 
+#[cfg(feature = "std")]
 impl From<&Matrix<f32, 4, 4>> for audionimbus_sys::IPLMatrix4x4 {
     fn from(matrix: &Matrix<f32, 4, 4>) -> Self {
         Self {
@@ -8,6 +8,8 @@
 //! # Building a Scene
 //!
 //! ```
+//! # #[cfg(feature = "std")]
+//! # fn main() -> Result<(), audionimbus::SteamAudioError> {
 //! use audionimbus::*;
 //!
 //! let context = Context::default();
@@ -36,7 +38,11 @@
 //! )?;
 //! scene.add_static_mesh(mesh);
 //! scene.commit();
-//! # Ok::<(), SteamAudioError>(())
+//! # Ok(())
+//! # }
+//! # // The example relies on the `std` feature.
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
 //! ```
 //!
 //! # Dynamic Geometry
@@ -44,6 +50,8 @@
 //! Use [`InstancedMesh`] for moving objects:
 //!
 //! ```
+//! # #[cfg(feature = "std")]
+//! # fn main() -> Result<(), audionimbus::SteamAudioError> {
 //! # use audionimbus::*;
 //! # let context = Context::default();
 //! # let mut scene = Scene::try_new(&context)?;
@@ -63,7 +71,11 @@
 //!     },
 //! )?;
 //! scene.add_instanced_mesh(instanced);
-//! # Ok::<(), SteamAudioError>(())
+//! # Ok(())
+//! # }
+//! # // The example relies on the `std` feature.
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
 //! ```
 
 mod vector3;
@@ -88,21 +100,27 @@ mod triangle;
 pub use triangle::Triangle;
 
 mod material;
-pub use material::Material;
+pub use material::{Material, MaterialError};
 
 mod ray;
 pub use ray::Ray;
 
+#[cfg(feature = "std")]
 mod scene;
+#[cfg(feature = "std")]
 pub use scene::{
-    InstancedMeshHandle, SaveableAsObj, SaveableAsSerialized, Scene, StaticMeshHandle,
+    CommitStats, InstancedMeshHandle, SaveableAsObj, SaveableAsSerialized, Scene, StaticMeshHandle,
     relative_direction,
 };
 
+#[cfg(feature = "std")]
 mod static_mesh;
-pub use static_mesh::{StaticMesh, StaticMeshSettings};
+#[cfg(feature = "std")]
+pub use static_mesh::{StaticMesh, StaticMeshError, StaticMeshSettings};
 
+#[cfg(feature = "std")]
 mod instanced_mesh;
+#[cfg(feature = "std")]
 pub use instanced_mesh::{InstancedMesh, InstancedMeshSettings};
 
 mod sphere;
@@ -10,6 +10,7 @@ pub struct Ray {
     pub direction: Vector3,
 }
 
+#[cfg(feature = "std")]
 impl From<Ray> for audionimbus_sys::IPLRay {
     fn from(ray: Ray) -> Self {
         Self {
@@ -19,6 +20,7 @@ impl From<Ray> for audionimbus_sys::IPLRay {
     }
 }
 
+#[cfg(feature = "std")]
 impl From<audionimbus_sys::IPLRay> for Ray {
     fn from(ray: audionimbus_sys::IPLRay) -> Self {
         Self {
@@ -4,16 +4,47 @@ use crate::context::Context;
 use crate::device::embree::EmbreeDevice;
 use crate::device::radeon_rays::RadeonRaysDevice;
 use crate::error::{SteamAudioError, to_option_error};
+use crate::geometry::static_mesh::vertex_bounds;
 use crate::geometry::{Direction, InstancedMesh, Matrix, Point, StaticMesh};
 use crate::ray_tracing::{
-    CustomCallbackUserData, CustomRayTracer, DefaultRayTracer, Embree, RadeonRays, RayTracer,
+    CustomCallbackUserData, CustomRayTracer, CustomRayTracing, DefaultRayTracer, Embree,
+    RadeonRays, RayTracer,
 };
-use crate::serialized_object::SerializedObject;
+use crate::serialized_object::{SerializedObject, SteamAudioSerialize};
 use slotmap::{DefaultKey, SlotMap};
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Statistics about a call to [`Scene::commit`].
+///
+/// Steam Audio rebuilds the acceleration structure of a scene when meshes are added to or removed
+/// from it, which is expensive for large scenes.
+/// When only the transforms of instanced meshes change, it only needs to refit it, which is much
+/// cheaper.
+/// These statistics can be used to budget commits, for example by spreading mesh additions and
+/// removals across frames.
+///
+/// Steam Audio does not report what a commit did, so [`Self::likely_rebuilt`] and
+/// [`Self::likely_refitted`] are estimates: they are inferred from the changes made through this
+/// crate since the previous commit, and may not match what Steam Audio actually did.
+/// Changes are tracked per scene: committing a scene does not report changes made to its
+/// sub-scenes, which are committed separately.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CommitStats {
+    /// Estimates whether the commit rebuilt the scene, because meshes were added or removed since
+    /// the previous commit.
+    pub likely_rebuilt: bool,
+
+    /// Estimates whether the commit only refitted the scene, because only instanced mesh
+    /// transforms were updated since the previous commit.
+    pub likely_refitted: bool,
+
+    /// Time spent committing the scene in Steam Audio.
+    pub duration: Duration,
+}
 
 /// Marker trait for scenes that can use `save()`.
 pub trait SaveableAsSerialized: Sealed {}
@@ -66,6 +97,12 @@ pub(crate) struct SceneShared<T: RayTracer> {
     /// Instanced meshes to be dropped by the next call to [`Self::commit`].
     instanced_meshes_to_remove: Vec<InstancedMesh<T>>,
 
+    /// Whether meshes were added or removed since the last call to [`Scene::commit`].
+    meshes_changed: bool,
+
+    /// Whether instanced mesh transforms were updated since the last call to [`Scene::commit`].
+    transforms_changed: bool,
+
     /// Simulator registrations that currently require this scene to block commits.
     simulation_registrations: HashMap<audionimbus_sys::IPLSimulator, SceneSimulationRegistration>,
 
@@ -84,6 +121,8 @@ impl<T: RayTracer> SceneShared<T> {
             instanced_meshes: SlotMap::new(),
             static_meshes_to_remove: Vec::new(),
             instanced_meshes_to_remove: Vec::new(),
+            meshes_changed: false,
+            transforms_changed: false,
             simulation_registrations: HashMap::new(),
             _device: device,
             _callback_user_data: callback_user_data,
@@ -498,6 +537,24 @@ impl Scene<CustomRayTracer> {
         )
     }
 
+    /// Creates a new scene that traces rays with `ray_tracing`, and returns a handle to it.
+    ///
+    /// This is equivalent to [`Self::try_with_custom`] with
+    /// [`CustomRayTracingCallbacks::from_ray_tracing`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SteamAudioError`] if creation fails.
+    pub fn try_with_custom_ray_tracing<R: CustomRayTracing>(
+        context: &Context,
+        ray_tracing: R,
+    ) -> Result<Self, SteamAudioError> {
+        Self::try_with_custom(
+            context,
+            CustomRayTracingCallbacks::from_ray_tracing(ray_tracing),
+        )
+    }
+
     /// Loads a scene from a serialized object using a custom ray tracer and returns a handle to it.
     ///
     /// Typically, the serialized object will be created from a byte array loaded from disk or over the network.
@@ -585,12 +642,9 @@ impl<T: RayTracer> Scene<T> {
             audionimbus_sys::iplStaticMeshAdd(static_mesh.raw_ptr(), self.raw_ptr());
         }
 
-        let key = self
-            .shared
-            .lock()
-            .unwrap()
-            .static_meshes
-            .insert(static_mesh);
+        let mut shared = self.shared.lock().unwrap();
+        let key = shared.static_meshes.insert(static_mesh);
+        shared.meshes_changed = true;
 
         StaticMeshHandle(key)
     }
@@ -641,10 +695,71 @@ impl<T: RayTracer> Scene<T> {
         }
 
         shared.static_meshes_to_remove.push(static_mesh);
+        shared.meshes_changed = true;
 
         true
     }
 
+    /// Removes all static meshes from a scene, e.g. before rebuilding its geometry.
+    ///
+    /// Handles to the removed meshes become invalid.
+    ///
+    /// After calling this function, [`Self::commit`] must be called for the changes to take effect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// # let context = Context::default();
+    /// # let mut scene = Scene::try_new(&context)?;
+    /// # let vertices = vec![Point::new(0.0, 0.0, 0.0)];
+    /// # let triangles = vec![Triangle::new(0, 1, 2)];
+    /// # let materials = vec![Material::default()];
+    /// # let material_indices = vec![0];
+    /// # let settings = StaticMeshSettings {
+    /// #     vertices: &vertices,
+    /// #     triangles: &triangles,
+    /// #     material_indices: &material_indices,
+    /// #     materials: &materials,
+    /// # };
+    /// for _ in 0..3 {
+    ///     let _ = scene.add_static_mesh(StaticMesh::try_new(&scene, &settings)?);
+    /// }
+    /// scene.commit();
+    /// assert_eq!(scene.num_static_meshes(), 3);
+    ///
+    /// scene.clear_static_meshes();
+    /// scene.commit();
+    /// assert_eq!(scene.num_static_meshes(), 0);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn clear_static_meshes(&mut self) {
+        let mut shared = self.shared.lock().unwrap();
+
+        let static_meshes = shared
+            .static_meshes
+            .drain()
+            .map(|(_, static_mesh)| static_mesh)
+            .collect::<Vec<_>>();
+
+        for static_mesh in &static_meshes {
+            unsafe {
+                audionimbus_sys::iplStaticMeshRemove(static_mesh.raw_ptr(), self.raw_ptr());
+            }
+        }
+
+        shared.meshes_changed |= !static_meshes.is_empty();
+        shared.static_meshes_to_remove.extend(static_meshes);
+    }
+
+    /// Returns the number of static meshes added to the scene and not removed since.
+    ///
+    /// Additions and removals are counted as soon as they are made, even before they are
+    /// committed with [`Self::commit`].
+    pub fn num_static_meshes(&self) -> usize {
+        self.shared.lock().unwrap().static_meshes.len()
+    }
+
     /// Adds an instanced mesh to a scene and returns a handle to it.
     ///
     /// After calling this function, [`Self::commit`] must be called for the changes to take effect.
@@ -697,6 +812,7 @@ impl<T: RayTracer> Scene<T> {
         let (key, registrations) = {
             let mut shared = self.shared.lock().unwrap();
             let key = shared.instanced_meshes.insert(instanced_mesh);
+            shared.meshes_changed = true;
             (key, shared.registration_snapshots())
         };
 
@@ -764,6 +880,7 @@ impl<T: RayTracer> Scene<T> {
         }
 
         shared.instanced_meshes_to_remove.push(instanced_mesh);
+        shared.meshes_changed = true;
 
         true
     }
@@ -826,10 +943,11 @@ impl<T: RayTracer> Scene<T> {
         handle: InstancedMeshHandle,
         transform: Matrix<f32, 4, 4>,
     ) -> bool {
-        let shared = self.shared.lock().unwrap();
-        let Some(instanced_mesh) = shared.instanced_meshes.get(handle.0) else {
+        let mut shared = self.shared.lock().unwrap();
+        let Some(instanced_mesh) = shared.instanced_meshes.get_mut(handle.0) else {
             return false;
         };
+        instanced_mesh.transform = transform;
 
         unsafe {
             audionimbus_sys::iplInstancedMeshUpdateTransform(
@@ -838,15 +956,89 @@ impl<T: RayTracer> Scene<T> {
                 transform.into(),
             );
         }
+        shared.transforms_changed = true;
 
         true
     }
 
+    /// Returns the minimum and maximum corners of the axis-aligned box enclosing the scene's
+    /// geometry, or `None` if the scene has no known geometry.
+    ///
+    /// Steam Audio does not expose scene bounds, so they are computed from the meshes added to the
+    /// scene and not removed since, including changes that have not been committed yet.
+    /// Instanced meshes contribute the bounds of their sub-scene, with their latest transform
+    /// applied.
+    /// Static meshes loaded from a serialized object, and the contents of scenes loaded with
+    /// [`Self::load`], are not taken into account since their geometry is unknown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// # let context = Context::default();
+    /// let mut scene = Scene::try_new(&context)?;
+    /// assert_eq!(scene.bounds(), None);
+    ///
+    /// let vertices = vec![
+    ///     Point::new(-1.0, 0.0, 0.0),
+    ///     Point::new(1.0, 0.0, 0.0),
+    ///     Point::new(0.0, 2.0, 3.0),
+    /// ];
+    /// let static_mesh = StaticMesh::try_new(
+    ///     &scene,
+    ///     &StaticMeshSettings {
+    ///         vertices: &vertices,
+    ///         triangles: &[Triangle::new(0, 1, 2)],
+    ///         material_indices: &[0],
+    ///         materials: &[Material::default()],
+    ///     },
+    /// )?;
+    /// scene.add_static_mesh(static_mesh);
+    /// scene.commit();
+    ///
+    /// assert_eq!(
+    ///     scene.bounds(),
+    ///     Some((Point::new(-1.0, 0.0, 0.0), Point::new(1.0, 2.0, 3.0)))
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let shared = self.shared.lock().unwrap();
+
+        let static_corners = shared
+            .static_meshes
+            .values()
+            .filter_map(StaticMesh::bounds)
+            .flat_map(|(min, max)| [min, max]);
+
+        let instanced_corners = shared
+            .instanced_meshes
+            .values()
+            .filter_map(|instanced_mesh| {
+                let bounds = instanced_mesh.sub_scene.bounds()?;
+                Some((instanced_mesh.transform, bounds))
+            })
+            .flat_map(|(transform, (min, max))| {
+                (0..8).map(move |i| {
+                    let corner = Point::new(
+                        if i & 1 == 0 { min.x } else { max.x },
+                        if i & 2 == 0 { min.y } else { max.y },
+                        if i & 4 == 0 { min.z } else { max.z },
+                    );
+                    transform_point(&transform, corner)
+                })
+            });
+
+        let corners = static_corners.chain(instanced_corners).collect::<Vec<_>>();
+        vertex_bounds(&corners)
+    }
+
     /// Commits any changes to the scene.
     ///
     /// This function should be called after any calls to the following functions, for the changes to take effect:
     /// - [`Self::add_static_mesh`]
     /// - [`Self::remove_static_mesh`]
+    /// - [`Self::clear_static_meshes`]
     /// - [`Self::add_instanced_mesh`]
     /// - [`Self::remove_instanced_mesh`]
     /// - [`Self::update_instanced_mesh_transform`]
@@ -877,10 +1069,15 @@ impl<T: RayTracer> Scene<T> {
     /// )?;
     ///
     /// let handle = scene.add_static_mesh(static_mesh);
-    /// scene.commit();
+    /// let stats = scene.commit();
+    /// assert!(stats.likely_rebuilt);
+    ///
+    /// // Nothing changed since the last commit.
+    /// let stats = scene.commit();
+    /// assert!(!stats.likely_rebuilt && !stats.likely_refitted);
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn commit(&self) {
+    pub fn commit(&self) -> CommitStats {
         let locks = {
             let shared = self.shared.lock().unwrap();
             shared.simulation_locks()
@@ -891,9 +1088,18 @@ impl<T: RayTracer> Scene<T> {
             .map(|lock| lock.lock().unwrap())
             .collect::<Vec<_>>();
 
+        let (likely_rebuilt, likely_refitted) = {
+            let mut shared = self.shared.lock().unwrap();
+            let meshes_changed = std::mem::take(&mut shared.meshes_changed);
+            let transforms_changed = std::mem::take(&mut shared.transforms_changed);
+            (meshes_changed, transforms_changed && !meshes_changed)
+        };
+
+        let start = Instant::now();
         unsafe {
             audionimbus_sys::iplSceneCommit(self.raw_ptr());
         }
+        let duration = start.elapsed();
 
         let (removed_sub_scenes, registrations) = {
             let mut shared = self.shared.lock().unwrap();
@@ -913,6 +1119,12 @@ impl<T: RayTracer> Scene<T> {
                 removed_sub_scene.unregister_simulator(*simulator, registration.ref_count);
             }
         }
+
+        CommitStats {
+            likely_rebuilt,
+            likely_refitted,
+            duration,
+        }
     }
 
     /// Returns the raw FFI pointer to the underlying scene.
@@ -925,7 +1137,11 @@ impl<T: RayTracer> Scene<T> {
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLScene {
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLScene {
         &mut self.inner
     }
 }
@@ -936,14 +1152,23 @@ impl<T: RayTracer + SaveableAsSerialized> Scene<T> {
     /// Typically, the serialized object will then be saved to disk.
     ///
     /// This function can only be called on a scene created with the `DefaultRayTracer` ray tracer.
-    pub fn save(&self) -> SerializedObject {
-        let serialized_object = SerializedObject(std::ptr::null_mut());
-
+    pub fn save(&self, serialized_object: &mut SerializedObject) {
         unsafe {
             audionimbus_sys::iplSceneSave(self.raw_ptr(), serialized_object.raw_ptr());
         }
+    }
+}
+
+impl SteamAudioSerialize for Scene<DefaultRayTracer> {
+    fn serialize(&self, serialized_object: &mut SerializedObject) {
+        self.save(serialized_object);
+    }
 
-        serialized_object
+    fn deserialize(
+        context: &Context,
+        serialized_object: &SerializedObject,
+    ) -> Result<Self, SteamAudioError> {
+        Self::load(context, serialized_object)
     }
 }
 
@@ -1001,6 +1226,16 @@ impl<T: RayTracer> Hash for Scene<T> {
     }
 }
 
+/// Applies an affine `transform` to `point`.
+fn transform_point(transform: &Matrix<f32, 4, 4>, point: Point) -> Point {
+    let [x, y, z] = [0, 1, 2].map(|row| {
+        let [m0, m1, m2, m3] = transform.elements[row];
+        m0 * point.x + m1 * point.y + m2 * point.z + m3
+    });
+
+    Point::new(x, y, z)
+}
+
 /// Calculates the relative direction from the listener to a sound source.
 ///
 /// The returned direction vector is expressed in the listener’s coordinate system.
@@ -1130,8 +1365,8 @@ mod tests {
     use super::*;
     use crate::{
         AnyHitCallback, AudioSettings, BatchedAnyHitCallback, BatchedClosestHitCallback,
-        ClosestHitCallback, CustomRayTracingCallbacks, Direct, DirectSimulationSettings,
-        InstancedMesh, InstancedMeshSettings, Matrix4, SimulationSettings, Simulator, Vector3,
+        ClosestHitCallback, CustomRayTracingCallbacks, Direct, DirectSimulationSettings, Hit,
+        InstancedMesh, InstancedMeshSettings, Matrix4, Ray, SimulationSettings, Simulator, Vector3,
     };
 
     fn registration_ref_count<D, R, P, RE>(
@@ -1210,6 +1445,29 @@ mod tests {
         assert!(Scene::<CustomRayTracer>::try_with_custom(&context, callbacks).is_ok());
     }
 
+    #[test]
+    fn test_custom_ray_tracing() {
+        struct Empty;
+
+        impl CustomRayTracing for Empty {
+            fn closest_hit(
+                &self,
+                _ray: Ray,
+                _min_distance: f32,
+                _max_distance: f32,
+            ) -> Option<Hit> {
+                None
+            }
+
+            fn any_hit(&self, _ray: Ray, _min_distance: f32, _max_distance: f32) -> bool {
+                false
+            }
+        }
+
+        let context = Context::default();
+        assert!(Scene::try_with_custom_ray_tracing(&context, Empty).is_ok());
+    }
+
     #[test]
     fn test_scene_clone() {
         let context = Context::default();
@@ -1220,6 +1478,44 @@ mod tests {
         assert!(!clone.raw_ptr().is_null());
     }
 
+    #[test]
+    fn test_commit_stats() {
+        let context = Context::default();
+        let sub_scene = Scene::<DefaultRayTracer>::try_new(&context).unwrap();
+        let mut scene = Scene::<DefaultRayTracer>::try_new(&context).unwrap();
+
+        assert!(!scene.commit().likely_rebuilt);
+
+        let instanced_mesh = InstancedMesh::try_new(
+            &scene,
+            &InstancedMeshSettings {
+                sub_scene,
+                transform: Matrix4::IDENTITY,
+            },
+        )
+        .unwrap();
+        let handle = scene.add_instanced_mesh(instanced_mesh);
+        let stats = scene.commit();
+        assert!(stats.likely_rebuilt);
+        assert!(!stats.likely_refitted);
+
+        assert!(scene.update_instanced_mesh_transform(handle, Matrix4::IDENTITY));
+        let stats = scene.commit();
+        assert!(!stats.likely_rebuilt);
+        assert!(stats.likely_refitted);
+
+        let stats = scene.commit();
+        assert!(!stats.likely_rebuilt);
+        assert!(!stats.likely_refitted);
+
+        // A transform update is subsumed by a rebuild.
+        assert!(scene.update_instanced_mesh_transform(handle, Matrix4::IDENTITY));
+        assert!(scene.remove_instanced_mesh(handle));
+        let stats = scene.commit();
+        assert!(stats.likely_rebuilt);
+        assert!(!stats.likely_refitted);
+    }
+
     #[test]
     fn test_add_instanced_mesh_propagates_existing_simulator_registration() {
         let context = Context::default();
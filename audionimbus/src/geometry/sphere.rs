@@ -11,6 +11,7 @@ pub struct Sphere {
     pub radius: f32,
 }
 
+#[cfg(feature = "std")]
 impl From<Sphere> for audionimbus_sys::IPLSphere {
     fn from(sphere: Sphere) -> Self {
         Self {
@@ -20,6 +21,7 @@ impl From<Sphere> for audionimbus_sys::IPLSphere {
     }
 }
 
+#[cfg(feature = "std")]
 impl From<audionimbus_sys::IPLSphere> for Sphere {
     fn from(ipl_sphere: audionimbus_sys::IPLSphere) -> Self {
         Self {
@@ -5,6 +5,7 @@ use crate::ray_tracing::{DefaultRayTracer, RayTracer};
 use crate::serialized_object::SerializedObject;
 use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 /// A triangle mesh that doesn’t move or deform in any way.
 ///
@@ -18,9 +19,20 @@ use std::marker::PhantomData;
 /// Cloning it is cheap; it produces a new handle pointing to the same underlying object, while
 /// incrementing a reference count.
 /// The underlying object is destroyed when all handles are dropped.
-#[derive(Debug, PartialEq, Eq)]
+///
+/// Steam Audio meshes are immutable once created.
+/// To change the materials of a mesh, create it with [`Self::try_new_rebuildable`], then use
+/// [`Self::rebuild_with_materials`] to create a copy of it with the new materials, and swap it
+/// into the scene in place of the original.
+#[derive(Debug)]
 pub struct StaticMesh<T> {
     inner: audionimbus_sys::IPLStaticMesh,
+    bounds: Option<(Point, Point)>,
+
+    /// Geometry the mesh was created with, kept to rebuild it with different materials.
+    /// `None` unless the mesh was created with [`Self::try_new_rebuildable`].
+    geometry: Option<Arc<StaticMeshGeometry>>,
+
     _marker: PhantomData<T>,
 }
 
@@ -84,12 +96,96 @@ impl<T: RayTracer> StaticMesh<T> {
 
         let static_mesh = Self {
             inner,
+            bounds: vertex_bounds(settings.vertices),
+            geometry: None,
             _marker: PhantomData,
         };
 
         Ok(static_mesh)
     }
 
+    /// Creates a new static mesh that keeps a copy of its geometry, so that it can be rebuilt
+    /// with different materials using [`Self::rebuild_with_materials`].
+    ///
+    /// The copy holds the vertices, triangles and material indices of the mesh, and is shared by
+    /// its clones and the meshes rebuilt from it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SteamAudioError`] if creation fails.
+    pub fn try_new_rebuildable(
+        scene: &Scene<T>,
+        settings: &StaticMeshSettings,
+    ) -> Result<Self, SteamAudioError> {
+        let geometry = StaticMeshGeometry {
+            vertices: settings.vertices.to_vec(),
+            triangles: settings.triangles.to_vec(),
+            material_indices: settings.material_indices.to_vec(),
+        };
+
+        let mut static_mesh = Self::try_new(scene, settings)?;
+        static_mesh.geometry = Some(Arc::new(geometry));
+
+        Ok(static_mesh)
+    }
+
+    /// Creates a static mesh from a heightfield sampled on a regular grid, such as terrain.
+    ///
+    /// `heights` contains `width * depth` samples in row-major order: the sample at column `x`
+    /// and row `z` is `heights[z * width + x]`, and is placed at
+    /// `(x * cell_size, heights[z * width + x], z * cell_size)`.
+    /// Each grid cell is split into two triangles sharing the cell's vertices, wound so that their
+    /// normals point upwards (+y). The whole mesh uses `material`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SteamAudioError`] if creation fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `depth` is less than 2, or if `heights` does not contain exactly
+    /// `width * depth` samples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// # let context = Context::default();
+    /// let mut scene = Scene::try_new(&context)?;
+    ///
+    /// // A 3x3 grid of samples with a bump in the middle, 10 meters apart.
+    /// let heights = [
+    ///     0.0, 0.0, 0.0,
+    ///     0.0, 2.0, 0.0,
+    ///     0.0, 0.0, 0.0,
+    /// ];
+    /// let terrain = StaticMesh::from_heightfield(&scene, &heights, 3, 3, 10.0, Material::GRAVEL)?;
+    /// scene.add_static_mesh(terrain);
+    /// scene.commit();
+    /// # Ok::<(), SteamAudioError>(())
+    /// ```
+    pub fn from_heightfield(
+        scene: &Scene<T>,
+        heights: &[f32],
+        width: usize,
+        depth: usize,
+        cell_size: f32,
+        material: Material,
+    ) -> Result<Self, SteamAudioError> {
+        let (vertices, triangles) = heightfield_geometry(heights, width, depth, cell_size);
+        let material_indices = vec![0; triangles.len()];
+
+        Self::try_new(
+            scene,
+            &StaticMeshSettings {
+                vertices: &vertices,
+                triangles: &triangles,
+                material_indices: &material_indices,
+                materials: &[material],
+            },
+        )
+    }
+
     /// Loads a static mesh from a serialized object.
     ///
     /// Typically, the serialized object will be created from a byte array loaded from disk or over the network.
@@ -159,12 +255,129 @@ impl<T: RayTracer> StaticMesh<T> {
 
         let static_mesh = Self {
             inner,
+            bounds: None,
+            geometry: None,
             _marker: PhantomData,
         };
 
         Ok(static_mesh)
     }
 
+    /// Creates a new static mesh with the same geometry as this one, but with different
+    /// materials.
+    ///
+    /// Each triangle keeps its material index, which now refers to `materials`.
+    /// If `material_indices` is given, it replaces the material index of each triangle instead.
+    ///
+    /// The returned mesh is not added to any scene; remove this mesh from the scene, add the
+    /// returned one, and commit the scene for the change to take effect.
+    /// It can itself be rebuilt again.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// - [`StaticMeshError::GeometryUnavailable`] if the mesh was not created with
+    ///   [`Self::try_new_rebuildable`].
+    /// - [`StaticMeshError::MaterialIndicesLengthMismatch`] if `material_indices` does not contain
+    ///   one index per triangle.
+    /// - [`StaticMeshError::MaterialIndexOutOfBounds`] if a triangle refers to a material that
+    ///   `materials` does not contain.
+    /// - [`StaticMeshError::SteamAudio`] if creating the new mesh fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// # let context = Context::default();
+    /// let mut scene = Scene::try_new(&context)?;
+    ///
+    /// let vertices = [
+    ///     Point::new(0.0, 0.0, 0.0),
+    ///     Point::new(10.0, 0.0, 0.0),
+    ///     Point::new(10.0, 10.0, 0.0),
+    /// ];
+    /// let wall = StaticMesh::try_new_rebuildable(
+    ///     &scene,
+    ///     &StaticMeshSettings {
+    ///         vertices: &vertices,
+    ///         triangles: &[Triangle::new(0, 1, 2)],
+    ///         material_indices: &[0],
+    ///         materials: &[Material::BRICK],
+    ///     },
+    /// )?;
+    /// let handle = scene.add_static_mesh(wall.clone());
+    /// scene.commit();
+    ///
+    /// // Make the wall more absorptive.
+    /// let wall = wall.rebuild_with_materials(&scene, &[Material::CARPET], None)?;
+    /// scene.remove_static_mesh(handle);
+    /// scene.add_static_mesh(wall);
+    /// scene.commit();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn rebuild_with_materials(
+        &self,
+        scene: &Scene<T>,
+        materials: &[Material],
+        material_indices: Option<&[usize]>,
+    ) -> Result<Self, StaticMeshError> {
+        let geometry = self
+            .geometry
+            .as_ref()
+            .ok_or(StaticMeshError::GeometryUnavailable)?;
+
+        let material_indices = material_indices.unwrap_or(&geometry.material_indices);
+        if material_indices.len() != geometry.triangles.len() {
+            return Err(StaticMeshError::MaterialIndicesLengthMismatch {
+                num_material_indices: material_indices.len(),
+                num_triangles: geometry.triangles.len(),
+            });
+        }
+
+        if let Some((triangle_index, &material_index)) = material_indices
+            .iter()
+            .enumerate()
+            .find(|&(_, &material_index)| material_index >= materials.len())
+        {
+            return Err(StaticMeshError::MaterialIndexOutOfBounds {
+                triangle_index,
+                material_index,
+                num_materials: materials.len(),
+            });
+        }
+
+        let mut static_mesh = Self::try_new(
+            scene,
+            &StaticMeshSettings {
+                vertices: &geometry.vertices,
+                triangles: &geometry.triangles,
+                material_indices,
+                materials,
+            },
+        )?;
+
+        static_mesh.geometry = Some(if material_indices == geometry.material_indices {
+            Arc::clone(geometry)
+        } else {
+            Arc::new(StaticMeshGeometry {
+                vertices: geometry.vertices.clone(),
+                triangles: geometry.triangles.clone(),
+                material_indices: material_indices.to_vec(),
+            })
+        });
+
+        Ok(static_mesh)
+    }
+
+    /// Returns the minimum and maximum corners of the axis-aligned box enclosing the mesh's
+    /// vertices.
+    ///
+    /// Returns `None` if the mesh has no vertices, or if it was loaded from a serialized object,
+    /// since Steam Audio does not expose the geometry of loaded meshes.
+    pub const fn bounds(&self) -> Option<(Point, Point)> {
+        self.bounds
+    }
+
     /// Returns the raw FFI pointer to the underlying static mesh.
     ///
     /// This is intended for internal use and advanced scenarios.
@@ -175,7 +388,11 @@ impl<T: RayTracer> StaticMesh<T> {
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLStaticMesh {
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLStaticMesh {
         &mut self.inner
     }
 }
@@ -210,11 +427,21 @@ impl<T: RayTracer> Clone for StaticMesh<T> {
         // SAFETY: The static mesh will not be destroyed until all references are released.
         Self {
             inner: unsafe { audionimbus_sys::iplStaticMeshRetain(self.inner) },
+            bounds: self.bounds,
+            geometry: self.geometry.clone(),
             _marker: PhantomData,
         }
     }
 }
 
+impl<T: RayTracer> PartialEq for StaticMesh<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw_ptr() == other.raw_ptr()
+    }
+}
+
+impl<T: RayTracer> Eq for StaticMesh<T> {}
+
 impl<T: RayTracer> Hash for StaticMesh<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         std::ptr::hash(self.raw_ptr(), state);
@@ -237,6 +464,141 @@ pub struct StaticMeshSettings<'a> {
     pub materials: &'a [Material],
 }
 
+/// Geometry of a [`StaticMesh`], independent of its materials.
+#[derive(Debug)]
+struct StaticMeshGeometry {
+    vertices: Vec<Point>,
+    triangles: Vec<Triangle>,
+    material_indices: Vec<usize>,
+}
+
+/// [`StaticMesh`] errors.
+#[derive(Debug, PartialEq, Eq)]
+pub enum StaticMeshError {
+    /// The mesh was not created with [`StaticMesh::try_new_rebuildable`], so its geometry is not
+    /// available.
+    GeometryUnavailable,
+
+    /// The number of material indices differs from the number of triangles.
+    MaterialIndicesLengthMismatch {
+        /// The number of material indices.
+        num_material_indices: usize,
+        /// The number of triangles.
+        num_triangles: usize,
+    },
+
+    /// A triangle refers to a material that does not exist.
+    MaterialIndexOutOfBounds {
+        /// The index of the triangle.
+        triangle_index: usize,
+        /// The material index of the triangle.
+        material_index: usize,
+        /// The number of materials.
+        num_materials: usize,
+    },
+
+    /// Steam Audio failed to create the mesh.
+    SteamAudio(SteamAudioError),
+}
+
+impl std::error::Error for StaticMeshError {}
+
+impl std::fmt::Display for StaticMeshError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::GeometryUnavailable => write!(
+                f,
+                "static mesh geometry is only available for meshes created with `StaticMesh::try_new_rebuildable`"
+            ),
+            Self::MaterialIndicesLengthMismatch {
+                num_material_indices,
+                num_triangles,
+            } => write!(
+                f,
+                "got {num_material_indices} material indices for {num_triangles} triangles"
+            ),
+            Self::MaterialIndexOutOfBounds {
+                triangle_index,
+                material_index,
+                num_materials,
+            } => write!(
+                f,
+                "material index {material_index} of triangle {triangle_index} out of bounds (num_materials: {num_materials})"
+            ),
+            Self::SteamAudio(error) => write!(f, "Steam Audio error: {error}"),
+        }
+    }
+}
+
+impl From<SteamAudioError> for StaticMeshError {
+    fn from(error: SteamAudioError) -> Self {
+        Self::SteamAudio(error)
+    }
+}
+
+/// Returns the minimum and maximum corners of the axis-aligned box enclosing `vertices`, or `None`
+/// if there are none.
+pub(crate) fn vertex_bounds(vertices: &[Point]) -> Option<(Point, Point)> {
+    let (first, rest) = vertices.split_first()?;
+
+    Some(rest.iter().fold((*first, *first), |(min, max), v| {
+        (
+            Point::new(min.x.min(v.x), min.y.min(v.y), min.z.min(v.z)),
+            Point::new(max.x.max(v.x), max.y.max(v.y), max.z.max(v.z)),
+        )
+    }))
+}
+
+/// Builds the vertices and triangles of a regular grid heightfield.
+///
+/// See [`StaticMesh::from_heightfield`].
+fn heightfield_geometry(
+    heights: &[f32],
+    width: usize,
+    depth: usize,
+    cell_size: f32,
+) -> (Vec<Point>, Vec<Triangle>) {
+    assert!(
+        width >= 2 && depth >= 2,
+        "heightfield must be at least 2x2 samples, got {width}x{depth}"
+    );
+    assert_eq!(
+        heights.len(),
+        width * depth,
+        "heightfield must contain width * depth samples"
+    );
+    assert!(
+        i32::try_from(heights.len()).is_ok(),
+        "heightfield has too many samples"
+    );
+
+    let vertices = heights
+        .iter()
+        .enumerate()
+        .map(|(index, &height)| {
+            let x = (index % width) as f32 * cell_size;
+            let z = (index / width) as f32 * cell_size;
+            Point::new(x, height, z)
+        })
+        .collect();
+
+    let mut triangles = Vec::with_capacity(2 * (width - 1) * (depth - 1));
+    for z in 0..depth - 1 {
+        for x in 0..width - 1 {
+            let top_left = (z * width + x) as i32;
+            let top_right = top_left + 1;
+            let bottom_left = top_left + width as i32;
+            let bottom_right = bottom_left + 1;
+
+            // Counter-clockwise when seen from above, so that normals point towards +y.
+            triangles.push(Triangle::new(top_left, bottom_left, top_right));
+            triangles.push(Triangle::new(top_right, bottom_left, bottom_right));
+        }
+    }
+
+    (vertices, triangles)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -279,4 +641,149 @@ mod tests {
         drop(static_mesh);
         assert!(!clone.raw_ptr().is_null());
     }
+
+    mod rebuild_with_materials {
+        use super::*;
+
+        fn quad(scene: &Scene) -> StaticMesh<DefaultRayTracer> {
+            let vertices = [
+                Point::new(0.0, 0.0, 0.0),
+                Point::new(1.0, 0.0, 0.0),
+                Point::new(1.0, 1.0, 0.0),
+                Point::new(0.0, 1.0, 0.0),
+            ];
+
+            StaticMesh::try_new_rebuildable(
+                scene,
+                &StaticMeshSettings {
+                    vertices: &vertices,
+                    triangles: &[Triangle::new(0, 1, 2), Triangle::new(0, 2, 3)],
+                    material_indices: &[0, 1],
+                    materials: &[Material::BRICK, Material::GLASS],
+                },
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn test_rebuild() {
+            let context = Context::default();
+            let mut scene = Scene::try_new(&context).unwrap();
+            let static_mesh = quad(&scene);
+
+            let rebuilt = static_mesh
+                .rebuild_with_materials(&scene, &[Material::CARPET, Material::WOOD], None)
+                .unwrap();
+            assert_ne!(rebuilt, static_mesh);
+            assert_eq!(rebuilt.bounds(), static_mesh.bounds());
+
+            let rebuilt_again = rebuilt
+                .rebuild_with_materials(&scene, &[Material::WOOD, Material::CARPET], None)
+                .unwrap();
+
+            scene.add_static_mesh(rebuilt_again);
+            scene.commit();
+        }
+
+        #[test]
+        fn test_not_rebuildable() {
+            let context = Context::default();
+            let scene = Scene::try_new(&context).unwrap();
+            let static_mesh = StaticMesh::try_new(
+                &scene,
+                &StaticMeshSettings {
+                    vertices: &[
+                        Point::new(0.0, 0.0, 0.0),
+                        Point::new(1.0, 0.0, 0.0),
+                        Point::new(1.0, 1.0, 0.0),
+                    ],
+                    triangles: &[Triangle::new(0, 1, 2)],
+                    material_indices: &[0],
+                    materials: &[Material::BRICK],
+                },
+            )
+            .unwrap();
+
+            assert_eq!(
+                static_mesh.rebuild_with_materials(&scene, &[Material::CARPET], None),
+                Err(StaticMeshError::GeometryUnavailable)
+            );
+        }
+
+        #[test]
+        fn test_material_indices() {
+            let context = Context::default();
+            let scene = Scene::try_new(&context).unwrap();
+            let static_mesh = quad(&scene);
+
+            assert!(
+                static_mesh
+                    .rebuild_with_materials(&scene, &[Material::CARPET], Some(&[0, 0]))
+                    .is_ok()
+            );
+            assert_eq!(
+                static_mesh.rebuild_with_materials(&scene, &[Material::CARPET], Some(&[0])),
+                Err(StaticMeshError::MaterialIndicesLengthMismatch {
+                    num_material_indices: 1,
+                    num_triangles: 2,
+                })
+            );
+        }
+
+        #[test]
+        fn test_material_index_out_of_bounds() {
+            let context = Context::default();
+            let scene = Scene::try_new(&context).unwrap();
+            let static_mesh = quad(&scene);
+
+            assert_eq!(
+                static_mesh.rebuild_with_materials(&scene, &[Material::CARPET], None),
+                Err(StaticMeshError::MaterialIndexOutOfBounds {
+                    triangle_index: 1,
+                    material_index: 1,
+                    num_materials: 1,
+                })
+            );
+        }
+    }
+
+    mod from_heightfield {
+        use super::*;
+        use crate::geometry::static_mesh::heightfield_geometry;
+
+        #[test]
+        fn test_geometry() {
+            let heights = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+            let (vertices, triangles) = heightfield_geometry(&heights, 3, 2, 2.0);
+
+            assert_eq!(vertices.len(), 6);
+            assert_eq!(vertices[4], Point::new(2.0, 4.0, 2.0));
+            assert_eq!(triangles.len(), 4);
+
+            // All normals point upwards.
+            for triangle in triangles {
+                let [a, b, c] = triangle.indices.map(|i| vertices[i as usize]);
+                let (u, v) = ([b.x - a.x, b.z - a.z], [c.x - a.x, c.z - a.z]);
+                let normal_y = u[1] * v[0] - u[0] * v[1];
+                assert!(normal_y > 0.0);
+            }
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_invalid_num_samples() {
+            heightfield_geometry(&[0.0; 5], 3, 2, 1.0);
+        }
+
+        #[test]
+        fn test_try_new() {
+            let context = Context::default();
+            let scene = Scene::try_new(&context).unwrap();
+            let heights = [0.0; 16];
+            assert!(
+                StaticMesh::from_heightfield(&scene, &heights, 4, 4, 1.0, Material::default())
+                    .is_ok()
+            );
+        }
+    }
 }
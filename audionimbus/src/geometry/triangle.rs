@@ -21,6 +21,7 @@ impl Triangle {
     }
 }
 
+#[cfg(feature = "std")]
 impl From<Triangle> for audionimbus_sys::IPLTriangle {
     fn from(triangle: Triangle) -> Self {
         Self {
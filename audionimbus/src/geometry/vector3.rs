@@ -2,6 +2,13 @@
 ///
 /// Steam Audio uses a right-handed coordinate system, with the positive x-axis pointing right, the positive y-axis pointing up, and the negative z-axis pointing ahead.
 /// Position and direction data obtained from a game engine or audio engine must be properly transformed before being passed to any Steam Audio API function.
+///
+/// # Equality
+///
+/// [`PartialEq`] compares components exactly, as floating-point numbers.
+/// Vectors that went through different computations rarely compare equal because of rounding,
+/// and a vector with a `NaN` component is not equal to anything, including itself.
+/// Prefer [`Self::approx_eq`] to compare computed vectors, such as in tests.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Vector3 {
     /// The x-coordinate.
@@ -19,6 +26,128 @@ impl Vector3 {
     pub const fn new(x: f32, y: f32, z: f32) -> Self {
         Self { x, y, z }
     }
+
+    /// Returns the dot product of this vector and `other`.
+    pub const fn dot(&self, other: &Self) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Returns the cross product of this vector and `other`.
+    ///
+    /// Since Steam Audio's coordinate system is right-handed, the cross product of the right
+    /// (+x) and up (+y) axes is the backward (+z) axis, i.e. the opposite of ahead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let right = Vector3::new(1.0, 0.0, 0.0);
+    /// let up = Vector3::new(0.0, 1.0, 0.0);
+    /// assert_eq!(right.cross(&up), Vector3::new(0.0, 0.0, 1.0));
+    /// ```
+    pub const fn cross(&self, other: &Self) -> Self {
+        Self {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    /// Returns `true` if each component of this vector is within `epsilon` of the corresponding
+    /// component of `other`.
+    ///
+    /// Vectors with a `NaN` component are never approximately equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let sum = Vector3::new(0.1 + 0.6, 0.0, 0.0);
+    ///
+    /// assert_ne!(sum, Vector3::new(0.7, 0.0, 0.0));
+    /// assert!(sum.approx_eq(&Vector3::new(0.7, 0.0, 0.0), 1e-6));
+    /// assert!(!sum.approx_eq(&Vector3::new(0.7, 0.0, f32::NAN), 1e-6));
+    /// ```
+    pub const fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        const fn close(a: f32, b: f32, epsilon: f32) -> bool {
+            a - b <= epsilon && b - a <= epsilon
+        }
+
+        close(self.x, other.x, epsilon)
+            && close(self.y, other.y, epsilon)
+            && close(self.z, other.z, epsilon)
+    }
+
+    /// Returns the length of this vector.
+    #[cfg(feature = "std")]
+    pub fn length(&self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    /// Returns the unit vector pointing in the same direction as this vector.
+    ///
+    /// The zero vector is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let listener = Point::new(0.0, 1.0, 0.0);
+    /// let source = Point::new(3.0, 1.0, -4.0);
+    ///
+    /// // Direction from the listener to the source.
+    /// let direction = (source - listener).normalize();
+    /// assert_eq!(direction, Direction::new(0.6, 0.0, -0.8));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn normalize(&self) -> Self {
+        let length = self.length();
+        if length == 0.0 {
+            *self
+        } else {
+            Self::new(self.x / length, self.y / length, self.z / length)
+        }
+    }
+}
+
+impl core::ops::Add for Vector3 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl core::ops::Sub for Vector3 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl core::ops::Neg for Vector3 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl core::ops::Mul<f32> for Vector3 {
+    type Output = Self;
+
+    fn mul(self, scalar: f32) -> Self {
+        Self::new(self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+}
+
+impl core::ops::Mul<Vector3> for f32 {
+    type Output = Vector3;
+
+    fn mul(self, vector: Vector3) -> Vector3 {
+        vector * self
+    }
 }
 
 impl Default for Vector3 {
@@ -47,6 +176,7 @@ impl From<Vector3> for [f32; 3] {
     }
 }
 
+#[cfg(feature = "std")]
 impl From<Vector3> for audionimbus_sys::IPLVector3 {
     fn from(vector: Vector3) -> Self {
         Self {
@@ -57,6 +187,7 @@ impl From<Vector3> for audionimbus_sys::IPLVector3 {
     }
 }
 
+#[cfg(feature = "std")]
 impl From<audionimbus_sys::IPLVector3> for Vector3 {
     fn from(vector: audionimbus_sys::IPLVector3) -> Self {
         Self {
@@ -97,6 +228,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_vector3_approx_eq() {
+        let v = Vector3::new(1.0, 2.0, 3.0);
+        assert!(v.approx_eq(&Vector3::new(1.0005, 1.9995, 3.0), 1e-3));
+        assert!(!v.approx_eq(&Vector3::new(1.0, 2.0, 3.01), 1e-3));
+
+        let nan = Vector3::new(f32::NAN, 0.0, 0.0);
+        assert!(!nan.approx_eq(&nan, f32::INFINITY));
+    }
+
     #[test]
     fn test_vector3_equality() {
         let v1 = Vector3::new(1.0, 2.0, 3.0);
@@ -106,4 +247,37 @@ mod tests {
         assert_eq!(v1, v2);
         assert_ne!(v1, v3);
     }
+
+    #[test]
+    fn test_vector3_arithmetic() {
+        let a = Vector3::new(1.0, 2.0, 3.0);
+        let b = Vector3::new(4.0, 5.0, 6.0);
+
+        assert_eq!(a + b, Vector3::new(5.0, 7.0, 9.0));
+        assert_eq!(b - a, Vector3::new(3.0, 3.0, 3.0));
+        assert_eq!(-a, Vector3::new(-1.0, -2.0, -3.0));
+        assert_eq!(a * 2.0, Vector3::new(2.0, 4.0, 6.0));
+        assert_eq!(2.0 * a, a * 2.0);
+    }
+
+    #[test]
+    fn test_vector3_dot_cross() {
+        let a = Vector3::new(1.0, 2.0, 3.0);
+        let b = Vector3::new(4.0, 5.0, 6.0);
+
+        assert_eq!(a.dot(&b), 32.0);
+        assert_eq!(a.cross(&b), Vector3::new(-3.0, 6.0, -3.0));
+        assert_eq!(a.cross(&b).dot(&a), 0.0);
+        assert_eq!(b.cross(&a), -a.cross(&b));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_vector3_normalize() {
+        let v = Vector3::new(0.0, 3.0, -4.0);
+
+        assert_eq!(v.length(), 5.0);
+        assert_eq!(v.normalize(), Vector3::new(0.0, 0.6, -0.8));
+        assert_eq!(Vector3::default().normalize(), Vector3::default());
+    }
 }
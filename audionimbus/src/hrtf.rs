@@ -24,9 +24,15 @@ static HRTF_CREATION_LOCK: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(())
 /// Cloning it is cheap; it produces a new handle pointing to the same underlying object, while
 /// incrementing a reference count.
 /// The underlying object is destroyed when all handles are dropped.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug)]
 #[cfg_attr(feature = "bevy", derive(Resource))]
-pub struct Hrtf(pub(crate) audionimbus_sys::IPLHRTF);
+pub struct Hrtf {
+    inner: audionimbus_sys::IPLHRTF,
+
+    /// Audio settings the HRTF was created with.
+    /// `None` if the handle was created from a raw pointer.
+    audio_settings: Option<AudioSettings>,
+}
 
 impl Hrtf {
     /// Creates a new Head-Related Transfer Function (HRTF) and returns a handle to it.
@@ -51,7 +57,10 @@ impl Hrtf {
     ) -> Result<Self, SteamAudioError> {
         let _guard = HRTF_CREATION_LOCK.lock().unwrap();
 
-        let mut hrtf = Self(std::ptr::null_mut());
+        let mut hrtf = Self {
+            inner: std::ptr::null_mut(),
+            audio_settings: Some(*audio_settings),
+        };
 
         let (mut settings_ffi, _filename_keeper) = hrtf_settings.to_ffi();
 
@@ -75,30 +84,74 @@ impl Hrtf {
         Ok(hrtf)
     }
 
+    /// Returns the sampling rate, in Hz, the HRTF was created for.
+    ///
+    /// Steam Audio resamples the HRTF data (including SOFA data) to the sampling rate of the
+    /// [`AudioSettings`] passed to [`Self::try_new`], so this is the rate of the audio the HRTF can
+    /// be applied to, not the rate the HRTF data was measured at.
+    /// Steam Audio does not expose the latter, nor the length of the impulse responses.
+    ///
+    /// Returns `None` if the handle was created from a raw pointer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let context = Context::default();
+    /// let audio_settings = AudioSettings::default();
+    /// let hrtf = Hrtf::try_new(&context, &audio_settings, &HrtfSettings::default())?;
+    ///
+    /// assert_eq!(hrtf.sampling_rate(), Some(audio_settings.sampling_rate));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub const fn sampling_rate(&self) -> Option<u32> {
+        match self.audio_settings {
+            Some(audio_settings) => Some(audio_settings.sampling_rate),
+            None => None,
+        }
+    }
+
+    /// Returns the frame size, in samples, the HRTF was created for.
+    ///
+    /// Returns `None` if the handle was created from a raw pointer.
+    pub const fn frame_size(&self) -> Option<u32> {
+        match self.audio_settings {
+            Some(audio_settings) => Some(audio_settings.frame_size),
+            None => None,
+        }
+    }
+
     /// Returns the raw FFI pointer to the underlying HRTF.
     ///
     /// This is intended for internal use and advanced scenarios.
     pub const fn raw_ptr(&self) -> audionimbus_sys::IPLHRTF {
-        self.0
+        self.inner
     }
 
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLHRTF {
-        &mut self.0
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLHRTF {
+        &mut self.inner
     }
 }
 
 impl From<audionimbus_sys::IPLHRTF> for Hrtf {
     fn from(ptr: audionimbus_sys::IPLHRTF) -> Self {
-        Self(ptr)
+        Self {
+            inner: ptr,
+            audio_settings: None,
+        }
     }
 }
 
 impl Drop for Hrtf {
     fn drop(&mut self) {
-        unsafe { audionimbus_sys::iplHRTFRelease(&raw mut self.0) }
+        unsafe { audionimbus_sys::iplHRTFRelease(&raw mut self.inner) }
     }
 }
 
@@ -113,10 +166,21 @@ impl Clone for Hrtf {
     fn clone(&self) -> Self {
         // SAFETY: iplHRTFRetain increments the reference count and returns a new handle.
         // The HRTF will not be destroyed until all references are released.
-        Self(unsafe { audionimbus_sys::iplHRTFRetain(self.0) })
+        Self {
+            inner: unsafe { audionimbus_sys::iplHRTFRetain(self.inner) },
+            audio_settings: self.audio_settings,
+        }
     }
 }
 
+impl PartialEq for Hrtf {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw_ptr() == other.raw_ptr()
+    }
+}
+
+impl Eq for Hrtf {}
+
 impl Hash for Hrtf {
     fn hash<H: Hasher>(&self, state: &mut H) {
         std::ptr::hash(self.raw_ptr(), state);
@@ -124,6 +188,10 @@ impl Hash for Hrtf {
 }
 
 /// Settings used to create an [`Hrtf`].
+///
+/// [`HrtfSettings::default`] uses Steam Audio's built-in HRTF.
+/// Use [`HrtfSettings::builder`] to load a custom HRTF from a SOFA file or buffer, or to adjust
+/// its volume.
 #[derive(Debug, Clone)]
 pub struct HrtfSettings {
     /// Volume correction factor to apply to the loaded HRTF data.
@@ -139,6 +207,27 @@ pub struct HrtfSettings {
 }
 
 impl HrtfSettings {
+    /// Returns a builder for HRTF settings, starting from the built-in HRTF with no volume
+    /// correction and no normalization.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let hrtf_settings = HrtfSettings::builder()
+    ///     .with_sofa_file("hrtf.sofa")
+    ///     .with_volume_normalization(VolumeNormalization::RootMeanSquared)
+    ///     .with_volume_gain_db(-6.0)
+    ///     .build();
+    ///
+    /// assert!(matches!(hrtf_settings.sofa_information, Some(Sofa::Filename(_))));
+    /// ```
+    pub fn builder() -> HrtfSettingsBuilder {
+        HrtfSettingsBuilder {
+            settings: Self::default(),
+        }
+    }
+
     /// Converts the settings to the FFI representation.
     ///
     /// Returns a tuple of the FFI settings struct and an optional `CString` that must be kept
@@ -201,6 +290,51 @@ impl Default for HrtfSettings {
     }
 }
 
+/// Builder for [`HrtfSettings`], created with [`HrtfSettings::builder`].
+#[derive(Debug, Clone)]
+pub struct HrtfSettingsBuilder {
+    settings: HrtfSettings,
+}
+
+impl HrtfSettingsBuilder {
+    /// Loads HRTF data from the SOFA file at `filename`.
+    pub fn with_sofa_file(mut self, filename: impl Into<String>) -> Self {
+        self.settings.sofa_information = Some(Sofa::Filename(filename.into()));
+        self
+    }
+
+    /// Loads HRTF data from a buffer containing the contents of a SOFA file.
+    pub fn with_sofa_buffer(mut self, buffer: impl Into<Vec<u8>>) -> Self {
+        self.settings.sofa_information = Some(Sofa::Buffer(buffer.into()));
+        self
+    }
+
+    /// Uses Steam Audio's built-in HRTF, discarding any SOFA file or buffer set previously.
+    pub fn with_built_in(mut self) -> Self {
+        self.settings.sofa_information = None;
+        self
+    }
+
+    /// Sets the volume normalization applied to the HRTF data.
+    pub fn with_volume_normalization(mut self, volume_normalization: VolumeNormalization) -> Self {
+        self.settings.volume_normalization = volume_normalization;
+        self
+    }
+
+    /// Sets the volume correction applied to the HRTF data, in decibels.
+    ///
+    /// A gain of 0 dB leaves the HRTF data unchanged.
+    pub fn with_volume_gain_db(mut self, gain_db: f32) -> Self {
+        self.settings.volume = 10.0_f32.powf(gain_db / 20.0);
+        self
+    }
+
+    /// Returns the configured [`HrtfSettings`].
+    pub fn build(self) -> HrtfSettings {
+        self.settings
+    }
+}
+
 /// Whether to load SOFA data from a filename or a buffer.
 #[derive(Debug, Clone)]
 pub enum Sofa {
@@ -235,17 +369,25 @@ impl From<VolumeNormalization> for audionimbus_sys::IPLHRTFNormType {
 /// Techniques for interpolating HRTF data.
 ///
 /// This is used when rendering a point source whose position relative to the listener is not contained in the measured HRTF data.
-#[derive(Copy, Clone, Debug)]
+///
+/// [`Nearest`](Self::Nearest) is cheaper, but snaps to the closest measured direction, which
+/// produces audible "stepping" (zipper) artifacts as a source moves around the listener.
+/// [`Bilinear`](Self::Bilinear) blends between neighboring measurements for smooth motion, at a
+/// higher CPU cost, and is the default.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub enum HrtfInterpolation {
     /// Nearest-neighbor filtering, i.e., no interpolation.
     ///
     /// Selects the measurement location that is closest to the source’s actual location.
+    /// Best suited to static or slowly moving sources, or when CPU budget is tight.
     Nearest,
 
     /// Bilinear filtering.
     ///
-    /// Incurs a relatively high CPU overhead as compared to nearest-neighbor filtering, so use this for sounds where it has a significant benefit.
-    /// Typically, bilinear filtering is most useful for wide-band noise-like sounds, such as radio static, mechanical noise, fire, etc.
+    /// Blends the measurements surrounding the source’s actual location, so that moving sources are rendered smoothly.
+    /// It is the default, and its benefit is most audible for wide-band noise-like sounds, such as radio static, mechanical noise, fire, etc.
+    /// It incurs a relatively high CPU overhead as compared to nearest-neighbor filtering, so consider [`Nearest`](Self::Nearest) for sources where it makes no audible difference.
+    #[default]
     Bilinear,
 }
 
@@ -271,6 +413,31 @@ mod tests {
         assert!(hrtf_result.is_ok());
     }
 
+    #[test]
+    fn test_builder() {
+        let hrtf_settings = HrtfSettings::builder()
+            .with_sofa_buffer(vec![0u8; 4])
+            .with_volume_gain_db(20.0)
+            .build();
+        assert!(matches!(
+            hrtf_settings.sofa_information,
+            Some(Sofa::Buffer(ref buffer)) if buffer.len() == 4
+        ));
+        assert!((hrtf_settings.volume - 10.0).abs() < 1e-4);
+
+        let hrtf_settings = HrtfSettings::builder()
+            .with_sofa_file("hrtf.sofa")
+            .with_built_in()
+            .with_volume_gain_db(0.0)
+            .build();
+        assert!(hrtf_settings.sofa_information.is_none());
+        assert_eq!(hrtf_settings.volume, 1.0);
+
+        let context = Context::default();
+        let audio_settings = AudioSettings::default();
+        assert!(Hrtf::try_new(&context, &audio_settings, &hrtf_settings).is_ok());
+    }
+
     #[test]
     fn test_hrtf_clone() {
         let context = Context::default();
@@ -279,6 +446,7 @@ mod tests {
         let hrtf = Hrtf::try_new(&context, &audio_settings, &hrtf_settings).unwrap();
         let clone = hrtf.clone();
         assert_eq!(hrtf.raw_ptr(), clone.raw_ptr());
+        assert_eq!(clone.sampling_rate(), hrtf.sampling_rate());
         drop(hrtf);
         assert!(!clone.raw_ptr().is_null());
     }
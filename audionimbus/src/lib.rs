@@ -84,6 +84,33 @@
 //!
 //! Set `AUDIONIMBUS_AUTO_INSTALL_PROGRESS=0` to silence Cargo progress warnings from the auto-install step.
 //!
+//! ### Building From Source
+//!
+//! Steam Audio is open source, so it can also be built from source and linked statically with the `build-from-source` feature:
+//!
+//! ```toml
+//! [dependencies]
+//! audionimbus = { version = "0.15.0", features = ["build-from-source"] }
+//! ```
+//!
+//! The build script configures and builds the Steam Audio core library with CMake, using the sources in `STEAMAUDIO_SOURCE_DIR` if set, or those of the `steam-audio` submodule otherwise (only available when depending on the git repository, since the published crate only ships headers).
+//! It requires **CMake** and a C++ toolchain, and Steam Audio's own third-party dependencies must be available as described in its build instructions.
+//! If the build only produces a shared library, it is linked dynamically instead, and a warning is emitted.
+//!
+//! This feature takes precedence over `auto-install`.
+//!
+//! ### Geometry Without `std`
+//!
+//! The math types of the [`geometry`] module ([`Vector3`], [`Point`], [`Direction`], [`Matrix`], [`Sphere`], [`CoordinateSystem`], [`Ray`], [`Hit`], [`Triangle`] and [`Material`]) do not depend on Steam Audio, and can be used in `no_std` crates by disabling the default `std` feature:
+//!
+//! ```toml
+//! [dependencies]
+//! audionimbus = { version = "0.15.0", default-features = false }
+//! ```
+//!
+//! Without `std`, the crate does not link against Steam Audio, only exposes these types (re-exported at the root), and omits the conversions to their FFI counterparts.
+//! Methods relying on floating-point functions from `std`, such as [`Vector3::from_spherical`], are also unavailable.
+//!
 //! ### Manual Installation
 //!
 //! If you prefer manual installation or the automatic installation doesn't work for your setup, you can still install Steam Audio manually.
@@ -120,6 +147,7 @@
 //! ```
 //!
 //! If you want to link directly against the extracted Steam Audio SDK instead of installing the library into a common linker directory, set `STEAMAUDIO_LIB_DIR` to the platform-specific library directory, for example `STEAMAUDIO_LIB_DIR="$SDKROOT/lib/linux-x64"`.
+//! Alternatively, set `STEAMAUDIO_DIR` to the directory in which you extracted the zip file (`SDKROOT`), and the library directory matching the target platform will be used.
 //!
 //! Finally, add `audionimbus` to your `Cargo.toml`:
 //!
@@ -208,6 +236,8 @@
 //! This example demonstrates how to spatialize sound using the `audionimbus` library:
 //!
 //! ```rust
+//! # #[cfg(feature = "std")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! use audionimbus::*;
 //!
 //! // Initialize the audio context.
@@ -250,7 +280,7 @@
 //!         0.0, // Up
 //!         0.0, // Behind
 //!     ),
-//!     interpolation: HrtfInterpolation::Nearest,
+//!     interpolation: HrtfInterpolation::Bilinear,
 //!     spatial_blend: 1.0,
 //!     hrtf,
 //!     peak_delays: None,
@@ -263,7 +293,11 @@
 //!
 //! // Note: most audio engines expect interleaved audio (alternating samples for each channel). If
 //! // required, use the `AudioBuffer::interleave` method to convert the format.
-//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! # Ok(())
+//! # }
+//! # // The example relies on the `std` feature.
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
 //! ```
 //!
 //! To implement real-time audio processing and playback in your game, check out the [demo crate](https://github.com/MaxenceMaire/audionimbus/tree/master/audionimbus/examples/core) for a basic example.
@@ -274,6 +308,17 @@
 //!
 //! For additional examples, you can explore the [tests](https://github.com/MaxenceMaire/audionimbus/tree/master/audionimbus/tests).
 //!
+//! ## Raw Pointers
+//!
+//! Handles to Steam Audio objects expose their underlying FFI pointer through `raw_ptr` and
+//! `raw_ptr_mut`, for use with `audionimbus-sys`.
+//!
+//! Each handle owns a reference to the underlying Steam Audio object, which is released when the
+//! handle is dropped.
+//! Callers of `raw_ptr_mut` must not overwrite or null the pointer unless they have released that
+//! reference and the new pointer carries a reference of its own; otherwise the object is leaked,
+//! released twice, or used after being destroyed.
+//!
 //! ## Documentation
 //!
 //! Documentation is available at [docs.rs](https://docs.rs/audionimbus/latest).
@@ -289,35 +334,77 @@
 //! You may choose either license when using the software.
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "std")]
 const NUM_BANDS: u32 = 3;
 
+/// Opens a profiling scope that lasts until the end of the enclosing block.
+///
+/// Expands to nothing unless the `profiling` feature is enabled.
+#[cfg(feature = "std")]
+macro_rules! profile_scope {
+    ($name:literal) => {
+        #[cfg(feature = "profiling")]
+        let _profiling_scope = $crate::profiling::ProfilingScope::new($name);
+    };
+}
+
+#[cfg(feature = "std")]
 pub mod prelude;
+#[cfg(not(feature = "std"))]
+pub use geometry::*;
+#[cfg(feature = "std")]
 pub use prelude::*;
 
+#[cfg(feature = "std")]
 pub mod audio_buffer;
+#[cfg(feature = "std")]
 pub mod audio_settings;
+#[cfg(feature = "std")]
 pub mod baking;
+#[cfg(feature = "std")]
 pub mod callback;
+#[cfg(feature = "std")]
 pub mod context;
+#[cfg(feature = "std")]
+pub mod denormals;
+#[cfg(feature = "std")]
 pub mod device;
+#[cfg(feature = "std")]
 pub mod effect;
+#[cfg(feature = "std")]
 mod error;
+#[cfg(feature = "std")]
 pub use error::SteamAudioError;
+#[cfg(feature = "std")]
 mod ffi_wrapper;
 pub mod geometry;
+#[cfg(feature = "std")]
 pub mod hrtf;
+#[cfg(feature = "std")]
 pub mod model;
+#[cfg(feature = "std")]
 pub mod probe;
+#[cfg(feature = "std")]
 mod serialized_object;
-pub use serialized_object::SerializedObject;
+#[cfg(feature = "std")]
+pub use serialized_object::{SerializationError, SerializedObject, SteamAudioSerialize};
+#[cfg(feature = "std")]
 pub mod energy_field;
+#[cfg(feature = "std")]
 pub mod impulse_response;
+#[cfg(feature = "std")]
 pub mod ray_tracing;
+#[cfg(feature = "std")]
 pub mod reconstructor;
+#[cfg(feature = "std")]
 mod sealed;
+#[cfg(feature = "std")]
 pub mod simulation;
+#[cfg(feature = "std")]
 pub mod version;
+#[cfg(feature = "std")]
 use sealed::Sealed;
 #[cfg(feature = "wiring")]
 #[cfg_attr(docsrs, doc(cfg(feature = "wiring")))]
@@ -334,3 +421,7 @@ pub mod wwise;
 #[cfg(feature = "bevy")]
 #[cfg_attr(docsrs, doc(cfg(feature = "bevy")))]
 pub mod bevy;
+
+#[cfg(feature = "profiling")]
+#[cfg_attr(docsrs, doc(cfg(feature = "profiling")))]
+pub mod profiling;
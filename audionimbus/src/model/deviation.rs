@@ -3,7 +3,7 @@
 pub use crate::callback::DeviationCallback;
 
 /// A deviation model that can be used for modeling frequency-dependent attenuation of sound as it bends along the path from the source to the listener.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub enum DeviationModel {
     /// The default deviation model.
     /// This is a physics-based model, based on the Uniform Theory of Diffraction, with various additional assumptions.
@@ -14,6 +14,54 @@ pub enum DeviationModel {
     Callback(DeviationCallback),
 }
 
+impl DeviationModel {
+    /// A deviation model that applies no attenuation, regardless of how much the sound path bends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let deviation = DeviationModel::off();
+    /// ```
+    pub fn off() -> Self {
+        Self::callback(|_angle, _band| 1.0)
+    }
+
+    /// A frequency-independent deviation model whose attenuation decreases linearly with the
+    /// deviation angle, from no attenuation when the path is straight to full attenuation when the
+    /// path turns back on itself (π radians).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let deviation = DeviationModel::linear();
+    /// ```
+    pub fn linear() -> Self {
+        Self::callback(|angle, _band| (1.0 - angle / std::f32::consts::PI).clamp(0.0, 1.0))
+    }
+
+    /// An arbitrary deviation model, defined by a closure taking the deviation angle (in radians)
+    /// and the frequency band index, and returning the attenuation to apply (between 0.0 and 1.0).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// // Attenuate higher frequencies more aggressively.
+    /// let deviation = DeviationModel::callback(|angle, band| {
+    ///     let falloff = [0.5, 1.0, 2.0][band as usize];
+    ///     (1.0 - falloff * angle / std::f32::consts::PI).clamp(0.0, 1.0)
+    /// });
+    /// ```
+    pub fn callback<F>(f: F) -> Self
+    where
+        F: Fn(f32, std::ffi::c_int) -> f32 + Send + Sync + 'static,
+    {
+        Self::Callback(DeviationCallback::new(f))
+    }
+}
+
 impl From<&DeviationModel> for audionimbus_sys::IPLDeviationModel {
     fn from(deviation_model: &DeviationModel) -> Self {
         let (type_, callback, user_data) = match deviation_model {
@@ -6,7 +6,7 @@ use crate::geometry;
 
 /// A directivity pattern that can be used to model changes in sound intensity as a function of the source’s orientation.
 /// Can be used with both direct and indirect sound propagation.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Directivity {
     /// The default directivity model is a weighted dipole.
     /// This is a linear blend between an omnidirectional source (which emits sound with equal intensity in all directions), and a dipole oriented along the z-axis in the source’s coordinate system (which focuses sound along the +z and -z axes).
@@ -56,6 +56,34 @@ impl From<&Directivity> for audionimbus_sys::IPLDirectivity {
 }
 
 /// Calculates the attenuation of a source due to its directivity pattern and orientation relative to a listener.
+///
+/// This evaluates the pattern exactly as Steam Audio does during simulation, which makes it
+/// suitable for previewing directivity patterns outside of a simulation.
+///
+/// # Examples
+///
+/// Sampling a cardioid pattern around the source, e.g. to draw a polar plot:
+///
+/// ```
+/// # use audionimbus::*;
+/// let context = Context::default();
+/// let source = CoordinateSystem::default();
+/// let directivity = Directivity::WeightedDipole {
+///     weight: 0.5,
+///     power: 1.0,
+/// };
+///
+/// let polar_plot: Vec<f32> = (0..360)
+///     .map(|degrees| {
+///         let angle = (degrees as f32).to_radians();
+///         let listener = Point::new(angle.sin(), 0.0, angle.cos());
+///         directivity_attenuation(&context, source, listener, &directivity)
+///     })
+///     .collect();
+///
+/// assert_eq!(polar_plot[0], 1.0); // In front of the source.
+/// assert!(polar_plot[180] < 1e-6); // Behind the source.
+/// ```
 pub fn directivity_attenuation(
     context: &Context,
     source: geometry::CoordinateSystem,
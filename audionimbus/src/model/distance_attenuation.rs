@@ -6,7 +6,7 @@ use crate::geometry;
 
 /// A distance attenuation model that can be used for modeling attenuation of sound over distance.
 /// Can be used with both direct and indirect sound propagation.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub enum DistanceAttenuationModel {
     /// The default distance attenuation model.
     /// This is an inverse distance falloff, with all sounds within 1 meter of the listener rendered without distance attenuation.
@@ -32,6 +32,31 @@ pub enum DistanceAttenuationModel {
     },
 }
 
+impl DistanceAttenuationModel {
+    /// Creates an inverse distance falloff model, with attenuation starting at `min_distance`
+    /// from the listener.
+    ///
+    /// Sources closer than `min_distance` don't get any louder as they move closer to the
+    /// listener, which avoids excessive near-field loudness.
+    /// Raising it makes nearby sources quieter overall, lowering it makes them louder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// # let context = Context::default();
+    /// let model = DistanceAttenuationModel::inverse_distance(2.0);
+    ///
+    /// let listener = Point::new(0.0, 0.0, 0.0);
+    /// let near = distance_attenuation(&context, Point::new(0.5, 0.0, 0.0), listener, &model);
+    /// let nearer = distance_attenuation(&context, Point::new(0.1, 0.0, 0.0), listener, &model);
+    /// assert_eq!(near, nearer);
+    /// ```
+    pub const fn inverse_distance(min_distance: f32) -> Self {
+        Self::InverseDistance { min_distance }
+    }
+}
+
 impl From<&DistanceAttenuationModel> for audionimbus_sys::IPLDistanceAttenuationModel {
     fn from(distance_attenuation_model: &DistanceAttenuationModel) -> Self {
         let (type_, min_distance, callback, user_data, dirty) = match distance_attenuation_model {
@@ -119,6 +144,37 @@ mod tests {
         assert_eq!(attenuation, 0.2);
     }
 
+    #[test]
+    fn test_inverse_distance_min_distance() {
+        let context = Context::default();
+        let listener = Point::new(0.0, 0.0, 0.0);
+        let model = DistanceAttenuationModel::inverse_distance(4.0);
+
+        let attenuation = |distance| {
+            distance_attenuation(&context, Point::new(distance, 0.0, 0.0), listener, &model)
+        };
+
+        // Sources within the minimum distance are not attenuated any less.
+        assert_eq!(attenuation(1.0), attenuation(4.0));
+        assert_eq!(attenuation(8.0), attenuation(4.0) / 2.0);
+    }
+
+    #[test]
+    fn test_panicking_callback() {
+        let context = Context::default();
+        let source = Point::new(5.0, 0.0, 0.0);
+        let listener = Point::new(0.0, 0.0, 0.0);
+        let model = DistanceAttenuationModel::Callback {
+            callback: DistanceAttenuationCallback::new(|_| panic!("callback panicked")),
+            dirty: false,
+        };
+
+        // The panic must not unwind into Steam Audio.
+        let attenuation = distance_attenuation(&context, source, listener, &model);
+
+        assert_eq!(attenuation, 0.0);
+    }
+
     #[test]
     fn test_zero_distance() {
         let context = Context::default();
@@ -9,8 +9,10 @@ use crate::{
 pub use audio_buffer::*;
 pub use audio_settings::*;
 pub use baking::pathing::{PathBakeParams, PathBaker};
-pub use baking::reflections::{ReflectionsBakeFlags, ReflectionsBakeParams, ReflectionsBaker};
-pub use baking::{BakeError, BakedDataIdentifier, BakedDataVariation};
+pub use baking::reflections::{
+    ReflectionsBakeFlags, ReflectionsBakeParams, ReflectionsBakeParamsBuilder, ReflectionsBaker,
+};
+pub use baking::{BakeError, BakeParamsError, BakedDataIdentifier, BakedDataVariation};
 pub use callback::{
     AirAbsorptionCallback, AnyHitCallback, BatchedAnyHitCallback, BatchedClosestHitCallback,
     ClosestHitCallback, CustomRayTracingCallbacks, DeviationCallback, DirectivityCallback,
@@ -48,6 +50,6 @@ pub use model::distance_attenuation::*;
 pub use probe::*;
 pub use ray_tracing::*;
 pub use reconstructor::*;
-pub use serialized_object::SerializedObject;
+pub use serialized_object::{SerializationError, SerializedObject, SteamAudioSerialize};
 pub use simulation::*;
 pub use version::*;
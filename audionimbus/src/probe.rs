@@ -1,12 +1,19 @@
 //! Sound probe generation and storage.
 
-use crate::baking::BakedDataIdentifier;
+use crate::baking::{BakedDataIdentifier, BakedDataVariation};
 use crate::context::Context;
-use crate::energy_field::EnergyField;
+use crate::energy_field::{EnergyField, EnergyFieldSettings};
 use crate::error::{SteamAudioError, to_option_error};
 use crate::geometry::{Matrix, Scene, Sphere};
-use crate::serialized_object::SerializedObject;
+use crate::impulse_response::{ImpulseResponse, ImpulseResponseSettings};
+use crate::reconstructor::{
+    Reconstructor, ReconstructorInputs, ReconstructorOutputs, ReconstructorSettings,
+    ReconstructorSharedInputs,
+};
+use crate::serialized_object::{SerializedObject, SteamAudioSerialize};
 use std::hash::{Hash, Hasher};
+use std::io::Write;
+use std::path::Path;
 use std::sync::{Arc, Mutex};
 
 /// An array of sound probes.
@@ -91,7 +98,11 @@ impl ProbeArray {
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLProbeArray {
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLProbeArray {
         &mut self.0
     }
 }
@@ -227,6 +238,11 @@ struct ProbeBatchShared {
 
     /// Pending probe count to be committed.
     pending_num_probes: i32,
+
+    /// Probes added to the batch (including uncommitted changes), in order.
+    ///
+    /// `None` for loaded batches, since Steam Audio does not expose the probes of a batch.
+    probes: Option<Vec<Sphere>>,
 }
 
 impl ProbeBatch {
@@ -238,7 +254,10 @@ impl ProbeBatch {
     pub fn try_new(context: &Context) -> Result<Self, SteamAudioError> {
         let mut probe_batch = Self {
             inner: std::ptr::null_mut(),
-            shared: Arc::new(Mutex::new(ProbeBatchShared::default())),
+            shared: Arc::new(Mutex::new(ProbeBatchShared {
+                probes: Some(Vec::new()),
+                ..Default::default()
+            })),
         };
 
         let status = unsafe {
@@ -272,6 +291,31 @@ impl ProbeBatch {
         }
     }
 
+    /// Returns `true` if the probe batch contains a baked data layer with the given identifier.
+    ///
+    /// Pathing simulations find paths using the data baked by
+    /// [`PathBaker`](crate::PathBaker); a source whose
+    /// [`pathing_probes`](crate::PathingSimulationParameters::pathing_probes) have none is
+    /// simulated without error, but its pathing output stays silent.
+    /// Checking for a [`BakedDataIdentifier::Pathing`] layer before setting pathing inputs turns
+    /// that silent failure into an actionable one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// # let context = Context::default();
+    /// let probe_batch = ProbeBatch::try_new(&context)?;
+    /// let identifier = BakedDataIdentifier::Pathing {
+    ///     variation: BakedDataVariation::Dynamic,
+    /// };
+    /// assert!(!probe_batch.has_baked_data(identifier));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn has_baked_data(&self, identifier: BakedDataIdentifier) -> bool {
+        self.data_size(identifier) > 0
+    }
+
     pub fn remove_data(&mut self, identifier: BakedDataIdentifier) {
         let mut ffi_identifier: audionimbus_sys::IPLBakedDataIdentifier = identifier.into();
 
@@ -290,16 +334,26 @@ impl ProbeBatch {
             );
         }
 
-        self.shared.lock().unwrap().pending_num_probes += 1;
+        let mut shared = self.shared.lock().unwrap();
+        shared.pending_num_probes += 1;
+        if let Some(probes) = &mut shared.probes {
+            probes.push(probe);
+        }
     }
 
     /// Removes a probe from the batch.
     ///
+    /// `probe_index` refers to the probes of the batch including uncommitted changes, so several
+    /// probes can be removed before committing.
+    ///
     /// # Errors
     ///
     /// Returns [`ProbeBatchError::ProbeIndexOutOfBounds`] if `probe_index` is out of bounds.
     pub fn remove_probe(&mut self, probe_index: usize) -> Result<(), ProbeBatchError> {
-        let num_probes = self.num_probes();
+        let mut shared = self.shared.lock().unwrap();
+        let num_probes = shared
+            .committed_num_probes
+            .saturating_add_signed(shared.pending_num_probes as isize);
         if probe_index >= num_probes {
             return Err(ProbeBatchError::ProbeIndexOutOfBounds {
                 probe_index,
@@ -311,7 +365,10 @@ impl ProbeBatch {
             audionimbus_sys::iplProbeBatchRemoveProbe(self.raw_ptr(), probe_index as i32);
         }
 
-        self.shared.lock().unwrap().pending_num_probes -= 1;
+        shared.pending_num_probes -= 1;
+        if let Some(probes) = &mut shared.probes {
+            probes.remove(probe_index);
+        }
 
         Ok(())
     }
@@ -323,7 +380,64 @@ impl ProbeBatch {
             audionimbus_sys::iplProbeBatchAddProbeArray(self.raw_ptr(), probe_array.raw_ptr());
         }
 
-        self.shared.lock().unwrap().pending_num_probes += probe_array.num_probes() as i32;
+        let mut shared = self.shared.lock().unwrap();
+        shared.pending_num_probes += probe_array.num_probes() as i32;
+        if let Some(probes) = &mut shared.probes {
+            probes.extend(probe_array.probes());
+        }
+    }
+
+    /// Returns the probes of the batch, i.e. their positions and radii of influence, for
+    /// debugging probe placement.
+    ///
+    /// The probes reflect every call to [`Self::add_probe`], [`Self::add_probe_array`] and
+    /// [`Self::remove_probe`], even before they are committed.
+    ///
+    /// Returns `None` for probe batches created with [`Self::load`], since Steam Audio does not
+    /// expose the probes of a batch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// # let context = Context::default();
+    /// let mut probe_batch = ProbeBatch::try_new(&context)?;
+    /// let probe = Sphere {
+    ///     center: Point::new(0.0, 1.5, 0.0),
+    ///     radius: 2.0,
+    /// };
+    /// probe_batch.add_probe(probe);
+    /// probe_batch.commit();
+    ///
+    /// assert_eq!(probe_batch.to_debug_spheres(), Some(vec![probe]));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_debug_spheres(&self) -> Option<Vec<Sphere>> {
+        self.shared.lock().unwrap().probes.clone()
+    }
+
+    /// Exports the probes of the batch to an OBJ file, as one icosphere per probe whose radius is
+    /// the probe's radius of influence.
+    ///
+    /// The OBJ file can be loaded into a 3D modeling tool such as Blender, alongside the scene
+    /// exported with [`Scene::save_obj`], to check where probes actually landed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`std::io::Error`] if the file cannot be written, or of kind
+    /// [`Unsupported`](std::io::ErrorKind::Unsupported) if the probes are unavailable (see
+    /// [`Self::to_debug_spheres`]).
+    pub fn export_obj(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let Some(probes) = self.to_debug_spheres() else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "the probes of a loaded probe batch are unavailable",
+            ));
+        };
+
+        let mut writer = std::io::BufWriter::new(std::fs::File::create(path)?);
+        write_icospheres_obj(&probes, &mut writer)?;
+        writer.flush()
     }
 
     /// Retrieves a single array of parametric reverb times in a specific baked data layer of a specific probe in the probe batch.
@@ -391,6 +505,81 @@ impl ProbeBatch {
         Ok(())
     }
 
+    /// Reconstructs the impulse response baked for a specific probe, e.g. to plot it, convert it
+    /// to WAV, or otherwise audit the quality of a bake.
+    ///
+    /// The reflections must have been baked with
+    /// [`ReflectionsBakeFlags::BAKE_CONVOLUTION`](crate::ReflectionsBakeFlags::BAKE_CONVOLUTION),
+    /// which stores energy fields in the probe batch.
+    /// The energy field of the probe is reconstructed into an impulse response with the duration,
+    /// Ambisonic order and sampling rate given in `settings`.
+    /// The samples are then available through [`ImpulseResponse::data`] or
+    /// [`ImpulseResponse::channel`].
+    ///
+    /// Returns `None` if the probe batch contains no data for `identifier`.
+    ///
+    /// This allocates an energy field, a reconstructor and an impulse response, and is not meant
+    /// to be called at runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// - [`ProbeBatchError::ProbeIndexOutOfBounds`] if `probe_index` is out of bounds.
+    /// - [`ProbeBatchError::SteamAudio`] if the intermediate objects cannot be created.
+    pub fn baked_impulse_response(
+        &self,
+        context: &Context,
+        identifier: BakedDataIdentifier,
+        probe_index: usize,
+        settings: &ImpulseResponseSettings,
+    ) -> Result<Option<ImpulseResponse>, ProbeBatchError> {
+        let num_probes = self.num_probes();
+        if probe_index >= num_probes {
+            return Err(ProbeBatchError::ProbeIndexOutOfBounds {
+                probe_index,
+                num_probes,
+            });
+        }
+
+        if self.data_size(identifier) == 0 {
+            return Ok(None);
+        }
+
+        let mut energy_field = EnergyField::try_new(
+            context,
+            &EnergyFieldSettings {
+                duration: settings.duration,
+                order: settings.order,
+            },
+        )?;
+        self.copy_energy_field_into(identifier, probe_index, &mut energy_field)?;
+
+        let reconstructor = Reconstructor::try_new(
+            context,
+            &ReconstructorSettings {
+                max_duration: settings.duration,
+                max_order: settings.order,
+                sampling_rate: settings.sampling_rate,
+            },
+        )?;
+        let mut impulse_response = ImpulseResponse::try_new(context, settings)?;
+
+        reconstructor
+            .reconstruct(
+                &[ReconstructorInputs { energy_field }],
+                &ReconstructorSharedInputs {
+                    duration: settings.duration,
+                    order: settings.order,
+                },
+                &[ReconstructorOutputs {
+                    impulse_response: &mut impulse_response,
+                }],
+            )
+            .expect("the reconstructor is created with the duration and order to reconstruct");
+
+        Ok(Some(impulse_response))
+    }
+
     /// Commits all changes made to a probe batch since this function was last called (or since the probe batch was first created, if this function was never called).
     ///
     /// This function must be called after adding, removing, or updating any probes in the batch, for the changes to take effect.
@@ -459,11 +648,29 @@ impl ProbeBatch {
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLProbeBatch {
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLProbeBatch {
         &mut self.inner
     }
 }
 
+impl SteamAudioSerialize for ProbeBatch {
+    fn serialize(&self, serialized_object: &mut SerializedObject) {
+        self.save(serialized_object);
+    }
+
+    fn deserialize(
+        context: &Context,
+        serialized_object: &SerializedObject,
+    ) -> Result<Self, SteamAudioError> {
+        // Loading only reads from the serialized object, so a new handle to it can be lent out.
+        Self::load(context, &mut serialized_object.clone())
+    }
+}
+
 impl Drop for ProbeBatch {
     fn drop(&mut self) {
         unsafe { audionimbus_sys::iplProbeBatchRelease(&raw mut self.inner) }
@@ -500,6 +707,76 @@ impl Hash for ProbeBatch {
     }
 }
 
+/// Writes an icosahedron approximating each sphere to `writer`, in OBJ format.
+fn write_icospheres_obj(spheres: &[Sphere], writer: &mut impl Write) -> std::io::Result<()> {
+    // Golden ratio.
+    const T: f32 = 1.618_034;
+
+    const VERTICES: [[f32; 3]; 12] = [
+        [-1.0, T, 0.0],
+        [1.0, T, 0.0],
+        [-1.0, -T, 0.0],
+        [1.0, -T, 0.0],
+        [0.0, -1.0, T],
+        [0.0, 1.0, T],
+        [0.0, -1.0, -T],
+        [0.0, 1.0, -T],
+        [T, 0.0, -1.0],
+        [T, 0.0, 1.0],
+        [-T, 0.0, -1.0],
+        [-T, 0.0, 1.0],
+    ];
+
+    const FACES: [[usize; 3]; 20] = [
+        [0, 11, 5],
+        [0, 5, 1],
+        [0, 1, 7],
+        [0, 7, 10],
+        [0, 10, 11],
+        [1, 5, 9],
+        [5, 11, 4],
+        [11, 10, 2],
+        [10, 7, 6],
+        [7, 1, 8],
+        [3, 9, 4],
+        [3, 4, 2],
+        [3, 2, 6],
+        [3, 6, 8],
+        [3, 8, 9],
+        [4, 9, 5],
+        [2, 4, 11],
+        [6, 2, 10],
+        [8, 6, 7],
+        [9, 8, 1],
+    ];
+
+    // Length of each vertex of the icosahedron above.
+    let length = (1.0 + T * T).sqrt();
+
+    for (index, sphere) in spheres.iter().enumerate() {
+        writeln!(writer, "o probe_{index}")?;
+
+        let scale = sphere.radius / length;
+        for [x, y, z] in VERTICES {
+            writeln!(
+                writer,
+                "v {} {} {}",
+                sphere.center.x + x * scale,
+                sphere.center.y + y * scale,
+                sphere.center.z + z * scale
+            )?;
+        }
+
+        // OBJ indices are 1-based and global to the file.
+        let offset = index * VERTICES.len() + 1;
+        for [a, b, c] in FACES {
+            writeln!(writer, "f {} {} {}", a + offset, b + offset, c + offset)?;
+        }
+    }
+
+    Ok(())
+}
+
 /// [`ProbeBatch`] errors.
 #[derive(Debug, PartialEq, Eq)]
 pub enum ProbeBatchError {
@@ -508,6 +785,9 @@ pub enum ProbeBatchError {
         probe_index: usize,
         num_probes: usize,
     },
+
+    /// Steam Audio failed to create an object needed by the operation.
+    SteamAudio(SteamAudioError),
 }
 
 impl std::error::Error for ProbeBatchError {}
@@ -522,10 +802,17 @@ impl std::fmt::Display for ProbeBatchError {
                 f,
                 "probe index {probe_index} out of bounds (num_probes: {num_probes})"
             ),
+            Self::SteamAudio(error) => write!(f, "Steam Audio error: {error}"),
         }
     }
 }
 
+impl From<SteamAudioError> for ProbeBatchError {
+    fn from(error: SteamAudioError) -> Self {
+        Self::SteamAudio(error)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -625,6 +912,21 @@ mod tests {
             assert!(probe_batch.is_ok());
         }
 
+        #[test]
+        fn test_has_baked_data_without_bake() {
+            let context = Context::default();
+            let mut probe_batch = ProbeBatch::try_new(&context).unwrap();
+            probe_batch.add_probe(Sphere {
+                center: Point::new(0.0, 0.0, 0.0),
+                radius: 1.0,
+            });
+            probe_batch.commit();
+
+            assert!(!probe_batch.has_baked_data(BakedDataIdentifier::Pathing {
+                variation: BakedDataVariation::Dynamic,
+            }));
+        }
+
         #[test]
         fn test_add_remove() {
             let context = Context::default();
@@ -666,6 +968,72 @@ mod tests {
             assert_eq!(probe_batch.num_probes(), probe_array.num_probes());
         }
 
+        #[test]
+        fn test_to_debug_spheres() {
+            let context = Context::default();
+            let mut probe_batch = ProbeBatch::try_new(&context).unwrap();
+
+            let probes = [0.0, 1.0, 2.0].map(|x| Sphere {
+                center: Point::new(x, 0.0, 0.0),
+                radius: 1.0,
+            });
+            for probe in probes {
+                probe_batch.add_probe(probe);
+            }
+            probe_batch.remove_probe(1).unwrap();
+            probe_batch.commit();
+
+            assert_eq!(
+                probe_batch.to_debug_spheres(),
+                Some(vec![probes[0], probes[2]])
+            );
+        }
+
+        #[test]
+        fn test_to_debug_spheres_loaded() {
+            let context = Context::default();
+            let mut probe_batch = ProbeBatch::try_new(&context).unwrap();
+            probe_batch.add_probe(Sphere::default());
+            probe_batch.commit();
+
+            let mut serialized_object = SerializedObject::try_new(&context).unwrap();
+            probe_batch.save(&mut serialized_object);
+            let loaded = ProbeBatch::load(&context, &mut serialized_object).unwrap();
+
+            assert_eq!(loaded.to_debug_spheres(), None);
+        }
+
+        #[test]
+        fn test_baked_impulse_response_without_bake() {
+            let context = Context::default();
+            let mut probe_batch = ProbeBatch::try_new(&context).unwrap();
+            probe_batch.add_probe(Sphere::default());
+            probe_batch.commit();
+
+            let identifier = BakedDataIdentifier::Reflections {
+                variation: BakedDataVariation::Reverb,
+            };
+            let settings = ImpulseResponseSettings {
+                duration: 1.0,
+                order: 1,
+                sampling_rate: 48_000,
+            };
+
+            assert_eq!(
+                probe_batch
+                    .baked_impulse_response(&context, identifier, 0, &settings)
+                    .unwrap(),
+                None
+            );
+            assert_eq!(
+                probe_batch.baked_impulse_response(&context, identifier, 1, &settings),
+                Err(ProbeBatchError::ProbeIndexOutOfBounds {
+                    probe_index: 1,
+                    num_probes: 1,
+                })
+            );
+        }
+
         #[test]
         fn test_remove_out_of_bounds() {
             let context = Context::default();
@@ -679,6 +1047,31 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_remove_before_commit() {
+            let context = Context::default();
+            let mut probe_batch = ProbeBatch::try_new(&context).unwrap();
+            for x in [0.0, 1.0, 2.0] {
+                probe_batch.add_probe(Sphere {
+                    center: Point::new(x, 0.0, 0.0),
+                    radius: 1.0,
+                });
+            }
+            probe_batch.commit();
+
+            probe_batch.remove_probe(2).unwrap();
+            probe_batch.remove_probe(1).unwrap();
+            assert_eq!(
+                probe_batch.remove_probe(1),
+                Err(ProbeBatchError::ProbeIndexOutOfBounds {
+                    probe_index: 1,
+                    num_probes: 1,
+                })
+            );
+            probe_batch.commit();
+            assert_eq!(probe_batch.num_probes(), 1);
+        }
+
         #[test]
         fn test_clone() {
             let context = Context::default();
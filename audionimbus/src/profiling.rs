@@ -0,0 +1,220 @@
+//! Profiling hooks for integration with external profilers such as Tracy.
+//!
+//! When the `profiling` feature is enabled, simulation runs (e.g. [`Simulator::run_direct`](crate::Simulator::run_direct)) and effect `apply` calls are wrapped in a [`ProfilingScope`].
+//! Scopes are forwarded to the [`Profiler`] registered with [`set_profiler`], and are no-ops when none has been registered.
+//!
+//! The profiler is global rather than attached to a [`Context`](crate::Context), since effects are applied on the audio thread without access to the context that created them.
+//!
+//! With the `tracy` feature, [`TracyProfiler`] reports scopes to a running [`tracy_client::Client`].
+//!
+//! # Examples
+//!
+//! A custom profiler forwarding scopes to another backend:
+//!
+//! ```
+//! # use audionimbus::profiling::*;
+//! struct LogProfiler;
+//!
+//! impl Profiler for LogProfiler {
+//!     fn begin_scope(&self, name: &'static str) {
+//!         println!("begin {name}");
+//!     }
+//!
+//!     fn end_scope(&self) {
+//!         println!("end");
+//!     }
+//! }
+//!
+//! set_profiler(LogProfiler)?;
+//!
+//! // Scopes can also be opened around application code.
+//! let _scope = ProfilingScope::new("my_audio_callback");
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::marker::PhantomData;
+use std::sync::OnceLock;
+
+static PROFILER: OnceLock<Box<dyn Profiler>> = OnceLock::new();
+
+/// Receives the scopes opened by the library.
+///
+/// Scopes are strictly nested on each thread: every call to [`Profiler::begin_scope`] is matched by a call to [`Profiler::end_scope`] on the same thread, in reverse order.
+pub trait Profiler: Send + Sync + 'static {
+    /// Called when a scope named `name` is entered.
+    fn begin_scope(&self, name: &'static str);
+
+    /// Called when the most recently entered scope on the current thread is exited.
+    fn end_scope(&self);
+}
+
+/// Registers the global profiler.
+///
+/// The profiler can only be set once.
+///
+/// # Errors
+///
+/// Returns [`ProfilerAlreadySetError`] if a profiler has already been registered.
+pub fn set_profiler(profiler: impl Profiler) -> Result<(), ProfilerAlreadySetError> {
+    PROFILER
+        .set(Box::new(profiler))
+        .map_err(|_| ProfilerAlreadySetError)
+}
+
+/// Error returned by [`set_profiler`] when a profiler has already been registered.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ProfilerAlreadySetError;
+
+impl std::error::Error for ProfilerAlreadySetError {}
+
+impl std::fmt::Display for ProfilerAlreadySetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "a profiler has already been set")
+    }
+}
+
+/// A profiling scope, closed when dropped.
+///
+/// Scopes must be dropped on the thread that created them, which is why this type is neither [`Send`] nor [`Sync`].
+#[derive(Debug)]
+pub struct ProfilingScope {
+    profiler: Option<&'static dyn Profiler>,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl ProfilingScope {
+    /// Opens a new scope named `name` with the registered profiler, if any.
+    pub fn new(name: &'static str) -> Self {
+        let profiler = PROFILER.get().map(|profiler| &**profiler);
+
+        if let Some(profiler) = profiler {
+            profiler.begin_scope(name);
+        }
+
+        Self {
+            profiler,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+impl Drop for ProfilingScope {
+    fn drop(&mut self) {
+        if let Some(profiler) = self.profiler {
+            profiler.end_scope();
+        }
+    }
+}
+
+impl std::fmt::Debug for dyn Profiler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Profiler").finish_non_exhaustive()
+    }
+}
+
+/// A [`Profiler`] reporting scopes to Tracy.
+///
+/// Scopes opened while no [`tracy_client::Client`] is running are ignored.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use audionimbus::profiling::*;
+/// tracy_client::Client::start();
+/// set_profiler(TracyProfiler)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "tracy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tracy")))]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct TracyProfiler;
+
+#[cfg(feature = "tracy")]
+thread_local! {
+    static TRACY_SPANS: std::cell::RefCell<Vec<Option<tracy_client::Span>>> =
+        const { std::cell::RefCell::new(Vec::new()) };
+}
+
+#[cfg(feature = "tracy")]
+impl Profiler for TracyProfiler {
+    fn begin_scope(&self, name: &'static str) {
+        let span = tracy_client::Client::running()
+            .map(|client| client.span_alloc(Some(name), "", "", 0, 0));
+        TRACY_SPANS.with_borrow_mut(|spans| spans.push(span));
+    }
+
+    fn end_scope(&self) {
+        TRACY_SPANS.with_borrow_mut(|spans| {
+            spans.pop();
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod profiling_scope {
+        use super::*;
+
+        #[test]
+        fn test_without_profiler() {
+            let scope = ProfilingScope::new("test");
+            drop(scope);
+        }
+
+        #[derive(Debug, PartialEq)]
+        enum Event {
+            Begin(&'static str),
+            End,
+        }
+
+        thread_local! {
+            static EVENTS: std::cell::RefCell<Vec<Event>> =
+                const { std::cell::RefCell::new(Vec::new()) };
+        }
+
+        /// Records the scopes opened on each thread.
+        struct RecordingProfiler;
+
+        impl Profiler for RecordingProfiler {
+            fn begin_scope(&self, name: &'static str) {
+                EVENTS.with_borrow_mut(|events| events.push(Event::Begin(name)));
+            }
+
+            fn end_scope(&self) {
+                EVENTS.with_borrow_mut(|events| events.push(Event::End));
+            }
+        }
+
+        #[test]
+        fn test_with_profiler() {
+            // The profiler is global, but events are recorded per thread, so scopes opened by
+            // tests running concurrently are not recorded here.
+            set_profiler(RecordingProfiler).unwrap();
+
+            {
+                profile_scope!("outer");
+                {
+                    profile_scope!("inner");
+                }
+            }
+
+            EVENTS.with_borrow(|events| {
+                assert_eq!(
+                    events[..],
+                    [
+                        Event::Begin("outer"),
+                        Event::Begin("inner"),
+                        Event::End,
+                        Event::End
+                    ]
+                );
+            });
+            assert_eq!(
+                set_profiler(RecordingProfiler),
+                Err(ProfilerAlreadySetError)
+            );
+        }
+    }
+}
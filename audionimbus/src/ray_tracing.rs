@@ -3,6 +3,7 @@
 use crate::Sealed;
 use crate::callback::CustomRayTracingUserData;
 use crate::device::{EmbreeDevice, RadeonRaysDevice};
+use crate::geometry::{Hit, Ray};
 use std::fmt::Debug;
 use std::sync::Arc;
 
@@ -90,6 +91,102 @@ impl RayTracer for CustomRayTracer {
     }
 }
 
+/// A ray tracer implemented in Rust, to be used by a [`Scene<CustomRayTracer>`](crate::geometry::Scene).
+///
+/// This is typically implemented over an existing acceleration structure (e.g. the BVH of a physics
+/// engine), so that Steam Audio traces rays against the same geometry as the rest of the
+/// application.
+///
+/// Methods are called from within Steam Audio, potentially from multiple threads at once.
+/// A panic in any of them is caught and reported, and the ray is treated as not hitting anything.
+///
+/// Pass an implementation to [`Scene::try_with_custom_ray_tracing`](crate::geometry::Scene::try_with_custom_ray_tracing).
+/// The number of rays passed to the batched methods is set with
+/// [`SimulationSettings::with_custom_ray_tracer`](crate::simulation::SimulationSettings::with_custom_ray_tracer).
+///
+/// # Examples
+///
+/// ```
+/// # use audionimbus::*;
+/// /// A ground plane at y = 0.
+/// struct Ground;
+///
+/// impl CustomRayTracing for Ground {
+///     fn closest_hit(&self, ray: Ray, min_distance: f32, max_distance: f32) -> Option<Hit> {
+///         if ray.direction.y >= 0.0 {
+///             return None;
+///         }
+///         let distance = -ray.origin.y / ray.direction.y;
+///         (min_distance..=max_distance)
+///             .contains(&distance)
+///             .then(|| Hit {
+///                 distance,
+///                 triangle_index: None,
+///                 object_index: None,
+///                 material_index: None,
+///                 normal: Vector3::new(0.0, 1.0, 0.0),
+///                 material: None,
+///             })
+///     }
+///
+///     fn any_hit(&self, ray: Ray, min_distance: f32, max_distance: f32) -> bool {
+///         self.closest_hit(ray, min_distance, max_distance).is_some()
+///     }
+/// }
+///
+/// let context = Context::default();
+/// let scene = Scene::try_with_custom_ray_tracing(&context, Ground)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub trait CustomRayTracing: Send + Sync + 'static {
+    /// Calculates the closest hit along a ray, ignoring intersections outside of
+    /// `[min_distance, max_distance]`.
+    ///
+    /// See [`ClosestHitCallback`](crate::callback::ClosestHitCallback).
+    fn closest_hit(&self, ray: Ray, min_distance: f32, max_distance: f32) -> Option<Hit>;
+
+    /// Calculates whether a ray hits any geometry within `[min_distance, max_distance]`.
+    ///
+    /// See [`AnyHitCallback`](crate::callback::AnyHitCallback).
+    fn any_hit(&self, ray: Ray, min_distance: f32, max_distance: f32) -> bool;
+
+    /// Calculates the closest hit along each ray of a batch.
+    ///
+    /// The default implementation calls [`Self::closest_hit`] for each ray.
+    fn batched_closest_hit(
+        &self,
+        rays: &[Ray],
+        min_distances: &[f32],
+        max_distances: &[f32],
+    ) -> Vec<Option<Hit>> {
+        rays.iter()
+            .zip(min_distances)
+            .zip(max_distances)
+            .map(|((&ray, &min_distance), &max_distance)| {
+                self.closest_hit(ray, min_distance, max_distance)
+            })
+            .collect()
+    }
+
+    /// Calculates whether each ray of a batch hits any geometry.
+    ///
+    /// The default implementation calls [`Self::any_hit`] for each ray.
+    fn batched_any_hit(
+        &self,
+        rays: &[Ray],
+        min_distances: &[f32],
+        max_distances: &[f32],
+    ) -> Vec<bool> {
+        rays.iter()
+            .zip(min_distances)
+            .zip(max_distances)
+            .map(|((&ray, &min_distance), &max_distance)| {
+                self.any_hit(ray, min_distance, max_distance)
+            })
+            .collect()
+    }
+}
+
 /// Callback user data used with a custom ray tracer.
 #[derive(Debug)]
 pub struct CustomCallbackUserData(
@@ -1,6 +1,7 @@
 use crate::context::Context;
 use crate::error::{SteamAudioError, to_option_error};
 use std::hash::{Hash, Hasher};
+use std::path::Path;
 
 #[cfg(doc)]
 use crate::geometry::Scene;
@@ -107,7 +108,11 @@ impl SerializedObject {
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLSerializedObject {
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLSerializedObject {
         &mut self.0
     }
 
@@ -173,6 +178,117 @@ impl Hash for SerializedObject {
     }
 }
 
+/// Types that can be saved to and loaded from a [`SerializedObject`], and therefore from a file.
+///
+/// Implementors only describe how to write themselves to, and read themselves from, a serialized
+/// object; reading and writing files comes for free with [`Self::save_to_file`] and
+/// [`Self::load_from_file`].
+pub trait SteamAudioSerialize: Sized {
+    /// Saves `self` to a serialized object.
+    fn serialize(&self, serialized_object: &mut SerializedObject);
+
+    /// Loads a value from a serialized object.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SteamAudioError`] if loading fails.
+    fn deserialize(
+        context: &Context,
+        serialized_object: &SerializedObject,
+    ) -> Result<Self, SteamAudioError>;
+
+    /// Saves `self` to the file at `path`, replacing it if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// - [`SerializationError::SteamAudio`] if the serialized object cannot be created.
+    /// - [`SerializationError::Io`] if the file cannot be written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let context = Context::default();
+    /// let mut probe_batch = ProbeBatch::try_new(&context)?;
+    /// probe_batch.add_probe(Sphere {
+    ///     center: Point::new(0.0, 1.5, 0.0),
+    ///     radius: 2.0,
+    /// });
+    /// probe_batch.commit();
+    ///
+    /// let path = std::env::temp_dir().join("probes.bin");
+    /// probe_batch.save_to_file(&context, &path)?;
+    ///
+    /// let loaded = ProbeBatch::load_from_file(&context, &path)?;
+    /// assert_eq!(loaded.num_probes(), 1);
+    /// # std::fs::remove_file(path)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn save_to_file(
+        &self,
+        context: &Context,
+        path: impl AsRef<Path>,
+    ) -> Result<(), SerializationError> {
+        let mut serialized_object = SerializedObject::try_new(context)?;
+        self.serialize(&mut serialized_object);
+        std::fs::write(path, serialized_object.to_vec())?;
+
+        Ok(())
+    }
+
+    /// Loads a value from the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// - [`SerializationError::Io`] if the file cannot be read.
+    /// - [`SerializationError::SteamAudio`] if its contents cannot be deserialized.
+    fn load_from_file(
+        context: &Context,
+        path: impl AsRef<Path>,
+    ) -> Result<Self, SerializationError> {
+        let mut buffer = std::fs::read(path)?;
+        let serialized_object = SerializedObject::try_with_buffer(context, &mut buffer)?;
+
+        Ok(Self::deserialize(context, &serialized_object)?)
+    }
+}
+
+/// Errors that can occur when saving a [`SteamAudioSerialize`] type to a file, or loading one
+/// from a file.
+#[derive(Debug)]
+pub enum SerializationError {
+    /// Reading or writing the file failed.
+    Io(std::io::Error),
+
+    /// Steam Audio failed to serialize or deserialize the object.
+    SteamAudio(SteamAudioError),
+}
+
+impl std::error::Error for SerializationError {}
+
+impl std::fmt::Display for SerializationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "I/O error: {error}"),
+            Self::SteamAudio(error) => write!(f, "Steam Audio error: {error}"),
+        }
+    }
+}
+
+impl From<std::io::Error> for SerializationError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<SteamAudioError> for SerializationError {
+    fn from(error: SteamAudioError) -> Self {
+        Self::SteamAudio(error)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -33,6 +33,40 @@
 //! it will block and cause audio glitches.
 //!
 //! Refer to the `wiring` feature to help set up this architecture and avoid common pitfalls.
+//!
+//! # Worker Threads
+//!
+//! Direct and pathing simulations run entirely on the thread calling [`Simulator::run_direct`] or
+//! [`Simulator::run_pathing`].
+//! Reflection simulations additionally use the pool of worker threads that Steam Audio spawns
+//! when the simulator is created.
+//! The size of this pool is set by the `num_threads` field of the reflections settings
+//! ([`ConvolutionSettings::num_threads`], [`ParametricSettings::num_threads`],
+//! [`HybridSettings::num_threads`] or [`TrueAudioNextSettings::num_threads`]).
+//! A value of `0` uses one thread per available core, as reported by
+//! [`std::thread::available_parallelism`], minus one to leave a core free for the audio and main
+//! threads (but always at least one thread).
+//!
+//! Each simulator spawns its own pool; pools are not shared between simulators.
+//! When running several simulators in the same process, divide the available cores between them
+//! to avoid oversubscribing the CPU.
+//!
+//! Steam Audio creates these worker threads itself, and its API exposes no hook to name them or
+//! set their affinity, so they show up unnamed in profilers and debuggers.
+//! To tell them apart from other threads, name the threads that drive simulations (with
+//! [`std::thread::Builder::name`]), and enable the `profiling` feature to get scopes around each
+//! simulation run.
+//!
+//! # Determinism
+//!
+//! Reflections (real-time or baked) are simulated by tracing rays in random directions, and
+//! Steam Audio exposes no way to seed the random number generator.
+//! Running the same simulation twice therefore yields slightly different results, and the
+//! variance decreases as the number of rays increases.
+//! Work distribution across worker threads adds to this variance.
+//!
+//! Regression tests should compare results with a tolerance rather than exactly, e.g. with
+//! [`EnergyField::approx_eq`](crate::EnergyField::approx_eq).
 
 use crate::Sealed;
 use crate::audio_settings::AudioSettings;
@@ -48,28 +82,29 @@ use crate::effect::{
     TrueAudioNext,
 };
 use crate::error::{SteamAudioError, to_option_error};
-use crate::geometry::{CoordinateSystem, Scene};
+use crate::geometry::{CoordinateSystem, Point, Scene};
 use crate::model::air_absorption::AirAbsorptionModel;
 use crate::model::deviation::DeviationModel;
 use crate::model::directivity::Directivity;
 use crate::model::distance_attenuation::DistanceAttenuationModel;
 use crate::probe::ProbeBatch;
 use crate::ray_tracing::{CustomRayTracer, DefaultRayTracer, Embree, RadeonRays, RayTracer};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
-use std::sync::{Arc, Mutex, MutexGuard};
+use std::sync::{Arc, Mutex, MutexGuard, TryLockError};
+use std::time::{Duration, Instant};
 
 /// Marker type indicating that direct sound simulation is enabled.
-#[derive(Default, Copy, Clone, Debug)]
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Direct;
 
 /// Marker type indicating that reflection simulation is enabled.
-#[derive(Default, Copy, Clone, Debug)]
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Reflections;
 
 /// Marker type indicating that pathing simulation is enabled.
-#[derive(Default, Copy, Clone, Debug)]
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Pathing;
 
 /// Provider of [`SimulationFlags`].
@@ -176,6 +211,9 @@ pub struct Simulator<T: RayTracer, D = (), R = (), P = (), RE = ()> {
     /// The maximum duration specified during creation.
     max_duration: Option<f32>,
 
+    /// The maximum Ambisonic order specified during creation.
+    max_order: Option<u32>,
+
     /// Synchronization lock for direct simulation operations.
     direct_lock: Option<Arc<Mutex<()>>>,
 
@@ -185,6 +223,14 @@ pub struct Simulator<T: RayTracer, D = (), R = (), P = (), RE = ()> {
     /// Synchronization lock for pathing simulation operations.
     pathing_lock: Option<Arc<Mutex<()>>>,
 
+    /// Listener from the last call to [`Self::set_shared_inputs_subset`].
+    /// Shared with sources to evaluate their culling distance.
+    listener: Arc<Mutex<Option<CoordinateSystem>>>,
+
+    /// Pathing probe batch of each source, from its last pathing inputs.
+    /// Shared with sources, which record it so that [`Self::run_pathing`] can validate it.
+    pathing_probes: Arc<Mutex<HashMap<audionimbus_sys::IPLSource, SourcePathingProbes>>>,
+
     _open_cl_device: Option<OpenClDevice>,
     _radeon_rays_device: Option<RadeonRaysDevice>,
     _true_audio_next_device: Option<TrueAudioNextDevice>,
@@ -195,6 +241,29 @@ pub struct Simulator<T: RayTracer, D = (), R = (), P = (), RE = ()> {
     _reflection_effect: PhantomData<RE>,
 }
 
+/// Statistics about the last reflections simulation run by a [`Simulator`].
+///
+/// These can be used to adapt the quality of reflections to a performance budget, for example by
+/// lowering [`ReflectionsSharedInputs::num_rays`] when runs take too long.
+///
+/// Steam Audio does not report how many rays it actually traced, so only the number of rays
+/// requested through the shared inputs is available.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReflectionsStats {
+    /// Time spent simulating reflections in Steam Audio.
+    pub duration: Duration,
+
+    /// Number of rays requested by the reflections shared inputs in effect during the run, or
+    /// `None` if they were never set.
+    pub num_rays: Option<u32>,
+
+    /// Number of sources added to the simulator during the run.
+    ///
+    /// This includes sources that were skipped, such as culled sources or sources whose inputs
+    /// do not enable reflections.
+    pub num_sources_added: usize,
+}
+
 /// Shared ownership of [`Simulator`] data across clones.
 #[derive(Debug)]
 struct SimulatorShared<T: RayTracer> {
@@ -205,11 +274,26 @@ struct SimulatorShared<T: RayTracer> {
     /// Pending probe batches to be committed.
     pending_probe_batches: HashMap<audionimbus_sys::IPLProbeBatch, usize>,
 
+    /// Probe batches visible to simulation after the last simulator commit.
+    /// Used to validate the pathing probes of sources before running pathing.
+    committed_probe_batches: HashSet<audionimbus_sys::IPLProbeBatch>,
+
     /// The scene currently visible to simulation after the last simulator commit.
     committed_scene: Option<Scene<T>>,
 
     /// The next scene to become visible after the next simulator commit.
     pending_scene: Option<Scene<T>>,
+
+    /// Reflections shared inputs from the last call to [`Simulator::set_shared_inputs_subset`]
+    /// that included reflections.
+    reflections_shared_inputs: Option<ReflectionsSharedInputs>,
+
+    /// Statistics about the last reflections simulation, if any.
+    last_reflections_stats: Option<ReflectionsStats>,
+
+    /// Sources added to the simulator and not removed since.
+    /// Each pointer holds a reference to the source, released when it is removed.
+    sources: HashSet<audionimbus_sys::IPLSource>,
 }
 
 impl<T: RayTracer> Default for SimulatorShared<T> {
@@ -217,8 +301,20 @@ impl<T: RayTracer> Default for SimulatorShared<T> {
         Self {
             committed_num_probes: 0,
             pending_probe_batches: HashMap::new(),
+            committed_probe_batches: HashSet::new(),
             committed_scene: None,
             pending_scene: None,
+            reflections_shared_inputs: None,
+            last_reflections_stats: None,
+            sources: HashSet::new(),
+        }
+    }
+}
+
+impl<T: RayTracer> Drop for SimulatorShared<T> {
+    fn drop(&mut self) {
+        for mut source in self.sources.drain() {
+            unsafe { audionimbus_sys::iplSourceRelease(&raw mut source) }
         }
     }
 }
@@ -295,9 +391,12 @@ where
             max_num_occlusion_samples: settings.max_num_occlusion_samples(),
             max_num_rays: settings.max_num_rays(),
             max_duration: settings.max_duration(),
+            max_order: settings.max_order(),
             direct_lock,
             reflections_lock,
             pathing_lock,
+            listener: Arc::new(Mutex::new(None)),
+            pathing_probes: Arc::default(),
             _open_cl_device: settings.open_cl_device.clone(),
             _radeon_rays_device: settings.radeon_rays_device.clone(),
             _true_audio_next_device: settings.true_audio_next_device.clone(),
@@ -398,6 +497,9 @@ where
 
     /// Adds a source to the set of sources processed by a simulator in subsequent simulations.
     ///
+    /// The simulator keeps the source alive until it is removed.
+    /// Adding a source that has already been added has no effect.
+    ///
     /// Call [`Self::commit`] after calling this function for the changes to take effect.
     pub fn add_source<SrcD, SrcR, SrcP, SrcRE>(&self, source: &Source<SrcD, SrcR, SrcP, SrcRE>)
     where
@@ -406,9 +508,18 @@ where
         SrcP: PathingCompatible<P> + 'static,
         SrcRE: ReflectionEffectCompatible<SrcR, RE> + 'static,
     {
+        let mut shared = self.shared.lock().unwrap();
+        if shared.sources.contains(&source.raw_ptr()) {
+            return;
+        }
+
         unsafe {
             audionimbus_sys::iplSourceAdd(source.raw_ptr(), self.raw_ptr());
         }
+
+        shared
+            .sources
+            .insert(unsafe { audionimbus_sys::iplSourceRetain(source.raw_ptr()) });
     }
 
     /// Removes a source from the set of sources processed by a simulator in subsequent simulations.
@@ -421,9 +532,68 @@ where
         SrcP: PathingCompatible<P> + 'static,
         SrcRE: ReflectionEffectCompatible<SrcR, RE> + 'static,
     {
+        let mut shared = self.shared.lock().unwrap();
+
         unsafe {
             audionimbus_sys::iplSourceRemove(source.raw_ptr(), self.raw_ptr());
         }
+
+        if let Some(mut source) = shared.sources.take(&source.raw_ptr()) {
+            unsafe { audionimbus_sys::iplSourceRelease(&raw mut source) }
+        }
+
+        self.pathing_probes
+            .lock()
+            .unwrap()
+            .remove(&source.raw_ptr());
+    }
+
+    /// Removes all sources from the set of sources processed by a simulator in subsequent
+    /// simulations.
+    ///
+    /// Call [`Self::commit`] after calling this function for the changes to take effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// # let context = Context::default();
+    /// # let audio_settings = AudioSettings::default();
+    /// # let settings = SimulationSettings::new(&audio_settings).with_direct(DirectSimulationSettings {
+    /// #     max_num_occlusion_samples: 4,
+    /// # });
+    /// let simulator = Simulator::try_new(&context, &settings)?;
+    /// for _ in 0..3 {
+    ///     simulator.add_source(&Source::try_new(&simulator)?);
+    /// }
+    /// simulator.commit();
+    /// assert_eq!(simulator.num_sources(), 3);
+    ///
+    /// // Scene teardown.
+    /// simulator.clear_sources();
+    /// simulator.commit();
+    /// assert_eq!(simulator.num_sources(), 0);
+    /// # Ok::<(), audionimbus::SteamAudioError>(())
+    /// ```
+    pub fn clear_sources(&self) {
+        let mut shared = self.shared.lock().unwrap();
+
+        for mut source in shared.sources.drain() {
+            unsafe {
+                audionimbus_sys::iplSourceRemove(source, self.raw_ptr());
+                audionimbus_sys::iplSourceRelease(&raw mut source);
+            }
+        }
+
+        self.pathing_probes.lock().unwrap().clear();
+    }
+
+    /// Returns the number of sources added to the simulator and not removed since.
+    ///
+    /// Additions and removals are counted as soon as they are made, even before they are
+    /// committed with [`Self::commit`].
+    pub fn num_sources(&self) -> usize {
+        self.shared.lock().unwrap().sources.len()
     }
 
     /// Commits changes to the scene or probe batches used for simulation.
@@ -434,6 +604,50 @@ where
     /// other finishes.
     pub fn commit(&self) {
         let _guards = self.acquire_all_locks();
+        self.commit_locked();
+    }
+
+    /// Commits changes to the scene or probe batches used for simulation, unless a simulation is
+    /// running.
+    ///
+    /// Like [`Self::commit`], except that it returns immediately instead of blocking until
+    /// running simulations finish.
+    /// This is useful to apply changes from a thread that must not stall, such as a game loop,
+    /// and retry on a later frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SimulationError::SimulationRunning`] if any simulation is running on this
+    /// simulator, in which case no changes are committed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// # let context = Context::default();
+    /// # let audio_settings = AudioSettings::default();
+    /// # let settings = SimulationSettings::new(&audio_settings).with_direct(DirectSimulationSettings {
+    /// #     max_num_occlusion_samples: 4,
+    /// # });
+    /// let mut simulator = Simulator::try_new(&context, &settings)?;
+    /// let scene = Scene::try_new(&context)?;
+    /// simulator.set_scene(&scene);
+    ///
+    /// // No simulation is running, so the changes are committed.
+    /// simulator.try_commit()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_commit(&self) -> Result<(), SimulationError> {
+        let _guards = self
+            .try_acquire_all_locks()
+            .ok_or(SimulationError::SimulationRunning)?;
+        self.commit_locked();
+
+        Ok(())
+    }
+
+    /// Commits changes, assuming that all simulation locks are held.
+    fn commit_locked(&self) {
         let simulator = self.raw_ptr();
 
         unsafe { audionimbus_sys::iplSimulatorCommit(self.raw_ptr()) }
@@ -441,6 +655,7 @@ where
         let previous_committed_scene = {
             let mut shared = self.shared.lock().unwrap();
             shared.committed_num_probes = shared.pending_probe_batches.values().sum();
+            shared.committed_probe_batches = shared.pending_probe_batches.keys().copied().collect();
 
             if let Some(pending_scene) = shared.pending_scene.take() {
                 shared.committed_scene.replace(pending_scene)
@@ -617,6 +832,13 @@ where
 
         let _guards = self.acquire_locks_for_flags(simulation_flags);
 
+        *self.listener.lock().unwrap() = Some(shared_inputs.listener);
+        if simulation_flags.contains(SimulationFlags::REFLECTIONS)
+            && let Some(reflections_shared_inputs) = shared_inputs.reflections_shared_inputs
+        {
+            self.shared.lock().unwrap().reflections_shared_inputs = Some(reflections_shared_inputs);
+        }
+
         unsafe {
             audionimbus_sys::iplSimulatorSetSharedInputs(
                 self.raw_ptr(),
@@ -670,6 +892,26 @@ where
         locks.into_iter().map(|lock| lock.lock().unwrap()).collect()
     }
 
+    /// Acquires locks for all simulation types enabled on this simulator, without blocking.
+    ///
+    /// Returns `None` if any of them is held, i.e. if a simulation is running.
+    /// The locks guard no data, so a lock poisoned by a panicking simulation is recovered.
+    fn try_acquire_all_locks(&self) -> Option<Vec<MutexGuard<'_, ()>>> {
+        [
+            self.direct_lock.as_ref(),
+            self.reflections_lock.as_ref(),
+            self.pathing_lock.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|lock| match lock.try_lock() {
+            Ok(guard) => Some(guard),
+            Err(TryLockError::WouldBlock) => None,
+            Err(TryLockError::Poisoned(error)) => Some(error.into_inner()),
+        })
+        .collect()
+    }
+
     /// Returns this simulator's simulation locks in a stable order.
     fn lock_handles(&self) -> Vec<Arc<Mutex<()>>> {
         let mut locks: Vec<_> = [
@@ -699,6 +941,14 @@ where
             return Ok(());
         };
 
+        // Validate irradiance_min_distance.
+        if !is_valid_irradiance_min_distance(reflections_inputs.irradiance_min_distance) {
+            return Err(ParameterValidationError::IrradianceMinDistanceTooSmall {
+                requested: reflections_inputs.irradiance_min_distance,
+                min: MIN_IRRADIANCE_MIN_DISTANCE,
+            });
+        }
+
         // Validate num_rays.
         if let Some(max) = self.max_num_rays
             && reflections_inputs.num_rays > max
@@ -719,6 +969,16 @@ where
             });
         }
 
+        // Validate order.
+        if let Some(max) = self.max_order
+            && reflections_inputs.order > max
+        {
+            return Err(ParameterValidationError::OrderExceedsMax {
+                requested: reflections_inputs.order,
+                max,
+            });
+        }
+
         Ok(())
     }
 
@@ -732,7 +992,11 @@ where
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLSimulator {
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLSimulator {
         &mut self.inner
     }
 }
@@ -742,6 +1006,35 @@ impl<T: RayTracer, D, R, P, RE> Simulator<T, D, R, P, RE> {
     pub const fn audio_settings(&self) -> AudioSettings {
         self.audio_settings
     }
+
+    /// Returns the listener set by the last call to [`Self::set_shared_inputs`] (or one of its
+    /// variants), or `None` if shared inputs were never set.
+    ///
+    /// This is the authoritative listener pose used by subsequent simulations, e.g. to place a
+    /// reverb source at the listener's position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// # let context = Context::default();
+    /// # let audio_settings = AudioSettings::default();
+    /// # let simulation_settings = SimulationSettings::new(&audio_settings)
+    /// #     .with_direct(DirectSimulationSettings { max_num_occlusion_samples: 4 });
+    /// # let simulator = Simulator::try_new(&context, &simulation_settings)?;
+    /// assert_eq!(simulator.listener(), None);
+    ///
+    /// let listener = CoordinateSystem {
+    ///     origin: Point::new(1.0, 2.0, 3.0),
+    ///     ..Default::default()
+    /// };
+    /// simulator.set_shared_direct_inputs(&SimulationSharedInputs::new(listener))?;
+    /// assert_eq!(simulator.listener(), Some(listener));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn listener(&self) -> Option<CoordinateSystem> {
+        *self.listener.lock().unwrap()
+    }
 }
 
 impl<T, R, P, RE> Simulator<T, Direct, R, P, RE>
@@ -803,6 +1096,8 @@ where
     /// This function should not be called from the audio processing thread if occlusion
     /// and/or transmission are enabled, as these calculations can be CPU-intensive.
     pub fn run_direct(&self) {
+        profile_scope!("audionimbus::Simulator::run_direct");
+
         let _guard = self
             .direct_lock
             .as_ref()
@@ -877,6 +1172,19 @@ where
         self.set_shared_inputs_subset::<(), Reflections, (), InD, Reflections, InP>(shared_inputs)
     }
 
+    /// Returns the reflections shared inputs set by the last call to
+    /// [`Self::set_shared_inputs`] (or one of its variants) that included reflections, or `None`
+    /// if they were never set.
+    pub fn reflections_shared_inputs(&self) -> Option<ReflectionsSharedInputs> {
+        self.shared.lock().unwrap().reflections_shared_inputs
+    }
+
+    /// Returns statistics about the last successful call to [`Self::run_reflections`] or
+    /// [`Self::run_reflections_solo`], or `None` if reflections were never simulated.
+    pub fn last_reflections_stats(&self) -> Option<ReflectionsStats> {
+        self.shared.lock().unwrap().last_reflections_stats
+    }
+
     /// Runs a reflections simulation for all sources added to the simulator.
     ///
     /// # Performance Considerations
@@ -892,6 +1200,8 @@ where
     /// [`Simulator::set_scene`] and committed via [`Simulator::commit`] before
     /// running simulations.
     pub fn run_reflections(&self) -> Result<(), SimulationError> {
+        profile_scope!("audionimbus::Simulator::run_reflections");
+
         let _guard = self
             .reflections_lock
             .as_ref()
@@ -899,17 +1209,123 @@ where
             .lock()
             .unwrap();
 
-        let shared = self.shared.lock().unwrap();
+        let mut shared = self.shared.lock().unwrap();
         if shared.committed_scene.is_none() {
             return Err(SimulationError::ReflectionsWithoutScene);
         }
 
+        let start = Instant::now();
         unsafe {
             audionimbus_sys::iplSimulatorRunReflections(self.raw_ptr());
         }
 
+        let stats = ReflectionsStats {
+            duration: start.elapsed(),
+            num_rays: shared
+                .reflections_shared_inputs
+                .map(|inputs| inputs.num_rays),
+            num_sources_added: shared.sources.len(),
+        };
+        shared.last_reflections_stats = Some(stats);
+
         Ok(())
     }
+
+    /// Runs a reflections simulation for a single source, as if it were the only source added to
+    /// the simulator.
+    ///
+    /// This is intended for authoring tools that iterate on the reflections of one source, where
+    /// simulating every source on each iteration would be needlessly slow.
+    /// The outputs of other sources keep the values from the last simulation they took part in.
+    /// The source does not need to be added to the simulator.
+    ///
+    /// # Performance Considerations
+    ///
+    /// Steam Audio cannot simulate a subset of sources, so the other sources are removed from the
+    /// simulator and the changes committed before running the simulation, then restored.
+    /// This has a cost of its own, and blocks all simulations in the meantime, so it is not a
+    /// substitute for [`Self::run_reflections`] at runtime.
+    ///
+    /// The simulator is committed once, before running the simulation, so call [`Self::commit`]
+    /// before this function rather than relying on it to apply pending changes to the scene or
+    /// probe batches.
+    /// The restored sources take effect at the next call to [`Self::commit`], which must be made
+    /// before running other simulations.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SimulationError::ReflectionsWithoutScene`] if no scene was set.
+    pub fn run_reflections_solo<SrcD, SrcR, SrcP, SrcRE>(
+        &self,
+        source: &Source<SrcD, SrcR, SrcP, SrcRE>,
+    ) -> Result<(), SimulationError>
+    where
+        SrcD: DirectCompatible<D> + 'static,
+        SrcR: ReflectionsCompatible<Reflections> + 'static,
+        SrcP: PathingCompatible<P> + 'static,
+        SrcRE: ReflectionEffectCompatible<SrcR, RE> + 'static,
+    {
+        profile_scope!("audionimbus::Simulator::run_reflections_solo");
+
+        let _guards = self.acquire_all_locks();
+
+        let solo = source.raw_ptr();
+        // The simulator holds a reference to each added source, so these pointers stay valid
+        // while the sources are temporarily removed.
+        let (others, is_added) = {
+            let shared = self.shared.lock().unwrap();
+            let others = shared
+                .sources
+                .iter()
+                .copied()
+                .filter(|&other| other != solo)
+                .collect::<Vec<_>>();
+            (others, shared.sources.contains(&solo))
+        };
+
+        unsafe {
+            for &other in &others {
+                audionimbus_sys::iplSourceRemove(other, self.raw_ptr());
+            }
+            if !is_added {
+                audionimbus_sys::iplSourceAdd(solo, self.raw_ptr());
+            }
+        }
+        self.commit_locked();
+
+        let mut shared = self.shared.lock().unwrap();
+        let result = if shared.committed_scene.is_none() {
+            Err(SimulationError::ReflectionsWithoutScene)
+        } else {
+            let start = Instant::now();
+            unsafe {
+                audionimbus_sys::iplSimulatorRunReflections(self.raw_ptr());
+            }
+
+            let stats = ReflectionsStats {
+                duration: start.elapsed(),
+                num_rays: shared
+                    .reflections_shared_inputs
+                    .map(|inputs| inputs.num_rays),
+                num_sources_added: 1,
+            };
+            shared.last_reflections_stats = Some(stats);
+
+            Ok(())
+        };
+        drop(shared);
+
+        unsafe {
+            if !is_added {
+                audionimbus_sys::iplSourceRemove(solo, self.raw_ptr());
+            }
+            for &other in &others {
+                audionimbus_sys::iplSourceAdd(other, self.raw_ptr());
+            }
+        }
+
+        result
+    }
 }
 
 impl<T, D, R, RE> Simulator<T, D, R, Pathing, RE>
@@ -976,7 +1392,18 @@ where
     /// Pathing requires at least one probe batch to be added to the simulator
     /// via [`Simulator::add_probe_batch`] and committed via [`Simulator::commit`] before running
     /// simulations.
+    ///
+    /// Returns [`SimulationError::ProbeBatchNotRegistered`] if the
+    /// [`pathing_probes`](PathingSimulationParameters::pathing_probes) of a source added to the
+    /// simulator were not committed to it.
+    ///
+    /// Returns [`SimulationError::NoBakedPaths`] if the
+    /// [`pathing_probes`](PathingSimulationParameters::pathing_probes) of a source with
+    /// [`enable_validation`](PathingSimulationParameters::enable_validation) set contain no baked
+    /// pathing data.
     pub fn run_pathing(&self) -> Result<(), SimulationError> {
+        profile_scope!("audionimbus::Simulator::run_pathing");
+
         let _guard = self
             .pathing_lock
             .as_ref()
@@ -989,6 +1416,29 @@ where
             return Err(SimulationError::PathingWithoutProbes);
         }
 
+        // Steam Audio silently produces no pathing outputs for probe batches it does not know
+        // about, so catch this here rather than failing silently.
+        let pathing_probes = self.pathing_probes.lock().unwrap();
+        let all_registered = shared.sources.iter().all(|source| {
+            pathing_probes
+                .get(source)
+                .is_none_or(|probes| shared.committed_probe_batches.contains(&probes.probe_batch))
+        });
+        if !all_registered {
+            return Err(SimulationError::ProbeBatchNotRegistered);
+        }
+
+        // Likewise, paths cannot be validated when none were baked.
+        let all_baked = shared.sources.iter().all(|source| {
+            pathing_probes
+                .get(source)
+                .is_none_or(|probes| !probes.enable_validation || probes.has_baked_paths())
+        });
+        if !all_baked {
+            return Err(SimulationError::NoBakedPaths);
+        }
+        drop(pathing_probes);
+
         unsafe {
             audionimbus_sys::iplSimulatorRunPathing(self.raw_ptr());
         }
@@ -1026,9 +1476,12 @@ where
             max_num_occlusion_samples: self.max_num_occlusion_samples,
             max_num_rays: self.max_num_rays,
             max_duration: self.max_duration,
+            max_order: self.max_order,
             direct_lock: self.direct_lock.clone(),
             reflections_lock: self.reflections_lock.clone(),
             pathing_lock: self.pathing_lock.clone(),
+            listener: Arc::clone(&self.listener),
+            pathing_probes: Arc::clone(&self.pathing_probes),
             _open_cl_device: self._open_cl_device.clone(),
             _radeon_rays_device: self._radeon_rays_device.clone(),
             _true_audio_next_device: self._true_audio_next_device.clone(),
@@ -1365,6 +1818,18 @@ impl<T: RayTracer, D, R, P, RE> SimulationSettings<T, D, R, P, RE> {
             None
         }
     }
+
+    /// Returns the maximum Ambisonic order allowed, if reflections simulation is enabled.
+    const fn max_order(&self) -> Option<u32> {
+        if self.settings.flags.0
+            & audionimbus_sys::IPLSimulationFlags::IPL_SIMULATIONFLAGS_REFLECTIONS.0
+            != 0
+        {
+            Some(self.settings.maxOrder as u32)
+        } else {
+            None
+        }
+    }
 }
 
 // SAFETY: The raw pointers in IPLSimulationSettings point to devices owned by the struct and
@@ -1428,6 +1893,8 @@ pub struct ConvolutionSettings {
     pub max_num_sources: u32,
 
     /// The number of threads used for real-time reflection simulations.
+    ///
+    /// See [Worker Threads](crate::simulation#worker-threads), including the meaning of `0`.
     pub num_threads: u32,
 
     /// The maximum Ambisonic order of impulse responses generated by reflection simulations.
@@ -1467,7 +1934,7 @@ impl ReflectionsAlgorithm for ConvolutionSettings {
         settings.numDiffuseSamples = self.num_diffuse_samples as i32;
         settings.maxDuration = self.max_duration;
         settings.maxNumSources = self.max_num_sources as i32;
-        settings.numThreads = self.num_threads as i32;
+        settings.numThreads = resolve_num_threads(self.num_threads);
         settings.maxOrder = self.max_order as i32;
 
         SimulationSettings {
@@ -1518,6 +1985,8 @@ pub struct ParametricSettings {
     pub max_num_sources: u32,
 
     /// The number of threads used for real-time reflection simulations.
+    ///
+    /// See [Worker Threads](crate::simulation#worker-threads), including the meaning of `0`.
     pub num_threads: u32,
 
     /// The maximum Ambisonic order of impulse responses generated by reflection simulations.
@@ -1557,7 +2026,7 @@ impl ReflectionsAlgorithm for ParametricSettings {
         settings.numDiffuseSamples = self.num_diffuse_samples as i32;
         settings.maxDuration = self.max_duration;
         settings.maxNumSources = self.max_num_sources as i32;
-        settings.numThreads = self.num_threads as i32;
+        settings.numThreads = resolve_num_threads(self.num_threads);
         settings.maxOrder = self.max_order as i32;
 
         SimulationSettings {
@@ -1595,6 +2064,8 @@ pub struct HybridSettings {
     pub max_num_sources: u32,
 
     /// The number of threads used for real-time reflection simulations.
+    ///
+    /// See [Worker Threads](crate::simulation#worker-threads), including the meaning of `0`.
     pub num_threads: u32,
 
     /// The maximum Ambisonic order of impulse responses generated by reflection simulations.
@@ -1634,7 +2105,7 @@ impl ReflectionsAlgorithm for HybridSettings {
         settings.numDiffuseSamples = self.num_diffuse_samples as i32;
         settings.maxDuration = self.max_duration;
         settings.maxNumSources = self.max_num_sources as i32;
-        settings.numThreads = self.num_threads as i32;
+        settings.numThreads = resolve_num_threads(self.num_threads);
         settings.maxOrder = self.max_order as i32;
 
         SimulationSettings {
@@ -1672,6 +2143,8 @@ pub struct TrueAudioNextSettings {
     pub max_num_sources: u32,
 
     /// The number of threads used for real-time reflection simulations.
+    ///
+    /// See [Worker Threads](crate::simulation#worker-threads), including the meaning of `0`.
     pub num_threads: u32,
 
     /// The OpenCL device being used.
@@ -1717,7 +2190,7 @@ impl ReflectionsAlgorithm for TrueAudioNextSettings {
         settings.numDiffuseSamples = self.num_diffuse_samples as i32;
         settings.maxDuration = self.max_duration;
         settings.maxNumSources = self.max_num_sources as i32;
-        settings.numThreads = self.num_threads as i32;
+        settings.numThreads = resolve_num_threads(self.num_threads);
         settings.maxOrder = self.max_order as i32;
 
         SimulationSettings {
@@ -1734,6 +2207,18 @@ impl ReflectionsAlgorithm for TrueAudioNextSettings {
     }
 }
 
+/// Resolves the number of reflection simulation threads, mapping `0` to the available parallelism
+/// minus one core reserved for the audio and main threads.
+fn resolve_num_threads(num_threads: u32) -> i32 {
+    let num_threads = if num_threads == 0 {
+        std::thread::available_parallelism().map_or(1, |n| n.get().saturating_sub(1).max(1))
+    } else {
+        num_threads as usize
+    };
+
+    i32::try_from(num_threads).unwrap_or(i32::MAX)
+}
+
 impl Sealed for ConvolutionSettings {}
 impl Sealed for ParametricSettings {}
 impl Sealed for HybridSettings {}
@@ -1859,6 +2344,17 @@ pub struct Source<D = (), R = (), P = (), RE = ()> {
     /// Used to synchronize access to pathing simulation data.
     pathing_lock: Option<Arc<Mutex<()>>>,
 
+    /// Reference to the simulator's last known listener.
+    /// Used to evaluate the culling distance.
+    listener: Arc<Mutex<Option<CoordinateSystem>>>,
+
+    /// Reference to the simulator's pathing probe batch of each source.
+    /// Updated when pathing inputs are set, for the simulator to validate them.
+    pathing_probes: Arc<Mutex<HashMap<audionimbus_sys::IPLSource, SourcePathingProbes>>>,
+
+    /// Output buffers reused across calls to [`Self::get_outputs_subset`].
+    outputs_cache: Arc<OutputsCache>,
+
     _direct: PhantomData<D>,
     _reflections: PhantomData<R>,
     _pathing: PhantomData<P>,
@@ -1872,9 +2368,27 @@ struct SourceShared {
     /// Only used when pathing simulation is enabled.
     deviation_model: Option<DeviationModel>,
 
+    /// FFI representation of [`Self::deviation_model`].
+    ///
+    /// Steam Audio keeps the `deviationModel` pointer passed to `iplSourceSetInputs` and
+    /// dereferences it when running pathing simulations, so it is boxed to give it a stable
+    /// address owned by the source.
+    ffi_deviation_model: Option<Box<audionimbus_sys::IPLDeviationModel>>,
+
     /// When pathing is enabled, a reference to the probe batch within which to find paths.
     /// It keeps the probe batch alive as long as the source is alive.
     _pathing_probes: Option<ProbeBatch>,
+
+    /// Distance from the listener beyond which the source is excluded from simulations.
+    culling_distance: Option<f32>,
+
+    /// Whether the source was beyond its culling distance when its inputs were last set.
+    culled: bool,
+
+    /// Inputs last passed to Steam Audio for direct, reflections and pathing simulation
+    /// respectively, along with whether the source was culled at the time.
+    /// Used to skip redundant calls to `iplSourceSetInputs`.
+    last_inputs: [Option<(bool, SimulationInputs)>; 3],
 }
 
 impl<D, R, P, RE> Source<D, R, P, RE>
@@ -1944,6 +2458,24 @@ where
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     ///
+    /// The source's simulation flags are derived from its type parameters, and must be a subset of
+    /// those enabled on the simulator.
+    /// Requesting a type of simulation the simulator was not created with is a compile-time
+    /// error rather than a crash in Steam Audio:
+    ///
+    /// ```compile_fail
+    /// # use audionimbus::*;
+    /// # let context = Context::default();
+    /// # let audio_settings = AudioSettings::default();
+    /// let simulation_settings = SimulationSettings::new(&audio_settings)
+    ///     .with_direct(DirectSimulationSettings { max_num_occlusion_samples: 4 });
+    /// let simulator = Simulator::try_new(&context, &simulation_settings)?;
+    ///
+    /// // The simulator does not support reflections.
+    /// let source = Source::<Direct, Reflections, ()>::try_new_subset(&simulator)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
     /// # Errors
     ///
     /// Returns [`SteamAudioError`] if creation fails.
@@ -1982,6 +2514,11 @@ where
         let direct_lock = simulator.direct_lock.clone();
         let reflections_lock = simulator.reflections_lock.clone();
         let pathing_lock = simulator.pathing_lock.clone();
+        let listener = Arc::clone(&simulator.listener);
+        let pathing_probes = Arc::clone(&simulator.pathing_probes);
+
+        // A previous source may have had the same address.
+        pathing_probes.lock().unwrap().remove(&inner);
 
         let source = Self {
             inner,
@@ -1990,6 +2527,9 @@ where
             direct_lock,
             reflections_lock,
             pathing_lock,
+            listener,
+            pathing_probes,
+            outputs_cache: Arc::default(),
             _direct: PhantomData,
             _reflections: PhantomData,
             _pathing: PhantomData,
@@ -2118,6 +2658,16 @@ where
     ///
     /// Returns [`ParameterValidationError`] if any parameters exceed the maximums
     /// set during simulator initialization.
+    ///
+    /// # Redundant calls
+    ///
+    /// The source remembers the inputs last set for each simulation type.
+    /// If the inputs are equal to those, and the source is still on the same side of its culling
+    /// distance, this returns without calling into Steam Audio, so static sources can call it
+    /// every frame at little cost.
+    /// Callbacks compare equal when they are clones of one another.
+    /// Inputs holding a callback model flagged as `dirty`, a directivity callback or a deviation
+    /// callback are always forwarded, since the callback may have changed without being replaced.
     pub fn set_inputs_subset<SubD, SubR, SubP, InD, InR, InP>(
         &self,
         inputs: &SimulationInputs<InD, InR, InP>,
@@ -2134,17 +2684,98 @@ where
 
         let simulation_flags = SubD::flags() | SubR::flags() | SubP::flags();
 
-        let mut ffi_inputs = inputs.to_ffi();
+        let _guards = self.acquire_locks_for_flags(simulation_flags);
 
         let mut shared = self.shared.lock().unwrap();
-        (shared.deviation_model, shared._pathing_probes) = inputs
-            .parameters
-            .pathing_simulation
-            .as_ref()
-            .map(|p| (Some(p.deviation.clone()), Some(p.pathing_probes.clone())))
-            .unwrap_or_default();
 
-        let _guards = self.acquire_locks_for_flags(simulation_flags);
+        // Steam Audio skips sources whose input flags are cleared when running simulations.
+        let listener_position = self
+            .listener
+            .lock()
+            .unwrap()
+            .map(|listener| listener.origin);
+        let culled = is_beyond_culling_distance(
+            inputs.source.origin,
+            listener_position,
+            shared.culling_distance,
+        );
+        shared.culled = culled;
+
+        // Skip the FFI call if Steam Audio already holds these exact inputs for every requested
+        // simulation type.
+        let last_inputs = Some((culled, inputs.without_markers()));
+        let requested = [
+            SimulationFlags::DIRECT,
+            SimulationFlags::REFLECTIONS,
+            SimulationFlags::PATHING,
+        ]
+        .map(|flag| simulation_flags.contains(flag));
+        let unchanged = !inputs.has_dirty_model()
+            && requested
+                .iter()
+                .zip(&shared.last_inputs)
+                .all(|(&requested, last)| !requested || *last == last_inputs);
+        if unchanged {
+            return Ok(());
+        }
+        for (&requested, last) in requested.iter().zip(&mut shared.last_inputs) {
+            if requested {
+                last.clone_from(&last_inputs);
+            }
+        }
+
+        let mut ffi_inputs = inputs.to_ffi();
+
+        // Steam Audio only reads the pathing inputs when the pathing flag is set. Otherwise, the
+        // deviation model and probes from a previous call may still be referenced by pathing
+        // simulations and must be kept alive.
+        // Replacing them is safe here: the pathing lock, if any, is held, so no pathing
+        // simulation can be reading them concurrently.
+        if simulation_flags.contains(SimulationFlags::PATHING) {
+            (shared.deviation_model, shared._pathing_probes) = inputs
+                .parameters
+                .pathing_simulation
+                .as_ref()
+                .map(|p| (Some(p.deviation.clone()), Some(p.pathing_probes.clone())))
+                .unwrap_or_default();
+
+            let mut pathing_probes = self.pathing_probes.lock().unwrap();
+            match &shared._pathing_probes {
+                Some(probe_batch) => pathing_probes.insert(
+                    self.raw_ptr(),
+                    SourcePathingProbes {
+                        probe_batch: probe_batch.raw_ptr(),
+                        enable_validation: inputs
+                            .parameters
+                            .pathing_simulation
+                            .as_ref()
+                            .is_some_and(|p| p.enable_validation),
+                    },
+                ),
+                None => pathing_probes.remove(&self.raw_ptr()),
+            };
+            drop(pathing_probes);
+
+            // The FFI deviation model must be built from the source-owned copy, since its
+            // `userData` points into the callback it was created from.
+            let ffi_deviation_model = audionimbus_sys::IPLDeviationModel::from(
+                shared
+                    .deviation_model
+                    .as_ref()
+                    .unwrap_or(&DeviationModel::Default),
+            );
+
+            // The box is reused across calls so that the pointer held by Steam Audio stays valid.
+            let boxed_ffi_deviation_model = shared
+                .ffi_deviation_model
+                .get_or_insert_with(|| Box::new(ffi_deviation_model));
+            **boxed_ffi_deviation_model = ffi_deviation_model;
+            ffi_inputs.deviationModel = &mut **boxed_ffi_deviation_model;
+        }
+
+        if shared.culled {
+            ffi_inputs.flags = audionimbus_sys::IPLSimulationFlags(0);
+        }
 
         unsafe {
             audionimbus_sys::iplSourceSetInputs(
@@ -2194,6 +2825,9 @@ where
     /// MUST NOT be called from a real-time audio thread.
     /// See the [module-level documentation](crate::simulation) for threading guidelines.
     ///
+    /// The memory backing [`SimulationOutputs`] is returned to the source when they are dropped,
+    /// and reused by subsequent calls, so that retrieving outputs every frame does not allocate.
+    ///
     /// # Errors
     ///
     /// Returns a [`SteamAudioError`] on failure to allocate sufficient memory for the
@@ -2291,17 +2925,73 @@ where
 }
 
 impl<D, R, P, RE> Source<D, R, P, RE> {
-    /// Returns the raw FFI pointer to the underlying source.
+    /// Sets the distance from the listener beyond which this source is excluded from simulations.
     ///
-    /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr(&self) -> audionimbus_sys::IPLSource {
-        self.inner
+    /// Steam Audio does not cull sources on its own, so distant sources would otherwise cost as
+    /// much CPU time as nearby ones.
+    /// Culling is evaluated whenever the source's inputs are set, against the listener position
+    /// from the last call to [`Simulator::set_shared_inputs`] (or one of its variants).
+    /// While culled, the source is skipped by [`Simulator::run_direct`],
+    /// [`Simulator::run_reflections`] and [`Simulator::run_pathing`], and its outputs keep the
+    /// values from the last simulation it took part in.
+    ///
+    /// Pass `None` to disable culling, which is the default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// # let context = Context::default();
+    /// # let audio_settings = AudioSettings::default();
+    /// # let simulation_settings = SimulationSettings::new(&audio_settings)
+    /// #     .with_direct(DirectSimulationSettings { max_num_occlusion_samples: 4 });
+    /// # let simulator = Simulator::try_new(&context, &simulation_settings)?;
+    /// let source = Source::try_new(&simulator)?;
+    /// source.set_culling_distance(Some(100.0));
+    ///
+    /// simulator.set_shared_direct_inputs(&SimulationSharedInputs::new(CoordinateSystem::default()))?;
+    /// let inputs = SimulationInputs::new(CoordinateSystem {
+    ///     origin: Point::new(500.0, 0.0, 0.0),
+    ///     ..Default::default()
+    /// })
+    /// .with_direct(DirectSimulationParameters::new());
+    /// source.set_direct_inputs(&inputs)?;
+    /// assert!(source.is_culled());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_culling_distance(&self, distance: Option<f32>) {
+        self.shared.lock().unwrap().culling_distance = distance;
+    }
+
+    /// Returns the distance beyond which this source is culled, if any.
+    ///
+    /// See [`Self::set_culling_distance`].
+    pub fn culling_distance(&self) -> Option<f32> {
+        self.shared.lock().unwrap().culling_distance
+    }
+
+    /// Returns `true` if the source was beyond its culling distance when its inputs were last set.
+    ///
+    /// Effects for culled sources can typically be skipped as well.
+    pub fn is_culled(&self) -> bool {
+        self.shared.lock().unwrap().culled
+    }
+
+    /// Returns the raw FFI pointer to the underlying source.
+    ///
+    /// This is intended for internal use and advanced scenarios.
+    pub const fn raw_ptr(&self) -> audionimbus_sys::IPLSource {
+        self.inner
     }
 
     /// Returns a mutable reference to the raw FFI pointer.
     ///
     /// This is intended for internal use and advanced scenarios.
-    pub const fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLSource {
+    ///
+    /// # Safety
+    ///
+    /// Callers must uphold the ownership rules described in [Raw Pointers](crate#raw-pointers).
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut audionimbus_sys::IPLSource {
         &mut self.inner
     }
 }
@@ -2532,6 +3222,162 @@ where
     }
 }
 
+/// A [`Source`] that follows the listener, used to simulate listener-centric reverb.
+///
+/// Listener-centric reverb is simulated by placing a source at the listener's position and
+/// simulating reflections for it.
+/// `ReverbSource` takes care of the placement: [`Self::update`] sets the source's inputs using the
+/// listener from the last call to [`Simulator::set_shared_inputs`] (or one of its variants), so
+/// that the two cannot get out of sync.
+///
+/// # Examples
+///
+/// ```
+/// # use audionimbus::*;
+/// # let context = Context::default();
+/// # let audio_settings = AudioSettings::default();
+/// let simulation_settings = SimulationSettings::new(&audio_settings).with_reflections(
+///     ConvolutionSettings {
+///         max_num_rays: 4096,
+///         num_diffuse_samples: 32,
+///         max_duration: 2.0,
+///         max_num_sources: 8,
+///         num_threads: 1,
+///         max_order: 1,
+///     },
+/// );
+/// let simulator = Simulator::try_new(&context, &simulation_settings)?;
+///
+/// let reverb_source = ReverbSource::try_new(
+///     &simulator,
+///     ConvolutionParameters {
+///         baked_data_identifier: None,
+///     },
+/// )?;
+/// simulator.add_source(reverb_source.source());
+/// simulator.commit();
+///
+/// // On every simulation update.
+/// let listener = CoordinateSystem {
+///     origin: Point::new(1.0, 2.0, 3.0),
+///     ..Default::default()
+/// };
+/// let shared_inputs = SimulationSharedInputs::new(listener).with_reflections(ReflectionsSharedInputs {
+///     num_rays: 4096,
+///     num_bounces: 16,
+///     duration: 2.0,
+///     order: 1,
+///     irradiance_min_distance: 1.0,
+/// });
+/// simulator.set_shared_reflections_inputs(&shared_inputs)?;
+/// reverb_source.update()?;
+/// // simulator.run_reflections()?;
+///
+/// // Parameters to render the reverb with a `ReflectionEffect`.
+/// let reverb_params = reverb_source.outputs()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReverbSource<RE = ()> {
+    source: Source<(), Reflections, (), RE>,
+    parameters: SimulationParameters<(), Reflections, ()>,
+}
+
+impl<RE> ReverbSource<RE>
+where
+    RE: 'static + ReflectionEffectType + ReflectionEffectCompatible<Reflections, RE>,
+{
+    /// Creates a new reverb source, simulated with the given reflections parameters.
+    ///
+    /// The source must still be added to the simulator with [`Simulator::add_source`], using
+    /// [`Self::source`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SteamAudioError`] if creation fails.
+    pub fn try_new<T, SimD, SimP, Params>(
+        simulator: &Simulator<T, SimD, Reflections, SimP, RE>,
+        parameters: Params,
+    ) -> Result<Self, SteamAudioError>
+    where
+        T: RayTracer,
+        SimD: 'static,
+        SimP: 'static,
+        Params: ReflectionsSimulationParameters,
+        (): DirectCompatible<SimD> + PathingCompatible<SimP>,
+    {
+        let source = Source::try_new_subset::<T, SimD, Reflections, SimP, RE>(simulator)?;
+        let parameters = SimulationParameters::new().with_reflections(parameters);
+
+        Ok(Self { source, parameters })
+    }
+
+    /// Sets the reverb source's inputs, placing it at the current listener.
+    ///
+    /// Call this after [`Simulator::set_shared_inputs`] (or one of its variants), and before
+    /// [`Simulator::run_reflections`].
+    /// The same threading considerations as [`Source::set_inputs`] apply.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReverbSourceError::ListenerNotSet`] if shared inputs were never set, or
+    /// [`ReverbSourceError::ParameterValidation`] if the reflections parameters exceed the
+    /// maximums set during simulator initialization.
+    pub fn update(&self) -> Result<(), ReverbSourceError> {
+        let listener = self
+            .source
+            .listener
+            .lock()
+            .unwrap()
+            .ok_or(ReverbSourceError::ListenerNotSet)?;
+
+        let inputs = SimulationInputs {
+            source: listener,
+            parameters: self.parameters.clone(),
+        };
+        self.source.set_reflections_inputs(&inputs)?;
+
+        Ok(())
+    }
+
+    /// Retrieves the reflections simulation results, to render listener-centric reverb.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SteamAudioError`] on failure to allocate sufficient memory for the
+    /// [`SimulationOutputs`].
+    pub fn outputs(&self) -> Result<ReflectionEffectParams<RE>, SteamAudioError> {
+        self.source.get_reflections_outputs()
+    }
+
+    /// Returns the underlying source.
+    ///
+    /// It is typically used to add the reverb source to the simulator, or to register it with
+    /// a game engine integration.
+    pub const fn source(&self) -> &Source<(), Reflections, (), RE> {
+        &self.source
+    }
+}
+
+/// Returns `true` if `source` is farther than `culling_distance` from `listener`.
+///
+/// Sources are never culled when either the culling distance or the listener position is unknown.
+fn is_beyond_culling_distance(
+    source: Point,
+    listener: Option<Point>,
+    culling_distance: Option<f32>,
+) -> bool {
+    let (Some(listener), Some(culling_distance)) = (listener, culling_distance) else {
+        return false;
+    };
+
+    let dx = source.x - listener.x;
+    let dy = source.y - listener.y;
+    let dz = source.z - listener.z;
+
+    dx * dx + dy * dy + dz * dz > culling_distance * culling_distance
+}
+
 impl<D, R, P, RE> Drop for Source<D, R, P, RE> {
     fn drop(&mut self) {
         unsafe { audionimbus_sys::iplSourceRelease(&raw mut self.inner) }
@@ -2554,6 +3400,9 @@ impl<D, R, P, RE> Clone for Source<D, R, P, RE> {
             direct_lock: self.direct_lock.clone(),
             reflections_lock: self.reflections_lock.clone(),
             pathing_lock: self.pathing_lock.clone(),
+            listener: Arc::clone(&self.listener),
+            pathing_probes: Arc::clone(&self.pathing_probes),
+            outputs_cache: Arc::clone(&self.outputs_cache),
             _direct: PhantomData,
             _reflections: PhantomData,
             _pathing: PhantomData,
@@ -2577,7 +3426,7 @@ impl<D, R, P, RE> Hash for Source<D, R, P, RE> {
 }
 
 /// Simulation inputs for a source.
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct SimulationInputs<D = (), R = (), P = ()> {
     /// The position and orientation of this source.
     pub source: CoordinateSystem,
@@ -2586,7 +3435,107 @@ pub struct SimulationInputs<D = (), R = (), P = ()> {
     pub parameters: SimulationParameters<D, R, P>,
 }
 
+impl SimulationInputs {
+    /// Creates new [`SimulationInputs`] for a source with the given position and orientation.
+    ///
+    /// No simulation is enabled initially; use [`Self::with_direct`], [`Self::with_reflections`]
+    /// and [`Self::with_pathing`] to enable them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let inputs = SimulationInputs::new(CoordinateSystem::default())
+    ///     .with_direct(
+    ///         DirectSimulationParameters::new()
+    ///             .with_distance_attenuation(DistanceAttenuationModel::default()),
+    ///     )
+    ///     .with_reflections(ConvolutionParameters {
+    ///         baked_data_identifier: None,
+    ///     });
+    /// ```
+    pub fn new(source: CoordinateSystem) -> Self {
+        Self {
+            source,
+            parameters: SimulationParameters::new(),
+        }
+    }
+}
+
 impl<D, R, P> SimulationInputs<D, R, P> {
+    /// Enables direct simulation with the specified parameters.
+    pub fn with_direct(self, params: DirectSimulationParameters) -> SimulationInputs<Direct, R, P> {
+        SimulationInputs {
+            source: self.source,
+            parameters: self.parameters.with_direct(params),
+        }
+    }
+
+    /// Enables reflections simulation with the specified parameters.
+    pub fn with_reflections<Params: ReflectionsSimulationParameters>(
+        self,
+        params: Params,
+    ) -> SimulationInputs<D, Reflections, P> {
+        SimulationInputs {
+            source: self.source,
+            parameters: self.parameters.with_reflections(params),
+        }
+    }
+
+    /// Enables pathing simulation with the specified parameters.
+    pub fn with_pathing(
+        self,
+        params: PathingSimulationParameters,
+    ) -> SimulationInputs<D, R, Pathing> {
+        SimulationInputs {
+            source: self.source,
+            parameters: self.parameters.with_pathing(params),
+        }
+    }
+
+    /// Returns a copy of the inputs without the simulation type markers.
+    fn without_markers(&self) -> SimulationInputs {
+        SimulationInputs {
+            source: self.source,
+            parameters: SimulationParameters {
+                direct_simulation: self.parameters.direct_simulation.clone(),
+                reflections_simulation: self.parameters.reflections_simulation,
+                pathing_simulation: self.parameters.pathing_simulation.clone(),
+                _direct: PhantomData,
+                _reflections: PhantomData,
+                _pathing: PhantomData,
+            },
+        }
+    }
+
+    /// Returns whether a callback-based model may have changed.
+    ///
+    /// Steam Audio must be notified of such changes even if the inputs are otherwise unchanged.
+    /// Directivity and deviation callbacks have no `dirty` flag, so they are always assumed to
+    /// have changed.
+    fn has_dirty_model(&self) -> bool {
+        let direct_dirty = self
+            .parameters
+            .direct_simulation
+            .as_ref()
+            .is_some_and(|direct| {
+                matches!(
+                    direct.distance_attenuation,
+                    Some(DistanceAttenuationModel::Callback { dirty: true, .. })
+                ) || matches!(
+                    direct.air_absorption,
+                    Some(AirAbsorptionModel::Callback { dirty: true, .. })
+                ) || matches!(direct.directivity, Some(Directivity::Callback(_)))
+            });
+        let pathing_dirty = self
+            .parameters
+            .pathing_simulation
+            .as_ref()
+            .is_some_and(|pathing| matches!(pathing.deviation, DeviationModel::Callback(_)));
+
+        direct_dirty || pathing_dirty
+    }
+
     fn to_ffi(&self) -> audionimbus_sys::IPLSimulationInputs {
         let mut flags = audionimbus_sys::IPLSimulationFlags(0);
 
@@ -2631,13 +3580,37 @@ impl<D, R, P> SimulationInputs<D, R, P> {
             pathingOrder: pathing_data.pathing_order,
             enableValidation: pathing_data.enable_validation,
             findAlternatePaths: pathing_data.find_alternate_paths,
-            deviationModel: &pathing_data.deviation_model as *const _ as *mut _,
+            // Set by `Source::set_inputs_subset`, which owns the deviation model.
+            deviationModel: std::ptr::null_mut(),
+        }
+    }
+}
+
+/// Pathing probe batch of a source, recorded from its last pathing inputs.
+#[derive(Clone, Copy, Debug)]
+struct SourcePathingProbes {
+    probe_batch: audionimbus_sys::IPLProbeBatch,
+    enable_validation: bool,
+}
+
+impl SourcePathingProbes {
+    /// Returns `true` if the probe batch contains the baked pathing data that pathing
+    /// simulations read.
+    fn has_baked_paths(&self) -> bool {
+        let mut identifier: audionimbus_sys::IPLBakedDataIdentifier =
+            BakedDataIdentifier::Pathing {
+                variation: BakedDataVariation::Dynamic,
+            }
+            .into();
+
+        unsafe {
+            audionimbus_sys::iplProbeBatchGetDataSize(self.probe_batch, &raw mut identifier) > 0
         }
     }
 }
 
 /// Simulation parameters for a source.
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct SimulationParameters<D = (), R = (), P = ()> {
     /// If `Some`, enables direct simulation.
     /// This includes distance attenuation, air absorption, directivity, occlusion, and transmission.
@@ -2770,7 +3743,7 @@ impl<D, R> SimulationParameters<D, R, Pathing> {
 }
 
 /// Direct simulation parameters for a source.
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct DirectSimulationParameters {
     /// If `Some`, enables distance attenuation calculations with the specified model.
     pub distance_attenuation: Option<DistanceAttenuationModel>,
@@ -2822,7 +3795,7 @@ impl DirectSimulationParameters {
 }
 
 /// Occlusion parameters.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Occlusion {
     /// If `Some`, enables transmission simulation.
     pub transmission: Option<TransmissionParameters>,
@@ -2847,8 +3820,24 @@ impl Occlusion {
     }
 }
 
+impl From<OcclusionAlgorithm> for Occlusion {
+    /// Creates an [`Occlusion`] with the given algorithm and transmission simulation disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// let parameters = DirectSimulationParameters::new()
+    ///     .with_occlusion(OcclusionAlgorithm::Raycast.into());
+    /// assert!(parameters.occlusion.is_some_and(|occlusion| occlusion.transmission.is_none()));
+    /// ```
+    fn from(algorithm: OcclusionAlgorithm) -> Self {
+        Self::new(algorithm)
+    }
+}
+
 /// Transmission parameters.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct TransmissionParameters {
     /// If simulating transmission, this is the maximum number of surfaces, starting from the closest surface to the listener, whose transmission coefficients will be considered when calculating the total amount of sound transmitted.
     /// Increasing this value will result in more accurate results when multiple surfaces lie between the source and the listener, at the cost of increased CPU usage.
@@ -3028,9 +4017,15 @@ impl IntoSimulationData for TrueAudioNextParameters {
 }
 
 /// Pathing simulation parameters for a source.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct PathingSimulationParameters {
     /// The probe batch within which to find paths from this source to the listener.
+    ///
+    /// Paths are found using the pathing data baked into this batch.
+    /// Without it, simulation succeeds but the pathing output is silent; use
+    /// [`ProbeBatch::has_baked_data`] to check for it.
+    /// If [`Self::enable_validation`] is `true`, [`Simulator::run_pathing`] returns
+    /// [`SimulationError::NoBakedPaths`] instead.
     pub pathing_probes: ProbeBatch,
 
     /// When testing for mutual visibility between a pair of probes, each probe is treated as a sphere of this radius (in meters), and point samples are generated within this sphere.
@@ -3207,7 +4202,7 @@ impl Default for DirectSimulationData {
 }
 
 /// Intermediate representation of reflections simulation parameters for FFI conversion.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 struct ReflectionsSimulationData {
     baked: audionimbus_sys::IPLbool,
     baked_data_identifier: BakedDataIdentifier,
@@ -3239,7 +4234,6 @@ struct PathingSimulationData {
     pathing_order: i32,
     enable_validation: audionimbus_sys::IPLbool,
     find_alternate_paths: audionimbus_sys::IPLbool,
-    deviation_model: audionimbus_sys::IPLDeviationModel,
 }
 
 impl PathingSimulationData {
@@ -3257,7 +4251,6 @@ impl PathingSimulationData {
             pathing_order: params.pathing_order as i32,
             enable_validation: params.enable_validation.into(),
             find_alternate_paths: params.find_alternate_paths.into(),
-            deviation_model: (&params.deviation).into(),
         }
     }
 }
@@ -3272,13 +4265,12 @@ impl Default for PathingSimulationData {
             pathing_order: 0,
             enable_validation: false.into(),
             find_alternate_paths: false.into(),
-            deviation_model: (&DeviationModel::Default).into(),
         }
     }
 }
 
 /// The different algorithms for simulating occlusion.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum OcclusionAlgorithm {
     /// Raycast occlusion.
     /// A single ray is traced from the listener to the source.
@@ -3298,6 +4290,10 @@ pub enum OcclusionAlgorithm {
 
         /// The number of point samples to consider when tracing rays.
         /// This value can change between simulation runs.
+        ///
+        /// Must not exceed [`DirectSimulationSettings::max_num_occlusion_samples`]; setting
+        /// source inputs with more samples returns
+        /// [`ParameterValidationError::OcclusionSamplesExceedsMax`].
         num_occlusion_samples: u32,
     },
 }
@@ -3431,6 +4427,29 @@ impl<D, R, P> SimulationSharedInputs<D, R, P> {
         }
     }
 
+    /// Sets the pathing simulation values of the shared inputs, with a closure called for each
+    /// valid path segment during calls to [`Simulator::run_pathing`].
+    ///
+    /// This is a shorthand for [`Self::with_pathing_visualization_callback`] with
+    /// [`PathingVisualizationCallback::from_fn_mut`].
+    /// The closure receives the positions of the starting and ending probes of the segment, and
+    /// whether the segment is occluded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// # fn draw_line(from: Point, to: Point, occluded: bool) {}
+    /// let shared_inputs = SimulationSharedInputs::new(CoordinateSystem::default())
+    ///     .with_pathing_visualization(|from, to, occluded| draw_line(from, to, occluded));
+    /// ```
+    pub fn with_pathing_visualization<F>(self, f: F) -> SimulationSharedInputs<D, R, Pathing>
+    where
+        F: FnMut(Point, Point, bool) + Send + 'static,
+    {
+        self.with_pathing_visualization_callback(PathingVisualizationCallback::from_fn_mut(f))
+    }
+
     /// Sets the position and orientation of the listener.
     pub const fn set_listener(&mut self, listener: CoordinateSystem) {
         self.listener = listener;
@@ -3494,6 +4513,19 @@ impl<D, R, P> From<&SimulationSharedInputs<D, R, P>>
     }
 }
 
+/// The smallest accepted value of [`ReflectionsSharedInputs::irradiance_min_distance`] and
+/// [`ReflectionsBakeParams::irradiance_min_distance`](crate::ReflectionsBakeParams::irradiance_min_distance), in meters.
+///
+/// Irradiance falls off with the inverse square of the distance, so smaller values let sources
+/// touching a surface inject an extreme amount of energy into the reflections.
+pub const MIN_IRRADIANCE_MIN_DISTANCE: f32 = 1e-3;
+
+/// Returns `true` if `distance` is at least [`MIN_IRRADIANCE_MIN_DISTANCE`], and not `NaN`.
+pub(crate) fn is_valid_irradiance_min_distance(distance: f32) -> bool {
+    // Comparisons with `NaN` are always false.
+    distance >= MIN_IRRADIANCE_MIN_DISTANCE
+}
+
 /// Reflections shared inputs.
 ///
 /// Used as an argument to [`SimulationSharedInputs::with_reflections`].
@@ -3516,9 +4548,133 @@ pub struct ReflectionsSharedInputs {
     pub order: u32,
 
     /// When calculating how much sound energy reaches a surface directly from a source, any source that is closer than [`Self::irradiance_min_distance`] to the surface is assumed to be at a distance of [`Self::irradiance_min_distance`], for the purposes of energy calculations.
+    ///
+    /// Must be at least [`MIN_IRRADIANCE_MIN_DISTANCE`].
     pub irradiance_min_distance: f32,
 }
 
+impl ReflectionsSharedInputs {
+    /// Caps [`Self::num_rays`], [`Self::duration`] and [`Self::order`] to the maximums of the
+    /// simulator created with `simulation_settings`.
+    ///
+    /// Shared inputs exceeding these maximums are rejected by
+    /// [`Simulator::set_shared_inputs`] (and its variants).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use audionimbus::*;
+    /// # let audio_settings = AudioSettings::default();
+    /// let settings = SimulationSettings::new(&audio_settings).with_reflections(ConvolutionSettings {
+    ///     max_num_rays: 4096,
+    ///     num_diffuse_samples: 32,
+    ///     max_duration: 2.0,
+    ///     max_num_sources: 8,
+    ///     num_threads: 2,
+    ///     max_order: 1,
+    /// });
+    ///
+    /// let inputs = ReflectionsSharedInputs {
+    ///     order: 3,
+    ///     ..Default::default()
+    /// }
+    /// .clamp_to(&settings);
+    /// assert_eq!(inputs.order, 1);
+    /// ```
+    pub fn clamp_to<T: RayTracer, D, P, RE>(
+        self,
+        simulation_settings: &SimulationSettings<T, D, Reflections, P, RE>,
+    ) -> Self {
+        let ffi_settings = simulation_settings.to_ffi();
+
+        Self {
+            num_rays: self.num_rays.min(ffi_settings.maxNumRays as u32),
+            duration: self.duration.min(ffi_settings.maxDuration),
+            order: self.order.min(ffi_settings.maxOrder as u32),
+            ..self
+        }
+    }
+}
+
+impl Default for ReflectionsSharedInputs {
+    /// Returns inputs matching Steam Audio's recommended real-time defaults: 4096 rays with 4
+    /// bounces, 1 second impulse responses, first-order Ambisonics, and a 1 meter irradiance
+    /// minimum distance.
+    fn default() -> Self {
+        Self {
+            num_rays: 4096,
+            num_bounces: 4,
+            duration: 1.0,
+            order: 1,
+            irradiance_min_distance: 1.0,
+        }
+    }
+}
+
+/// Maximum number of released output buffers kept by a [`Source`] for reuse.
+const MAX_CACHED_OUTPUTS: usize = 4;
+
+/// Output buffers released by dropped [`SimulationOutputs`], kept by the [`Source`] they
+/// originated from (and its clones) to be reused instead of allocating new ones.
+#[derive(Debug, Default)]
+struct OutputsCache {
+    buffers: Mutex<Vec<*mut audionimbus_sys::IPLSimulationOutputs>>,
+}
+
+impl OutputsCache {
+    /// Takes a buffer from the cache, or allocates one if the cache is empty.
+    /// The returned buffer is zeroed.
+    fn acquire(&self) -> Result<*mut audionimbus_sys::IPLSimulationOutputs, SteamAudioError> {
+        let cached = self.buffers.lock().unwrap().pop();
+        let ptr = match cached {
+            Some(ptr) => ptr,
+            None => unsafe {
+                let layout = std::alloc::Layout::new::<audionimbus_sys::IPLSimulationOutputs>();
+                let ptr = std::alloc::alloc(layout).cast::<audionimbus_sys::IPLSimulationOutputs>();
+                if ptr.is_null() {
+                    return Err(SteamAudioError::OutOfMemory);
+                }
+                ptr
+            },
+        };
+
+        unsafe { std::ptr::write(ptr, std::mem::zeroed()) };
+
+        Ok(ptr)
+    }
+
+    /// Returns a buffer obtained from [`Self::acquire`] to the cache, or frees it if the cache is
+    /// full.
+    fn release(&self, ptr: *mut audionimbus_sys::IPLSimulationOutputs) {
+        let mut buffers = self.buffers.lock().unwrap();
+        if buffers.len() < MAX_CACHED_OUTPUTS {
+            buffers.push(ptr);
+        } else {
+            drop(buffers);
+            unsafe { Self::deallocate(ptr) };
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `ptr` must have been allocated by [`Self::acquire`] and must not be used afterwards.
+    unsafe fn deallocate(ptr: *mut audionimbus_sys::IPLSimulationOutputs) {
+        let layout = std::alloc::Layout::new::<audionimbus_sys::IPLSimulationOutputs>();
+        unsafe { std::alloc::dealloc(ptr.cast::<u8>(), layout) };
+    }
+}
+
+impl Drop for OutputsCache {
+    fn drop(&mut self) {
+        for ptr in self.buffers.get_mut().unwrap().drain(..) {
+            unsafe { Self::deallocate(ptr) };
+        }
+    }
+}
+
+unsafe impl Send for OutputsCache {}
+unsafe impl Sync for OutputsCache {}
+
 /// Simulation results for a source.
 #[derive(Debug)]
 pub struct SimulationOutputs<D, R, P, RE = ()> {
@@ -3528,6 +4684,9 @@ pub struct SimulationOutputs<D, R, P, RE = ()> {
     /// valid.
     _source: audionimbus_sys::IPLSource,
 
+    /// Cache of the [`Source`] it originated from, to which [`Self::inner`] is returned on drop.
+    cache: Arc<OutputsCache>,
+
     _direct: PhantomData<D>,
     _reflections: PhantomData<R>,
     _pathing: PhantomData<P>,
@@ -3548,21 +4707,15 @@ impl<D, R, P, RE> SimulationOutputs<D, R, P, RE> {
         SourceP: 'static,
         SourceRE: 'static,
     {
-        let ptr = unsafe {
-            let layout = std::alloc::Layout::new::<audionimbus_sys::IPLSimulationOutputs>();
-            let ptr = std::alloc::alloc(layout).cast::<audionimbus_sys::IPLSimulationOutputs>();
-            if ptr.is_null() {
-                return Err(SteamAudioError::OutOfMemory);
-            }
-            std::ptr::write(ptr, std::mem::zeroed());
-            ptr
-        };
+        let ptr = source.outputs_cache.acquire()?;
+        let cache = Arc::clone(&source.outputs_cache);
 
         let source = unsafe { audionimbus_sys::iplSourceRetain(source.raw_ptr()) };
 
         Ok(Self {
             inner: ptr,
             _source: source,
+            cache,
             _direct: PhantomData,
             _reflections: PhantomData,
             _pathing: PhantomData,
@@ -3574,7 +4727,16 @@ impl<D, R, P, RE> SimulationOutputs<D, R, P, RE> {
         self.inner
     }
 
-    pub const fn raw_ptr_mut(&mut self) -> &mut *mut audionimbus_sys::IPLSimulationOutputs {
+    /// Returns a mutable reference to the raw pointer to the simulation outputs.
+    ///
+    /// This is intended for internal use and advanced scenarios.
+    ///
+    /// # Safety
+    ///
+    /// The outputs are obtained from the originating [`Source`] and returned to it when dropped.
+    /// Callers must not overwrite or null the pointer; otherwise the outputs are leaked or a
+    /// pointer that was not allocated by the source is reused or freed.
+    pub const unsafe fn raw_ptr_mut(&mut self) -> &mut *mut audionimbus_sys::IPLSimulationOutputs {
         &mut self.inner
     }
 }
@@ -3606,10 +4768,9 @@ unsafe impl<D, R, P, RE> Send for SimulationOutputs<D, R, P, RE> {}
 
 impl<D, R, P, RE> Drop for SimulationOutputs<D, R, P, RE> {
     fn drop(&mut self) {
-        unsafe {
-            let layout = std::alloc::Layout::new::<audionimbus_sys::IPLSimulationOutputs>();
-            std::alloc::dealloc(self.inner.cast::<u8>(), layout);
+        self.cache.release(self.inner);
 
+        unsafe {
             audionimbus_sys::iplSourceRelease(&mut self._source);
         }
     }
@@ -3631,6 +4792,28 @@ pub enum SimulationError {
     /// [`Simulator::set_scene`] and committed via [`Simulator::commit`] before
     /// running simulations.
     ReflectionsWithoutScene,
+
+    /// Attempted to commit changes while a simulation is running.
+    ///
+    /// Returned by [`Simulator::try_commit`]; [`Simulator::commit`] blocks until running
+    /// simulations finish instead.
+    SimulationRunning,
+
+    /// Attempted to run pathing simulation for a source whose pathing probe batch is not
+    /// committed to the simulator.
+    ///
+    /// The [`pathing_probes`](PathingSimulationParameters::pathing_probes) of each source must be
+    /// added to the simulator via [`Simulator::add_probe_batch`] and committed via
+    /// [`Simulator::commit`] before running simulations.
+    ProbeBatchNotRegistered,
+
+    /// Attempted to run pathing simulation with validation enabled for a source whose pathing
+    /// probe batch contains no baked pathing data.
+    ///
+    /// Paths must be baked into the
+    /// [`pathing_probes`](PathingSimulationParameters::pathing_probes) of each source using
+    /// [`PathBaker`](crate::PathBaker) before they can be validated.
+    NoBakedPaths,
 }
 
 impl std::error::Error for SimulationError {}
@@ -3644,10 +4827,56 @@ impl std::fmt::Display for SimulationError {
             Self::ReflectionsWithoutScene => {
                 write!(f, "running reflections on a simulator with no scene set")
             }
+            Self::SimulationRunning => {
+                write!(f, "committing changes while a simulation is running")
+            }
+            Self::ProbeBatchNotRegistered => {
+                write!(
+                    f,
+                    "running pathing for a source whose probe batch is not committed to the simulator"
+                )
+            }
+            Self::NoBakedPaths => {
+                write!(
+                    f,
+                    "validating paths for a source whose probe batch has no baked pathing data"
+                )
+            }
+        }
+    }
+}
+
+/// Errors that can occur when updating a [`ReverbSource`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ReverbSourceError {
+    /// The listener is unknown, because shared inputs were never set on the simulator.
+    ListenerNotSet,
+
+    /// The reflections parameters are invalid.
+    ParameterValidation(ParameterValidationError),
+}
+
+impl std::error::Error for ReverbSourceError {}
+
+impl std::fmt::Display for ReverbSourceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::ListenerNotSet => {
+                write!(f, "the listener is unknown until shared inputs are set")
+            }
+            Self::ParameterValidation(error) => {
+                write!(f, "invalid reverb source parameters: {error}")
+            }
         }
     }
 }
 
+impl From<ParameterValidationError> for ReverbSourceError {
+    fn from(error: ParameterValidationError) -> Self {
+        Self::ParameterValidation(error)
+    }
+}
+
 /// Errors that can occur during parameter validation.
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum ParameterValidationError {
@@ -3674,6 +4903,22 @@ pub enum ParameterValidationError {
         /// The maximum allowed duration in seconds.
         max: f32,
     },
+
+    /// Ambisonic order exceeds the maximum set during initialization.
+    OrderExceedsMax {
+        /// The requested order.
+        requested: u32,
+        /// The maximum allowed order.
+        max: u32,
+    },
+
+    /// Irradiance minimum distance is below [`MIN_IRRADIANCE_MIN_DISTANCE`], or is `NaN`.
+    IrradianceMinDistanceTooSmall {
+        /// The requested distance in meters.
+        requested: f32,
+        /// The minimum allowed distance in meters.
+        min: f32,
+    },
 }
 
 impl std::error::Error for ParameterValidationError {}
@@ -3702,6 +4947,20 @@ impl std::fmt::Display for ParameterValidationError {
                     requested, max
                 )
             }
+            Self::OrderExceedsMax { requested, max } => {
+                write!(
+                    f,
+                    "requested Ambisonic order {}, but maximum is {} (set during simulator initialization)",
+                    requested, max
+                )
+            }
+            Self::IrradianceMinDistanceTooSmall { requested, min } => {
+                write!(
+                    f,
+                    "requested irradiance minimum distance of {}m, but minimum is {}m",
+                    requested, min
+                )
+            }
         }
     }
 }
@@ -3729,6 +4988,149 @@ mod tests {
                 assert!(!clone.raw_ptr().is_null());
             }
         }
+
+        mod set_inputs {
+            use super::*;
+
+            #[test]
+            fn test_tracks_last_inputs() {
+                let context = Context::default();
+                let audio_settings = AudioSettings::default();
+                let simulator_settings = SimulationSettings::new(&audio_settings).with_direct(
+                    DirectSimulationSettings {
+                        max_num_occlusion_samples: 4,
+                    },
+                );
+                let simulator = Simulator::try_new(&context, &simulator_settings).unwrap();
+                let source = Source::<Direct, (), ()>::try_new(&simulator).unwrap();
+
+                let callback = DistanceAttenuationCallback::new(|_distance| 1.0);
+                let inputs = |x, dirty| {
+                    SimulationInputs::new(CoordinateSystem {
+                        origin: Point::new(x, 0.0, 0.0),
+                        ..Default::default()
+                    })
+                    .with_direct(
+                        DirectSimulationParameters::new().with_distance_attenuation(
+                            DistanceAttenuationModel::Callback {
+                                callback: callback.clone(),
+                                dirty,
+                            },
+                        ),
+                    )
+                };
+                let last_inputs = || source.shared.lock().unwrap().last_inputs.clone();
+
+                source.set_inputs(&inputs(1.0, false)).unwrap();
+                let [direct, reflections, pathing] = last_inputs();
+                assert_eq!(direct, Some((false, inputs(1.0, false).without_markers())));
+                assert!(reflections.is_none());
+                assert!(pathing.is_none());
+
+                source.set_inputs(&inputs(1.0, true)).unwrap();
+                let [direct, _, _] = last_inputs();
+                assert_eq!(direct, Some((false, inputs(1.0, true).without_markers())));
+
+                source.set_inputs(&inputs(2.0, false)).unwrap();
+                let [direct, _, _] = last_inputs();
+                assert_eq!(direct, Some((false, inputs(2.0, false).without_markers())));
+            }
+
+            #[test]
+            fn test_forwards_changed_callbacks() {
+                let context = Context::default();
+                let audio_settings = AudioSettings::default();
+                let simulator_settings = SimulationSettings::new(&audio_settings).with_direct(
+                    DirectSimulationSettings {
+                        max_num_occlusion_samples: 4,
+                    },
+                );
+                let mut simulator = Simulator::try_new(&context, &simulator_settings).unwrap();
+                let scene = Scene::try_new(&context).unwrap();
+                simulator.set_scene(&scene);
+                let source = Source::<Direct, (), ()>::try_new(&simulator).unwrap();
+                simulator.add_source(&source);
+                simulator.commit();
+                simulator
+                    .set_shared_direct_inputs(&SimulationSharedInputs::new(
+                        CoordinateSystem::default(),
+                    ))
+                    .unwrap();
+
+                let inputs = |callback: &DirectivityCallback| {
+                    SimulationInputs::new(CoordinateSystem {
+                        origin: Point::new(1.0, 0.0, 0.0),
+                        ..Default::default()
+                    })
+                    .with_direct(
+                        DirectSimulationParameters::new()
+                            .with_directivity(Directivity::Callback(callback.clone())),
+                    )
+                };
+                let simulated_directivity = || {
+                    simulator.run_direct();
+                    source.get_outputs().unwrap().direct().directivity
+                };
+
+                let quiet = DirectivityCallback::new(|_direction| 0.25);
+                source.set_inputs(&inputs(&quiet)).unwrap();
+                assert_eq!(simulated_directivity(), Some(0.25));
+
+                // Clones of the same callback compare equal.
+                source.set_inputs(&inputs(&quiet.clone())).unwrap();
+                assert_eq!(simulated_directivity(), Some(0.25));
+
+                // Changing only the callback is forwarded.
+                let loud = DirectivityCallback::new(|_direction| 0.75);
+                source.set_inputs(&inputs(&loud)).unwrap();
+                assert_eq!(simulated_directivity(), Some(0.75));
+            }
+        }
+
+        mod get_outputs {
+            use super::*;
+
+            #[test]
+            fn test_reuses_dropped_outputs() {
+                let context = Context::default();
+                let audio_settings = AudioSettings::default();
+                let simulator_settings = SimulationSettings::new(&audio_settings).with_direct(
+                    DirectSimulationSettings {
+                        max_num_occlusion_samples: 4,
+                    },
+                );
+                let simulator = Simulator::try_new(&context, &simulator_settings).unwrap();
+                let source = Source::<Direct, (), ()>::try_new(&simulator).unwrap();
+
+                let outputs = source.get_outputs().unwrap();
+                let ptr = outputs.raw_ptr();
+                drop(outputs);
+
+                let outputs = source.clone().get_outputs().unwrap();
+                assert_eq!(outputs.raw_ptr(), ptr);
+
+                // Outputs held at the same time must not share memory.
+                let other_outputs = source.get_outputs().unwrap();
+                assert_ne!(other_outputs.raw_ptr(), ptr);
+            }
+
+            #[test]
+            fn test_outlive_source() {
+                let context = Context::default();
+                let audio_settings = AudioSettings::default();
+                let simulator_settings = SimulationSettings::new(&audio_settings).with_direct(
+                    DirectSimulationSettings {
+                        max_num_occlusion_samples: 4,
+                    },
+                );
+                let simulator = Simulator::try_new(&context, &simulator_settings).unwrap();
+                let source = Source::<Direct, (), ()>::try_new(&simulator).unwrap();
+
+                let outputs = source.get_outputs().unwrap();
+                drop(source);
+                let _ = outputs.direct();
+            }
+        }
     }
 
     mod simulator {
@@ -3769,5 +5171,370 @@ mod tests {
             assert!(shared.pending_scene.is_none());
             assert_eq!(shared.committed_scene.as_ref(), Some(&scene));
         }
+
+        #[test]
+        fn test_try_commit_while_running() {
+            let context = Context::default();
+            let audio_settings = AudioSettings::default();
+            let settings =
+                SimulationSettings::new(&audio_settings).with_direct(DirectSimulationSettings {
+                    max_num_occlusion_samples: 4,
+                });
+            let mut simulator = Simulator::try_new(&context, &settings).unwrap();
+            let scene = Scene::try_new(&context).unwrap();
+            simulator.set_scene(&scene);
+
+            {
+                // Simulate a direct simulation in progress.
+                let _running = simulator.direct_lock.as_ref().unwrap().lock().unwrap();
+                assert_eq!(
+                    simulator.try_commit(),
+                    Err(SimulationError::SimulationRunning)
+                );
+                assert!(simulator.shared.lock().unwrap().pending_scene.is_some());
+            }
+
+            assert_eq!(simulator.try_commit(), Ok(()));
+            let shared = simulator.shared.lock().unwrap();
+            assert!(shared.pending_scene.is_none());
+            assert_eq!(shared.committed_scene.as_ref(), Some(&scene));
+        }
+
+        #[test]
+        fn test_try_commit_after_panic_in_simulation() {
+            let context = Context::default();
+            let audio_settings = AudioSettings::default();
+            let settings =
+                SimulationSettings::new(&audio_settings).with_direct(DirectSimulationSettings {
+                    max_num_occlusion_samples: 4,
+                });
+            let simulator = Simulator::try_new(&context, &settings).unwrap();
+
+            // Simulate a panic during a direct simulation, which poisons its lock.
+            let direct_lock = std::sync::Arc::clone(simulator.direct_lock.as_ref().unwrap());
+            let _ = std::thread::spawn(move || {
+                let _running = direct_lock.lock().unwrap();
+                panic!("simulation panicked");
+            })
+            .join();
+            assert!(simulator.direct_lock.as_ref().unwrap().is_poisoned());
+
+            assert_eq!(simulator.try_commit(), Ok(()));
+        }
+
+        #[test]
+        fn test_num_sources() {
+            let context = Context::default();
+            let audio_settings = AudioSettings::default();
+            let settings = SimulationSettings::new(&audio_settings);
+            let simulator = Simulator::try_new(&context, &settings).unwrap();
+            let source = Source::try_new(&simulator).unwrap();
+            let other_source = Source::try_new(&simulator).unwrap();
+
+            simulator.add_source(&source);
+            simulator.add_source(&source);
+            simulator.add_source(&other_source);
+            assert_eq!(simulator.num_sources(), 2);
+
+            simulator.remove_source(&source);
+            assert_eq!(simulator.num_sources(), 1);
+
+            simulator.remove_source(&source);
+            assert_eq!(simulator.num_sources(), 1);
+        }
+
+        #[test]
+        fn test_clear_sources() {
+            let context = Context::default();
+            let audio_settings = AudioSettings::default();
+            let settings = SimulationSettings::new(&audio_settings);
+            let simulator = Simulator::try_new(&context, &settings).unwrap();
+
+            for _ in 0..3 {
+                let source = Source::try_new(&simulator).unwrap();
+                simulator.add_source(&source);
+            }
+            simulator.commit();
+            assert_eq!(simulator.num_sources(), 3);
+
+            simulator.clear_sources();
+            simulator.commit();
+            assert_eq!(simulator.num_sources(), 0);
+            assert_eq!(simulator.clone().num_sources(), 0);
+        }
+
+        #[test]
+        fn test_clear_sources_then_re_add() {
+            let context = Context::default();
+            let audio_settings = AudioSettings::default();
+            let settings =
+                SimulationSettings::new(&audio_settings).with_pathing(PathingSimulationSettings {
+                    num_visibility_samples: 4,
+                });
+            let mut simulator = Simulator::try_new(&context, &settings).unwrap();
+
+            let mut registered = ProbeBatch::try_new(&context).unwrap();
+            registered.add_probe(Sphere::default());
+            registered.commit();
+            simulator.add_probe_batch(&registered);
+
+            let mut unregistered = ProbeBatch::try_new(&context).unwrap();
+            unregistered.add_probe(Sphere::default());
+            unregistered.commit();
+
+            let pathing_inputs = |pathing_probes: &ProbeBatch| SimulationInputs {
+                source: CoordinateSystem::default(),
+                parameters: SimulationParameters::new().with_pathing(PathingSimulationParameters {
+                    pathing_probes: pathing_probes.clone(),
+                    visibility_radius: 1.0,
+                    visibility_threshold: 10.0,
+                    visibility_range: 10.0,
+                    pathing_order: 1,
+                    enable_validation: false,
+                    find_alternate_paths: false,
+                    deviation: DeviationModel::default(),
+                }),
+            };
+
+            let source = Source::try_new(&simulator).unwrap();
+            source
+                .set_pathing_inputs(&pathing_inputs(&unregistered))
+                .unwrap();
+            simulator.add_source(&source);
+            simulator.commit();
+            assert_eq!(
+                simulator.run_pathing(),
+                Err(SimulationError::ProbeBatchNotRegistered)
+            );
+
+            simulator.clear_sources();
+            simulator.commit();
+            assert!(simulator.pathing_probes.lock().unwrap().is_empty());
+
+            let source = Source::try_new(&simulator).unwrap();
+            source
+                .set_pathing_inputs(&pathing_inputs(&registered))
+                .unwrap();
+            simulator.add_source(&source);
+            simulator.commit();
+            assert_eq!(simulator.num_sources(), 1);
+            assert!(simulator.run_pathing().is_ok());
+        }
+    }
+
+    mod reflections_shared_inputs {
+        use super::*;
+
+        mod clamp_to {
+            use super::*;
+
+            #[test]
+            fn test_caps_to_maximums() {
+                let audio_settings = AudioSettings::default();
+                let settings = SimulationSettings::new(&audio_settings).with_reflections(
+                    ConvolutionSettings {
+                        max_num_rays: 1024,
+                        num_diffuse_samples: 32,
+                        max_duration: 0.5,
+                        max_num_sources: 8,
+                        num_threads: 2,
+                        max_order: 1,
+                    },
+                );
+
+                let inputs = ReflectionsSharedInputs {
+                    num_rays: 4096,
+                    num_bounces: 16,
+                    duration: 2.0,
+                    order: 3,
+                    irradiance_min_distance: 2.0,
+                }
+                .clamp_to(&settings);
+
+                assert_eq!(
+                    inputs,
+                    ReflectionsSharedInputs {
+                        num_rays: 1024,
+                        num_bounces: 16,
+                        duration: 0.5,
+                        order: 1,
+                        irradiance_min_distance: 2.0,
+                    }
+                );
+            }
+
+            #[test]
+            fn test_within_maximums() {
+                let audio_settings = AudioSettings::default();
+                let settings = SimulationSettings::new(&audio_settings).with_reflections(
+                    ConvolutionSettings {
+                        max_num_rays: 4096,
+                        num_diffuse_samples: 32,
+                        max_duration: 2.0,
+                        max_num_sources: 8,
+                        num_threads: 2,
+                        max_order: 2,
+                    },
+                );
+
+                let inputs = ReflectionsSharedInputs::default();
+                assert_eq!(inputs.clamp_to(&settings), inputs);
+            }
+        }
+    }
+
+    mod simulation_shared_inputs {
+        use super::*;
+
+        mod with_pathing_visualization {
+            use super::*;
+            use std::sync::{Arc, Mutex};
+
+            #[test]
+            fn test_calls_closure() {
+                let segments = Arc::new(Mutex::new(Vec::new()));
+                let captured = Arc::clone(&segments);
+                let shared_inputs = SimulationSharedInputs::new(CoordinateSystem::default())
+                    .with_pathing_visualization(move |from, to, occluded| {
+                        captured.lock().unwrap().push((from, to, occluded));
+                    });
+
+                let ffi_shared_inputs =
+                    audionimbus_sys::IPLSimulationSharedInputs::from(&shared_inputs);
+                let callback = ffi_shared_inputs.pathingVisCallback.unwrap();
+                let from = Point::new(1.0, 2.0, 3.0);
+                let to = Point::new(4.0, 5.0, 6.0);
+                unsafe {
+                    callback(
+                        from.into(),
+                        to.into(),
+                        true.into(),
+                        ffi_shared_inputs.pathingUserData,
+                    );
+                }
+
+                assert_eq!(*segments.lock().unwrap(), [(from, to, true)]);
+            }
+
+            #[test]
+            fn test_catches_panics() {
+                let shared_inputs = SimulationSharedInputs::new(CoordinateSystem::default())
+                    .with_pathing_visualization(|_, _, _| panic!("draw failed"));
+
+                let ffi_shared_inputs =
+                    audionimbus_sys::IPLSimulationSharedInputs::from(&shared_inputs);
+                let callback = ffi_shared_inputs.pathingVisCallback.unwrap();
+                unsafe {
+                    callback(
+                        Point::default().into(),
+                        Point::default().into(),
+                        false.into(),
+                        ffi_shared_inputs.pathingUserData,
+                    );
+                }
+            }
+        }
+    }
+
+    mod reverb_source {
+        use super::*;
+
+        #[test]
+        fn test_update() {
+            let context = Context::default();
+            let audio_settings = AudioSettings::default();
+            let simulation_settings =
+                SimulationSettings::new(&audio_settings).with_reflections(ConvolutionSettings {
+                    max_num_rays: 1024,
+                    num_diffuse_samples: 32,
+                    max_duration: 1.0,
+                    max_num_sources: 2,
+                    num_threads: 1,
+                    max_order: 1,
+                });
+            let simulator = Simulator::try_new(&context, &simulation_settings).unwrap();
+
+            let reverb_source = ReverbSource::try_new(
+                &simulator,
+                ConvolutionParameters {
+                    baked_data_identifier: None,
+                },
+            )
+            .unwrap();
+            simulator.add_source(reverb_source.source());
+            simulator.commit();
+
+            assert_eq!(
+                reverb_source.update(),
+                Err(ReverbSourceError::ListenerNotSet)
+            );
+
+            let shared_inputs = SimulationSharedInputs::new(CoordinateSystem::default())
+                .with_reflections(ReflectionsSharedInputs {
+                    num_rays: 1024,
+                    num_bounces: 4,
+                    duration: 1.0,
+                    order: 1,
+                    irradiance_min_distance: 1.0,
+                });
+            simulator
+                .set_shared_reflections_inputs(&shared_inputs)
+                .unwrap();
+
+            assert_eq!(reverb_source.update(), Ok(()));
+            assert!(reverb_source.outputs().is_ok());
+        }
+    }
+
+    mod resolve_num_threads {
+        use super::*;
+        use crate::simulation::resolve_num_threads;
+
+        #[test]
+        fn test_explicit() {
+            assert_eq!(resolve_num_threads(3), 3);
+        }
+
+        #[test]
+        fn test_clamped() {
+            assert_eq!(resolve_num_threads(u32::MAX), i32::MAX);
+        }
+
+        #[test]
+        fn test_zero() {
+            assert!(resolve_num_threads(0) >= 1);
+        }
+    }
+
+    mod is_beyond_culling_distance {
+        use super::*;
+        use crate::simulation::is_beyond_culling_distance;
+
+        #[test]
+        fn test_within_distance() {
+            let source = Point::new(3.0, 4.0, 0.0);
+            let listener = Some(Point::new(0.0, 0.0, 0.0));
+            assert!(!is_beyond_culling_distance(source, listener, Some(5.0)));
+        }
+
+        #[test]
+        fn test_beyond_distance() {
+            let source = Point::new(3.0, 4.0, 0.1);
+            let listener = Some(Point::new(0.0, 0.0, 0.0));
+            assert!(is_beyond_culling_distance(source, listener, Some(5.0)));
+        }
+
+        #[test]
+        fn test_no_culling_distance() {
+            let source = Point::new(1000.0, 0.0, 0.0);
+            let listener = Some(Point::new(0.0, 0.0, 0.0));
+            assert!(!is_beyond_culling_distance(source, listener, None));
+        }
+
+        #[test]
+        fn test_unknown_listener() {
+            let source = Point::new(1000.0, 0.0, 0.0);
+            assert!(!is_beyond_culling_distance(source, None, Some(5.0)));
+        }
     }
 }
@@ -5,8 +5,29 @@ pub const STEAMAUDIO_VERSION_MAJOR: usize = audionimbus_sys::STEAMAUDIO_VERSION_
 pub const STEAMAUDIO_VERSION_MINOR: usize = audionimbus_sys::STEAMAUDIO_VERSION_MINOR as usize;
 pub const STEAMAUDIO_VERSION_PATCH: usize = audionimbus_sys::STEAMAUDIO_VERSION_PATCH as usize;
 
+/// Returns the version of the Steam Audio library audionimbus was built against.
+///
+/// This does not require a [`Context`](crate::Context), so it can be used for logging at
+/// startup, before any Steam Audio object is created.
+///
+/// # Examples
+///
+/// ```
+/// # use audionimbus::*;
+/// let version = audionimbus::version();
+/// assert_eq!(version, SteamAudioVersion::default());
+/// println!("Steam Audio {version}");
+/// ```
+pub const fn version() -> SteamAudioVersion {
+    SteamAudioVersion {
+        major: STEAMAUDIO_VERSION_MAJOR,
+        minor: STEAMAUDIO_VERSION_MINOR,
+        patch: STEAMAUDIO_VERSION_PATCH,
+    }
+}
+
 /// The version of the Steam Audio library.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct SteamAudioVersion {
     pub major: usize,
     pub minor: usize,
@@ -21,11 +42,13 @@ impl From<SteamAudioVersion> for u32 {
 
 impl Default for SteamAudioVersion {
     fn default() -> Self {
-        Self {
-            major: STEAMAUDIO_VERSION_MAJOR,
-            minor: STEAMAUDIO_VERSION_MINOR,
-            patch: STEAMAUDIO_VERSION_PATCH,
-        }
+        version()
+    }
+}
+
+impl std::fmt::Display for SteamAudioVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
     }
 }
 
@@ -45,4 +68,27 @@ mod tests {
 
         assert_eq!(version_u32, 264_192);
     }
+
+    #[test]
+    fn test_version_matches_bindings() {
+        assert_eq!(u32::from(version()), audionimbus_sys::STEAMAUDIO_VERSION);
+    }
+
+    #[test]
+    fn test_version_accepted_by_linked_library() {
+        let settings = crate::ContextSettings::new().with_version(version());
+
+        assert!(crate::Context::try_new(&settings).is_ok());
+    }
+
+    #[test]
+    fn test_display() {
+        let version = SteamAudioVersion {
+            major: 4,
+            minor: 8,
+            patch: 1,
+        };
+
+        assert_eq!(version.to_string(), "4.8.1");
+    }
 }
@@ -2,6 +2,10 @@ use crate::context::Context;
 use crate::hrtf::Hrtf;
 use crate::ray_tracing::RayTracer;
 use crate::simulation::{SimulationSettings, Source};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether a [`WwiseIntegration`] guard is currently alive.
+static INTEGRATION_ACTIVE: AtomicBool = AtomicBool::new(false);
 
 #[derive(Debug, Copy, Clone)]
 /// Settings used for initializing the Steam Audio Wwise integration.
@@ -13,6 +17,7 @@ pub struct WwiseSettings {
 /// Initializes the Wwise integration with default settings.
 ///
 /// This function must be called before creating any Steam Audio DSP effects.
+/// [`WwiseIntegration`] calls it for you, and calls [`terminate`] when it goes out of scope.
 pub fn initialize(context: &Context) {
     let ipl_settings = std::ptr::null_mut();
     unsafe { audionimbus_sys::wwise::iplWwiseInitialize(context.raw_ptr(), ipl_settings) }
@@ -98,9 +103,143 @@ pub fn version() -> WwiseIntegrationVersion {
 }
 
 /// The version of the Wwise integration.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct WwiseIntegrationVersion {
     pub major: usize,
     pub minor: usize,
     pub patch: usize,
 }
+
+impl std::fmt::Display for WwiseIntegrationVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// A guard that keeps the Wwise integration initialized for as long as it is alive.
+///
+/// The integration is initialized when the guard is created, and shut down when it is dropped.
+/// The guard's methods can only be called while it is alive, and so while the integration is
+/// initialized, as long as [`initialize`] and [`terminate`] are not also called directly.
+///
+/// Steam Audio DSP effects must be destroyed before the guard is dropped.
+/// The guard keeps the [`Context`] it was created from alive.
+#[derive(Debug)]
+pub struct WwiseIntegration {
+    _context: Context,
+}
+
+impl WwiseIntegration {
+    /// Initializes the Wwise integration with default settings and returns a guard that shuts it
+    /// down when dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WwiseIntegrationError::AlreadyInitialized`] if another [`WwiseIntegration`]
+    /// guard is alive.
+    pub fn try_new(context: &Context) -> Result<Self, WwiseIntegrationError> {
+        activate()?;
+        initialize(context);
+
+        Ok(Self {
+            _context: context.clone(),
+        })
+    }
+
+    /// Initializes the Wwise integration with settings and returns a guard that shuts it down
+    /// when dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WwiseIntegrationError::AlreadyInitialized`] if another [`WwiseIntegration`]
+    /// guard is alive.
+    pub fn try_with_settings(
+        context: &Context,
+        settings: WwiseSettings,
+    ) -> Result<Self, WwiseIntegrationError> {
+        activate()?;
+        initialize_with_settings(context, settings);
+
+        Ok(Self {
+            _context: context.clone(),
+        })
+    }
+
+    /// Specifies the simulation settings used by the game engine for simulating direct and/or indirect sound propagation.
+    ///
+    /// See [`set_simulation_settings`].
+    pub fn set_simulation_settings<T: RayTracer, D, R, P, RE>(
+        &self,
+        simulation_settings: &SimulationSettings<T, D, R, P, RE>,
+    ) {
+        set_simulation_settings(simulation_settings);
+    }
+
+    /// Specifies the HRTF to use for spatialization in subsequent audio frames.
+    ///
+    /// See [`set_hrtf`].
+    pub fn set_hrtf(&self, hrtf: &Hrtf) {
+        set_hrtf(hrtf);
+    }
+
+    /// Specifies the [`Source`] used by the game engine for simulating occlusion, reflections, etc. for the given Wwise game object.
+    ///
+    /// See [`add_source`].
+    pub fn add_source(&self, game_object_id: WwiseGameObjectId, source: &Source) {
+        add_source(game_object_id, source);
+    }
+
+    /// Remove any [`Source`] associated the given Wwise game object ID.
+    ///
+    /// See [`remove_source`].
+    pub fn remove_source(&self, game_object_id: WwiseGameObjectId) {
+        remove_source(game_object_id);
+    }
+
+    /// Specifies the [`Source`] used by the game engine for simulating reverb.
+    ///
+    /// See [`set_reverb_source`].
+    pub fn set_reverb_source(&self, source: &Source) {
+        set_reverb_source(source);
+    }
+}
+
+impl Drop for WwiseIntegration {
+    fn drop(&mut self) {
+        terminate();
+        INTEGRATION_ACTIVE.store(false, Ordering::Release);
+    }
+}
+
+/// Marks the integration as active.
+///
+/// # Errors
+///
+/// Returns [`WwiseIntegrationError::AlreadyInitialized`] if a [`WwiseIntegration`] guard is
+/// already alive.
+fn activate() -> Result<(), WwiseIntegrationError> {
+    if INTEGRATION_ACTIVE.swap(true, Ordering::AcqRel) {
+        return Err(WwiseIntegrationError::AlreadyInitialized);
+    }
+
+    Ok(())
+}
+
+/// [`WwiseIntegration`] errors.
+#[derive(Debug, PartialEq, Eq)]
+pub enum WwiseIntegrationError {
+    /// Another [`WwiseIntegration`] guard is alive.
+    AlreadyInitialized,
+}
+
+impl std::error::Error for WwiseIntegrationError {}
+
+impl std::fmt::Display for WwiseIntegrationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::AlreadyInitialized => {
+                write!(f, "the Wwise integration is already initialized")
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 use audionimbus::*;
 
 mod common;
@@ -156,6 +158,68 @@ fn test_ambisonics_decode_effect() {
     assert!(output_buffer.interleave(&context, &mut interleaved).is_ok());
 }
 
+#[test]
+fn test_binaural_renderers() {
+    // Renders the same sine wave, duplicated to every input channel, with `renderer`.
+    fn render(
+        renderer: &mut impl BinauralRenderer,
+        hrtf: &Hrtf,
+        sine_wave: &[Sample],
+    ) -> Vec<Sample> {
+        let num_channels = renderer.num_input_channels();
+        let mix = sine_wave.repeat(num_channels as usize);
+        let mix_buffer = AudioBuffer::try_with_data_and_settings(
+            &mix,
+            AudioBufferSettings::with_num_channels(num_channels),
+        )
+        .unwrap();
+
+        let mut stereo = Vec::new();
+        let stereo_buffer = renderer
+            .render_to_stereo(&mix_buffer, hrtf, &mut stereo)
+            .unwrap();
+        assert_eq!(stereo_buffer.num_channels(), 2);
+        stereo
+    }
+
+    let sine_wave = sine_wave(440.0, 0.5, 0.1, 48000);
+    let frame_size = sine_wave.len() as u32;
+
+    let context = Context::default();
+    let audio_settings = AudioSettings {
+        frame_size,
+        ..Default::default()
+    };
+    let hrtf = Hrtf::try_new(&context, &audio_settings, &HrtfSettings::default()).unwrap();
+
+    let mut virtual_surround = effect::VirtualSurroundEffect::try_new(
+        &context,
+        &audio_settings,
+        &effect::VirtualSurroundEffectSettings {
+            speaker_layout: SpeakerLayout::Surround5_1,
+            hrtf: hrtf.clone(),
+        },
+    )
+    .unwrap();
+    let mut ambisonics_decode = effect::AmbisonicsDecodeEffect::try_new(
+        &context,
+        &audio_settings,
+        &effect::AmbisonicsDecodeEffectSettings {
+            speaker_layout: SpeakerLayout::Stereo,
+            hrtf: hrtf.clone(),
+            max_order: 1,
+            rendering: Rendering::Binaural,
+        },
+    )
+    .unwrap();
+
+    let virtual_surround_output = render(&mut virtual_surround, &hrtf, &sine_wave);
+    let ambisonics_decode_output = render(&mut ambisonics_decode, &hrtf, &sine_wave);
+
+    assert_eq!(virtual_surround_output.len(), 2 * sine_wave.len());
+    assert_eq!(ambisonics_decode_output.len(), 2 * sine_wave.len());
+}
+
 #[test]
 fn test_direct_effect() {
     let frequency = 440.0;
@@ -166,12 +230,8 @@ fn test_direct_effect() {
     let input_buffer = AudioBuffer::try_with_data(&sine_wave).unwrap();
     let frame_size = sine_wave.len() as u32;
 
-    let mut output_container = vec![0.0; 2 * input_buffer.num_samples() as usize];
-    let output_buffer = AudioBuffer::try_with_data_and_settings(
-        &mut output_container,
-        AudioBufferSettings::with_num_channels(2),
-    )
-    .unwrap();
+    let mut output_container = vec![0.0; input_buffer.num_samples() as usize];
+    let output_buffer = AudioBuffer::try_with_data(&mut output_container).unwrap();
 
     let context = Context::default();
 
@@ -195,7 +255,11 @@ fn test_direct_effect() {
         ]))),
     };
 
-    let _ = direct_effect.apply(&direct_effect_params, &input_buffer, &output_buffer);
+    assert!(
+        direct_effect
+            .apply(&direct_effect_params, &input_buffer, &output_buffer)
+            .is_ok()
+    );
 
     let mut interleaved =
         vec![0.0; (output_buffer.num_channels() * output_buffer.num_samples()) as usize];
@@ -287,6 +351,7 @@ fn test_pathing() {
     PathBaker::new()
         .bake(&context, &mut pathing_probes, &scene, path_bake_params)
         .unwrap();
+    assert!(pathing_probes.has_baked_data(identifier));
 
     let source = Source::try_new(&simulator).unwrap();
     let simulation_inputs = SimulationInputs {
@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 use audionimbus::*;
 
 const SOFA_PATH: &str = concat!(
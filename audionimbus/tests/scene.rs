@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 use audionimbus::*;
 
 #[test]
@@ -36,9 +38,21 @@ fn test_static_mesh() {
 
     let static_mesh = StaticMesh::try_new(&scene, &static_mesh_settings).unwrap();
 
-    scene.add_static_mesh(static_mesh);
+    let handle = scene.add_static_mesh(static_mesh);
+
+    scene.commit();
+    assert_eq!(scene.num_static_meshes(), 1);
 
+    assert!(scene.remove_static_mesh(handle));
+    assert!(!scene.remove_static_mesh(handle));
     scene.commit();
+    assert_eq!(scene.num_static_meshes(), 0);
+
+    let static_mesh = StaticMesh::try_new(&scene, &static_mesh_settings).unwrap();
+    let _ = scene.add_static_mesh(static_mesh);
+    scene.clear_static_meshes();
+    scene.commit();
+    assert_eq!(scene.num_static_meshes(), 0);
 }
 
 #[test]
@@ -106,6 +120,98 @@ fn test_instanced_mesh() {
     main_scene.commit();
 }
 
+#[test]
+fn test_scene_bounds() {
+    let context = Context::default();
+    let mut main_scene = Scene::try_new(&context).unwrap();
+    let mut sub_scene = Scene::try_new(&context).unwrap();
+    assert_eq!(main_scene.bounds(), None);
+
+    // A unit square in the x-y plane.
+    let vertices = vec![
+        geometry::Point::new(0.0, 0.0, 0.0),
+        geometry::Point::new(1.0, 0.0, 0.0),
+        geometry::Point::new(1.0, 1.0, 0.0),
+        geometry::Point::new(0.0, 1.0, 0.0),
+    ];
+    let triangles = vec![
+        geometry::Triangle::new(0, 1, 2),
+        geometry::Triangle::new(0, 2, 3),
+    ];
+    let materials = vec![geometry::Material::default()];
+    let material_indices = vec![0, 0];
+    let static_mesh_settings = geometry::StaticMeshSettings {
+        vertices: &vertices,
+        triangles: &triangles,
+        material_indices: &material_indices,
+        materials: &materials,
+    };
+
+    let static_mesh = StaticMesh::try_new(&main_scene, &static_mesh_settings).unwrap();
+    let static_mesh_handle = main_scene.add_static_mesh(static_mesh);
+    main_scene.commit();
+    assert_eq!(
+        main_scene.bounds(),
+        Some((
+            geometry::Point::new(0.0, 0.0, 0.0),
+            geometry::Point::new(1.0, 1.0, 0.0)
+        ))
+    );
+
+    let static_mesh = StaticMesh::try_new(&sub_scene, &static_mesh_settings).unwrap();
+    sub_scene.add_static_mesh(static_mesh);
+    sub_scene.commit();
+
+    // Rotate 90 degrees around the Y axis, then move 5 meters along the X axis.
+    let transform = Matrix::new([
+        [0.0, 0.0, 1.0, 5.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [-1.0, 0.0, 0.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+    let instanced_mesh_settings = geometry::InstancedMeshSettings {
+        sub_scene,
+        transform,
+    };
+    let instanced_mesh = InstancedMesh::try_new(&main_scene, &instanced_mesh_settings).unwrap();
+    let instanced_mesh_handle = main_scene.add_instanced_mesh(instanced_mesh);
+    main_scene.commit();
+    assert_eq!(
+        main_scene.bounds(),
+        Some((
+            geometry::Point::new(0.0, 0.0, -1.0),
+            geometry::Point::new(5.0, 1.0, 0.0)
+        ))
+    );
+
+    // Move 10 meters along the X axis, without rotation.
+    let new_transform = Matrix::new([
+        [1.0, 0.0, 0.0, 10.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+    main_scene.update_instanced_mesh_transform(instanced_mesh_handle, new_transform);
+    main_scene.commit();
+    assert_eq!(
+        main_scene.bounds(),
+        Some((
+            geometry::Point::new(0.0, 0.0, 0.0),
+            geometry::Point::new(11.0, 1.0, 0.0)
+        ))
+    );
+
+    main_scene.remove_static_mesh(static_mesh_handle);
+    main_scene.commit();
+    assert_eq!(
+        main_scene.bounds(),
+        Some((
+            geometry::Point::new(10.0, 0.0, 0.0),
+            geometry::Point::new(11.0, 1.0, 0.0)
+        ))
+    );
+}
+
 #[test]
 fn test_scene_serialization() {
     let context = Context::default();
@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 use audionimbus::*;
 
 fn static_mesh(scene: &Scene) -> StaticMesh<DefaultRayTracer> {
@@ -52,6 +54,22 @@ fn test_static_mesh_to_vec() {
     assert!(!data.is_empty());
 }
 
+#[test]
+fn test_scene_save_load() {
+    let context = Context::default();
+    let mut scene = Scene::try_new(&context).unwrap();
+    let static_mesh = static_mesh(&scene);
+    scene.add_static_mesh(static_mesh);
+    scene.commit();
+
+    let mut serialized = SerializedObject::try_new(&context).unwrap();
+    scene.save(&mut serialized);
+    assert!(!serialized.to_vec().is_empty());
+
+    let loaded = Scene::<DefaultRayTracer>::load(&context, &serialized);
+    assert!(loaded.is_ok());
+}
+
 #[test]
 fn test_scene_save_obj() {
     let context = Context::default();
@@ -72,6 +90,37 @@ fn test_scene_save_obj() {
     let _ = std::fs::remove_file(temp_file);
 }
 
+#[test]
+fn test_probe_batch_export_obj() {
+    let context = Context::default();
+    let mut probe_batch = ProbeBatch::try_new(&context).unwrap();
+    for x in [0.0, 4.0] {
+        probe_batch.add_probe(Sphere {
+            center: Point::new(x, 1.5, 0.0),
+            radius: 2.0,
+        });
+    }
+    probe_batch.commit();
+
+    let temp_file = std::env::temp_dir().join("test_probe_batch.obj");
+    probe_batch.export_obj(&temp_file).unwrap();
+
+    // One icosahedron per probe.
+    let obj = std::fs::read_to_string(&temp_file).unwrap();
+    assert_eq!(obj.lines().filter(|line| line.starts_with("o ")).count(), 2);
+    assert_eq!(
+        obj.lines().filter(|line| line.starts_with("v ")).count(),
+        24
+    );
+    assert_eq!(
+        obj.lines().filter(|line| line.starts_with("f ")).count(),
+        40
+    );
+
+    // Clean up.
+    let _ = std::fs::remove_file(temp_file);
+}
+
 #[test]
 fn test_probe_batch_save_load() {
     let context = Context::default();
@@ -91,3 +140,53 @@ fn test_probe_batch_save_load() {
     assert!(loaded_batch.is_ok());
     assert_eq!(loaded_batch.unwrap().num_probes(), 1);
 }
+
+#[test]
+fn test_scene_save_load_file() {
+    let context = Context::default();
+    let mut scene = Scene::try_new(&context).unwrap();
+    let static_mesh = static_mesh(&scene);
+    scene.add_static_mesh(static_mesh);
+    scene.commit();
+
+    let temp_file = std::env::temp_dir().join("test_scene_save_load_file.bin");
+    scene.save_to_file(&context, &temp_file).unwrap();
+    assert!(std::fs::metadata(&temp_file).unwrap().len() > 0);
+
+    let loaded = Scene::<DefaultRayTracer>::load_from_file(&context, &temp_file);
+    assert!(loaded.is_ok());
+
+    // Clean up.
+    let _ = std::fs::remove_file(temp_file);
+}
+
+#[test]
+fn test_probe_batch_save_load_file() {
+    let context = Context::default();
+    let mut probe_batch = ProbeBatch::try_new(&context).unwrap();
+    probe_batch.add_probe(Sphere {
+        center: Point::new(1.0, 2.0, 3.0),
+        radius: 5.0,
+    });
+    probe_batch.commit();
+
+    let temp_file = std::env::temp_dir().join("test_probe_batch_save_load_file.bin");
+    probe_batch.save_to_file(&context, &temp_file).unwrap();
+
+    let loaded = ProbeBatch::load_from_file(&context, &temp_file).unwrap();
+    assert_eq!(loaded.num_probes(), 1);
+
+    // Clean up.
+    let _ = std::fs::remove_file(temp_file);
+}
+
+#[test]
+fn test_load_from_missing_file() {
+    let context = Context::default();
+    let temp_file = std::env::temp_dir().join("test_load_from_missing_file.bin");
+
+    assert!(matches!(
+        ProbeBatch::load_from_file(&context, &temp_file),
+        Err(SerializationError::Io(_))
+    ));
+}
@@ -1,5 +1,9 @@
+#![cfg(feature = "std")]
+
+#[cfg(feature = "wiring")]
 use audionimbus::wiring::*;
 use audionimbus::*;
+#[cfg(feature = "wiring")]
 use std::time::Duration;
 
 mod common;
@@ -254,6 +258,61 @@ fn test_pathing_without_probes() {
     );
 }
 
+#[test]
+fn test_source_keeps_deviation_model_alive() {
+    let context = Context::default();
+    let audio_settings = AudioSettings::default();
+
+    let simulation_settings = SimulationSettings::new(&audio_settings)
+        .with_direct(DirectSimulationSettings {
+            max_num_occlusion_samples: 4,
+        })
+        .with_pathing(PathingSimulationSettings {
+            num_visibility_samples: 4,
+        });
+    let simulator = Simulator::try_new(&context, &simulation_settings).unwrap();
+    let source = Source::try_new(&simulator).unwrap();
+
+    let marker = std::sync::Arc::new(());
+    let captured = marker.clone();
+    let deviation = DeviationModel::callback(move |_angle, _band| {
+        let _ = &captured;
+        1.0
+    });
+
+    let pathing_inputs = SimulationInputs {
+        source: CoordinateSystem::default(),
+        parameters: SimulationParameters::new()
+            .with_direct(DirectSimulationParameters::new())
+            .with_pathing(PathingSimulationParameters {
+                pathing_probes: ProbeBatch::try_new(&context).unwrap(),
+                visibility_radius: 1.0,
+                visibility_threshold: 10.0,
+                visibility_range: 10.0,
+                pathing_order: 1,
+                enable_validation: true,
+                find_alternate_paths: true,
+                deviation,
+            }),
+    };
+    source.set_pathing_inputs(&pathing_inputs).unwrap();
+    drop(pathing_inputs);
+
+    // The source owns a copy of the deviation model.
+    assert_eq!(std::sync::Arc::strong_count(&marker), 2);
+
+    // Setting direct inputs must not release the deviation model used for pathing.
+    let direct_inputs = SimulationInputs {
+        source: CoordinateSystem::default(),
+        parameters: SimulationParameters::new().with_direct(DirectSimulationParameters::new()),
+    };
+    source.set_direct_inputs(&direct_inputs).unwrap();
+    assert_eq!(std::sync::Arc::strong_count(&marker), 2);
+
+    drop(source);
+    assert_eq!(std::sync::Arc::strong_count(&marker), 1);
+}
+
 #[test]
 fn test_reflections_without_scene() {
     let context = Context::default();
@@ -291,6 +350,359 @@ fn test_reflections_without_scene() {
 }
 
 #[test]
+fn test_reflections_with_uncommitted_scene() {
+    let context = Context::default();
+    let audio_settings = AudioSettings::default();
+
+    let simulation_settings =
+        SimulationSettings::new(&audio_settings).with_reflections(ConvolutionSettings {
+            max_num_rays: 4096,
+            num_diffuse_samples: 32,
+            max_duration: 2.0,
+            max_num_sources: 8,
+            num_threads: 2,
+            max_order: 1,
+        });
+    let mut simulator = Simulator::try_new(&context, &simulation_settings).unwrap();
+
+    let scene = Scene::try_new(&context).unwrap();
+    scene.commit();
+    simulator.set_scene(&scene);
+
+    // The scene only takes effect once the simulator is committed.
+    assert_eq!(
+        simulator.run_reflections(),
+        Err(SimulationError::ReflectionsWithoutScene)
+    );
+}
+
+#[test]
+fn test_last_reflections_stats() {
+    let context = Context::default();
+    let audio_settings = AudioSettings::default();
+
+    let simulation_settings =
+        SimulationSettings::new(&audio_settings).with_reflections(ConvolutionSettings {
+            max_num_rays: 4096,
+            num_diffuse_samples: 32,
+            max_duration: 2.0,
+            max_num_sources: 8,
+            num_threads: 2,
+            max_order: 1,
+        });
+    let mut simulator = Simulator::try_new(&context, &simulation_settings).unwrap();
+    assert_eq!(simulator.last_reflections_stats(), None);
+
+    let scene = Scene::try_new(&context).unwrap();
+    scene.commit();
+    simulator.set_scene(&scene);
+
+    let source = Source::try_new(&simulator).unwrap();
+    simulator.add_source(&source);
+    simulator.commit();
+
+    let simulation_shared_inputs = SimulationSharedInputs::new(CoordinateSystem::default())
+        .with_reflections(ReflectionsSharedInputs {
+            num_rays: 1024,
+            num_bounces: 4,
+            duration: 1.0,
+            order: 1,
+            irradiance_min_distance: 1.0,
+        });
+    simulator
+        .set_shared_reflections_inputs(&simulation_shared_inputs)
+        .unwrap();
+
+    let inputs = SimulationInputs::new(CoordinateSystem::default()).with_reflections(
+        ConvolutionParameters {
+            baked_data_identifier: None,
+        },
+    );
+    source.set_reflections_inputs(&inputs).unwrap();
+
+    simulator.run_reflections().unwrap();
+
+    let stats = simulator.last_reflections_stats().unwrap();
+    assert_eq!(stats.num_rays, Some(1024));
+    assert_eq!(stats.num_sources_added, 1);
+}
+
+#[test]
+fn test_run_reflections_solo() {
+    let context = Context::default();
+    let audio_settings = AudioSettings::default();
+
+    let simulation_settings =
+        SimulationSettings::new(&audio_settings).with_reflections(ConvolutionSettings {
+            max_num_rays: 4096,
+            num_diffuse_samples: 32,
+            max_duration: 2.0,
+            max_num_sources: 8,
+            num_threads: 2,
+            max_order: 1,
+        });
+    let mut simulator = Simulator::try_new(&context, &simulation_settings).unwrap();
+
+    let solo = Source::try_new(&simulator).unwrap();
+    let other = Source::try_new(&simulator).unwrap();
+    simulator.add_source(&other);
+    simulator.commit();
+
+    assert_eq!(
+        simulator.run_reflections_solo(&solo),
+        Err(SimulationError::ReflectionsWithoutScene)
+    );
+
+    let scene = Scene::try_new(&context).unwrap();
+    scene.commit();
+    simulator.set_scene(&scene);
+    simulator.commit();
+
+    let simulation_shared_inputs = SimulationSharedInputs::new(CoordinateSystem::default())
+        .with_reflections(ReflectionsSharedInputs {
+            num_rays: 4096,
+            num_bounces: 16,
+            duration: 2.0,
+            order: 1,
+            irradiance_min_distance: 1.0,
+        });
+    simulator
+        .set_shared_reflections_inputs(&simulation_shared_inputs)
+        .unwrap();
+
+    let inputs = SimulationInputs::new(CoordinateSystem::default()).with_reflections(
+        ConvolutionParameters {
+            baked_data_identifier: None,
+        },
+    );
+    solo.set_reflections_inputs(&inputs).unwrap();
+    other.set_reflections_inputs(&inputs).unwrap();
+
+    assert_eq!(simulator.run_reflections_solo(&solo), Ok(()));
+    assert!(solo.get_reflections_outputs().is_ok());
+
+    // The other source is restored, and the solo source is not left added.
+    assert_eq!(simulator.num_sources(), 1);
+    simulator.commit();
+    assert_eq!(simulator.run_reflections(), Ok(()));
+    assert_eq!(
+        simulator
+            .last_reflections_stats()
+            .unwrap()
+            .num_sources_added,
+        1
+    );
+}
+
+#[test]
+fn test_occlusion_samples_exceeds_max() {
+    let context = Context::default();
+    let audio_settings = AudioSettings::default();
+
+    let simulation_settings =
+        SimulationSettings::new(&audio_settings).with_direct(DirectSimulationSettings {
+            max_num_occlusion_samples: 8,
+        });
+    let simulator = Simulator::try_new(&context, &simulation_settings).unwrap();
+    let source = Source::try_new(&simulator).unwrap();
+
+    let inputs = |num_occlusion_samples| {
+        SimulationInputs::new(CoordinateSystem::default()).with_direct(
+            DirectSimulationParameters::new().with_occlusion(Occlusion::new(
+                OcclusionAlgorithm::Volumetric {
+                    radius: 1.0,
+                    num_occlusion_samples,
+                },
+            )),
+        )
+    };
+
+    assert!(source.set_direct_inputs(&inputs(8)).is_ok());
+    assert_eq!(
+        source.set_direct_inputs(&inputs(9)),
+        Err(ParameterValidationError::OcclusionSamplesExceedsMax {
+            requested: 9,
+            max: 8,
+        })
+    );
+}
+
+#[test]
+fn test_order_exceeds_max() {
+    let context = Context::default();
+    let audio_settings = AudioSettings::default();
+
+    let simulation_settings =
+        SimulationSettings::new(&audio_settings).with_reflections(ConvolutionSettings {
+            max_num_rays: 4096,
+            num_diffuse_samples: 32,
+            max_duration: 2.0,
+            max_num_sources: 8,
+            num_threads: 2,
+            max_order: 1,
+        });
+    let simulator = Simulator::try_new(&context, &simulation_settings).unwrap();
+
+    let shared_inputs = |order| {
+        SimulationSharedInputs::new(CoordinateSystem::default()).with_reflections(
+            ReflectionsSharedInputs {
+                order,
+                ..Default::default()
+            },
+        )
+    };
+
+    assert!(
+        simulator
+            .set_shared_reflections_inputs(&shared_inputs(1))
+            .is_ok()
+    );
+    assert_eq!(
+        simulator.set_shared_reflections_inputs(&shared_inputs(3)),
+        Err(ParameterValidationError::OrderExceedsMax {
+            requested: 3,
+            max: 1,
+        })
+    );
+}
+
+#[test]
+fn test_irradiance_min_distance_too_small() {
+    let context = Context::default();
+    let audio_settings = AudioSettings::default();
+
+    let simulation_settings =
+        SimulationSettings::new(&audio_settings).with_reflections(ConvolutionSettings::default());
+    let simulator = Simulator::try_new(&context, &simulation_settings).unwrap();
+
+    let shared_inputs = |irradiance_min_distance| {
+        SimulationSharedInputs::new(CoordinateSystem::default()).with_reflections(
+            ReflectionsSharedInputs {
+                irradiance_min_distance,
+                ..Default::default()
+            },
+        )
+    };
+
+    assert!(
+        simulator
+            .set_shared_reflections_inputs(&shared_inputs(MIN_IRRADIANCE_MIN_DISTANCE))
+            .is_ok()
+    );
+    assert_eq!(
+        simulator.set_shared_reflections_inputs(&shared_inputs(0.0)),
+        Err(ParameterValidationError::IrradianceMinDistanceTooSmall {
+            requested: 0.0,
+            min: MIN_IRRADIANCE_MIN_DISTANCE,
+        })
+    );
+    assert!(
+        simulator
+            .set_shared_reflections_inputs(&shared_inputs(f32::NAN))
+            .is_err()
+    );
+}
+
+#[test]
+fn test_probe_batch_not_registered() {
+    let context = Context::default();
+    let audio_settings = AudioSettings::default();
+
+    let simulation_settings =
+        SimulationSettings::new(&audio_settings).with_pathing(PathingSimulationSettings {
+            num_visibility_samples: 4,
+        });
+    let mut simulator = Simulator::try_new(&context, &simulation_settings).unwrap();
+
+    let mut registered = ProbeBatch::try_new(&context).unwrap();
+    registered.add_probe(Sphere::default());
+    registered.commit();
+    simulator.add_probe_batch(&registered);
+    simulator.commit();
+
+    let mut probe_batch = ProbeBatch::try_new(&context).unwrap();
+    probe_batch.add_probe(Sphere::default());
+    probe_batch.commit();
+
+    // Setting inputs before adding their probe batch to the simulator is valid.
+    let source = Source::try_new(&simulator).unwrap();
+    let pathing_inputs = SimulationInputs {
+        source: CoordinateSystem::default(),
+        parameters: SimulationParameters::new().with_pathing(PathingSimulationParameters {
+            pathing_probes: probe_batch.clone(),
+            visibility_radius: 1.0,
+            visibility_threshold: 10.0,
+            visibility_range: 10.0,
+            pathing_order: 1,
+            enable_validation: false,
+            find_alternate_paths: false,
+            deviation: DeviationModel::default(),
+        }),
+    };
+    assert!(source.set_pathing_inputs(&pathing_inputs).is_ok());
+    simulator.add_source(&source);
+    simulator.commit();
+
+    assert_eq!(
+        simulator.run_pathing(),
+        Err(SimulationError::ProbeBatchNotRegistered)
+    );
+
+    simulator.add_probe_batch(&probe_batch);
+    simulator.commit();
+    assert!(simulator.run_pathing().is_ok());
+
+    simulator.remove_probe_batch(&probe_batch);
+    simulator.commit();
+    assert_eq!(
+        simulator.run_pathing(),
+        Err(SimulationError::ProbeBatchNotRegistered)
+    );
+}
+
+#[test]
+fn test_no_baked_paths() {
+    let context = Context::default();
+    let audio_settings = AudioSettings::default();
+
+    let simulation_settings =
+        SimulationSettings::new(&audio_settings).with_pathing(PathingSimulationSettings {
+            num_visibility_samples: 4,
+        });
+    let mut simulator = Simulator::try_new(&context, &simulation_settings).unwrap();
+
+    let mut probe_batch = ProbeBatch::try_new(&context).unwrap();
+    probe_batch.add_probe(Sphere::default());
+    probe_batch.commit();
+    simulator.add_probe_batch(&probe_batch);
+
+    let source = Source::try_new(&simulator).unwrap();
+    simulator.add_source(&source);
+    simulator.commit();
+
+    let pathing_inputs = |enable_validation| SimulationInputs {
+        source: CoordinateSystem::default(),
+        parameters: SimulationParameters::new().with_pathing(PathingSimulationParameters {
+            pathing_probes: probe_batch.clone(),
+            visibility_radius: 1.0,
+            visibility_threshold: 10.0,
+            visibility_range: 10.0,
+            pathing_order: 1,
+            enable_validation,
+            find_alternate_paths: false,
+            deviation: DeviationModel::default(),
+        }),
+    };
+
+    source.set_pathing_inputs(&pathing_inputs(true)).unwrap();
+    assert_eq!(simulator.run_pathing(), Err(SimulationError::NoBakedPaths));
+
+    source.set_pathing_inputs(&pathing_inputs(false)).unwrap();
+    assert!(simulator.run_pathing().is_ok());
+}
+
+#[test]
+#[cfg(feature = "wiring")]
 fn test_wiring_simulation() {
     let context = Context::default();
     let audio_settings = AudioSettings::default();